@@ -0,0 +1,38 @@
+use anyhow::Context;
+
+// MARK: AccountEngine
+
+/// 操作の種類やリソースによらず, principal のアカウントの状態だけで判定するポリシー
+#[derive(Debug, Clone)]
+pub(crate) struct AccountEngine {
+    policies: cedar_policy::PolicySet,
+}
+
+impl AccountEngine {
+    pub(crate) const POLICIES: &str = include_str!("policies/account.cedar");
+
+    pub(crate) fn new() -> anyhow::Result<Self> {
+        let policies = Self::POLICIES
+            .parse()
+            .context("Failed to parse account policies")?;
+        Ok(Self { policies })
+    }
+}
+
+impl crate::Engine {
+    /// アカウントの状態が `request` の操作を許すかを判定します。
+    pub(crate) fn judge_account(
+        &self,
+        by: service::Principal,
+        request: &cedar_policy::Request,
+    ) -> anyhow::Result<service::Judgement> {
+        let engine = self.account();
+        let principal = self.encode_principal_entity(by, std::iter::empty())?;
+        let entities = cedar_policy::Entities::from_entities([principal], None)
+            .context("Failed to make entities of account request")?;
+        let response = self
+            .authorizer()
+            .is_authorized(request, &engine.policies, &entities);
+        Ok(self.read_response(response))
+    }
+}
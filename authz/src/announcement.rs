@@ -0,0 +1,376 @@
+use anyhow::Context;
+use cedar_policy::EntityUid;
+
+use crate::group::{ProvideGroupEntityRepository, fetch_group_lineage};
+
+// MARK: AnnouncementEngine
+
+#[derive(Debug, Clone)]
+pub(crate) struct AnnouncementEngine {
+    policies: cedar_policy::PolicySet,
+    action_list: EntityUid,
+    action_get: EntityUid,
+    action_create: EntityUid,
+    action_update: EntityUid,
+    action_delete: EntityUid,
+    action_mark_read: EntityUid,
+    announcement_type: cedar_policy::EntityTypeName,
+}
+
+impl AnnouncementEngine {
+    pub(crate) const POLICIES: &str = include_str!("policies/announcement.cedar");
+    pub(crate) const LIST_ID: &str = "list-announcements";
+    pub(crate) const GET_ID: &str = "get-announcement";
+    pub(crate) const CREATE_ID: &str = "create-announcement";
+    pub(crate) const UPDATE_ID: &str = "update-announcement";
+    pub(crate) const DELETE_ID: &str = "delete-announcement";
+    pub(crate) const MARK_READ_ID: &str = "mark-announcement-read";
+    pub(crate) const MEETING_POLL_TYPE: &str = "Announcement";
+
+    pub(crate) fn new() -> anyhow::Result<Self> {
+        use cedar_policy::EntityId;
+
+        let policies = Self::POLICIES
+            .parse()
+            .context("Failed to parse announcement policies")?;
+        let action = crate::Engine::action_type();
+        let list = EntityId::new(Self::LIST_ID);
+        let get = EntityId::new(Self::GET_ID);
+        let create = EntityId::new(Self::CREATE_ID);
+        let update = EntityId::new(Self::UPDATE_ID);
+        let delete = EntityId::new(Self::DELETE_ID);
+        let mark_read = EntityId::new(Self::MARK_READ_ID);
+        Ok(Self {
+            policies,
+            action_list: EntityUid::from_type_name_and_id(action.clone(), list),
+            action_get: EntityUid::from_type_name_and_id(action.clone(), get),
+            action_create: EntityUid::from_type_name_and_id(action.clone(), create),
+            action_update: EntityUid::from_type_name_and_id(action.clone(), update),
+            action_delete: EntityUid::from_type_name_and_id(action.clone(), delete),
+            action_mark_read: EntityUid::from_type_name_and_id(action, mark_read),
+            announcement_type: Self::MEETING_POLL_TYPE
+                .parse()
+                .context("Failed to parse announcement type")?,
+        })
+    }
+}
+
+// MARK: AnnouncementEntityRepository
+
+pub trait AnnouncementEntityRepository<Context, E>: Send + Sync {
+    /// お知らせが存在しなければ `None` を返します。
+    fn get_announcement_entity(
+        &self,
+        ctx: Context,
+        id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Option<domain::Announcement>, E>> + Send;
+}
+
+impl<R, C, E> AnnouncementEntityRepository<C, E> for &R
+where
+    R: AnnouncementEntityRepository<C, E>,
+    C: Send,
+{
+    async fn get_announcement_entity(
+        &self,
+        ctx: C,
+        id: domain::AnnouncementId,
+    ) -> Result<Option<domain::Announcement>, E> {
+        R::get_announcement_entity(self, ctx, id).await
+    }
+}
+
+pub trait ProvideAnnouncementEntityRepository: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type AnnouncementEntityRepository<'a>: AnnouncementEntityRepository<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+    type Error;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn announcement_entity_repository(&self) -> &Self::AnnouncementEntityRepository<'_>;
+
+    fn get_announcement_entity(
+        &self,
+        id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Option<domain::Announcement>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_entity_repository()
+            .get_announcement_entity(ctx, id)
+    }
+}
+
+impl<R> ProvideAnnouncementEntityRepository for &R
+where
+    R: ProvideAnnouncementEntityRepository,
+{
+    type Context<'a>
+        = R::Context<'a>
+    where
+        Self: 'a;
+    type Error = R::Error;
+    type AnnouncementEntityRepository<'a>
+        = R::AnnouncementEntityRepository<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        R::context(self)
+    }
+    fn announcement_entity_repository(&self) -> &Self::AnnouncementEntityRepository<'_> {
+        R::announcement_entity_repository(self)
+    }
+}
+
+// MARK: Request
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Request {
+    ListAnnouncements(domain::GroupId),
+    GetAnnouncement {
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    },
+    CreateAnnouncement(domain::GroupId),
+    UpdateAnnouncement {
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    },
+    DeleteAnnouncement {
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    },
+    MarkAnnouncementRead {
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    },
+}
+
+impl Request {
+    /// お知らせを掲示するグループ
+    fn group_id(self) -> domain::GroupId {
+        match self {
+            Self::ListAnnouncements(group_id)
+            | Self::CreateAnnouncement(group_id)
+            | Self::GetAnnouncement { group_id, .. }
+            | Self::UpdateAnnouncement { group_id, .. }
+            | Self::DeleteAnnouncement { group_id, .. }
+            | Self::MarkAnnouncementRead { group_id, .. } => group_id,
+        }
+    }
+}
+
+impl crate::Engine {
+    /// announcement -> `Announcement` entity
+    ///
+    /// `{ group: Group, author?: id }`
+    ///
+    /// 投稿者のアカウントが消去されていれば `author` を持ちません。
+    fn encode_announcement_entity(
+        &self,
+        announcement: &domain::Announcement,
+    ) -> anyhow::Result<cedar_policy::Entity> {
+        use std::collections::{HashMap, HashSet};
+
+        use cedar_policy::RestrictedExpression;
+
+        let uid = self.encode_announcement_id(announcement.id)?;
+        let group =
+            RestrictedExpression::new_entity_uid(self.encode_group_id(announcement.group_id)?);
+        let author = announcement.author_id.map(|id| {
+            (
+                "author".to_string(),
+                RestrictedExpression::new_string(id.to_string()),
+            )
+        });
+        let attrs: HashMap<_, _> = [("group".to_string(), group)]
+            .into_iter()
+            .chain(author)
+            .collect();
+        cedar_policy::Entity::new(uid, attrs, HashSet::new())
+            .context("Failed to make entity of announcement")
+    }
+
+    fn encode_announcement_id(
+        &self,
+        id: domain::AnnouncementId,
+    ) -> anyhow::Result<cedar_policy::EntityUid> {
+        let ty = self.announcement().announcement_type.clone();
+        let id = id
+            .to_string()
+            .parse()
+            .context("Failed to parse AnnouncementId as entity ID")?;
+        Ok(EntityUid::from_type_name_and_id(ty, id))
+    }
+
+    pub(crate) async fn process_announcement_request<E, R>(
+        &self,
+        by: service::Principal,
+        repo: R,
+        request: Request,
+    ) -> Result<service::Judgement, E>
+    where
+        E: crate::Error,
+        R: ProvideGroupEntityRepository<Error = E> + ProvideAnnouncementEntityRepository<Error = E>,
+    {
+        use Request::{
+            CreateAnnouncement, DeleteAnnouncement, GetAnnouncement, ListAnnouncements,
+            MarkAnnouncementRead, UpdateAnnouncement,
+        };
+
+        let engine = self.announcement();
+        let action = match request {
+            ListAnnouncements(_) => engine.action_list.clone(),
+            GetAnnouncement { .. } => engine.action_get.clone(),
+            CreateAnnouncement(_) => engine.action_create.clone(),
+            UpdateAnnouncement { .. } => engine.action_update.clone(),
+            DeleteAnnouncement { .. } => engine.action_delete.clone(),
+            MarkAnnouncementRead { .. } => engine.action_mark_read.clone(),
+        };
+        let (resource, entities) = match request {
+            ListAnnouncements(group_id) | CreateAnnouncement(group_id) => {
+                let lineage = fetch_group_lineage(&repo, group_id).await?;
+                if lineage.is_empty() {
+                    return Ok(service::Judgement::Deny);
+                }
+                let resource = self.encode_group_id(group_id)?;
+                let entities = self.encode_group_lineage_entities(by, &lineage)?;
+                (resource, entities)
+            }
+            GetAnnouncement {
+                group_id,
+                announcement_id,
+            }
+            | UpdateAnnouncement {
+                group_id,
+                announcement_id,
+            }
+            | DeleteAnnouncement {
+                group_id,
+                announcement_id,
+            }
+            | MarkAnnouncementRead {
+                group_id,
+                announcement_id,
+            } => {
+                let Some(announcement) = repo.get_announcement_entity(announcement_id).await?
+                else {
+                    return Ok(service::Judgement::Deny);
+                };
+                // 別グループのお知らせは扱えない
+                if announcement.group_id != group_id {
+                    return Ok(service::Judgement::Deny);
+                }
+                let lineage = fetch_group_lineage(&repo, group_id).await?;
+                if lineage.is_empty() {
+                    return Ok(service::Judgement::Deny);
+                }
+                let resource = self.encode_announcement_id(announcement_id)?;
+                let mut entities = self.encode_group_lineage_entities(by, &lineage)?;
+                entities.push(self.encode_announcement_entity(&announcement)?);
+                (resource, entities)
+            }
+        };
+        let entities = cedar_policy::Entities::from_entities(entities, None)
+            .context("Failed to make cedar entities")?;
+        let context = cedar_policy::Context::empty();
+        let group = self.encode_group_id(request.group_id())?;
+        let policies = crate::link_group_templates(&engine.policies, &group)?;
+        let request = self.make_request(by, action, resource, context)?;
+        Ok(self.authorize(by, &request, &policies, &entities)?)
+    }
+}
+
+// MARK: AnnouncementAccessControl for Engine
+
+impl<C, E> service::AnnouncementAccessControl<C, E> for crate::Engine
+where
+    C: ProvideGroupEntityRepository<Error = E> + ProvideAnnouncementEntityRepository<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_list_announcements(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::ListAnnouncements(group_id);
+        self.process_announcement_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_get_announcement(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::GetAnnouncement {
+            group_id,
+            announcement_id,
+        };
+        self.process_announcement_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx, _params), ret(level = "debug"))]
+    async fn judge_create_announcement(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        _params: &domain::CreateAnnouncementParams,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::CreateAnnouncement(group_id);
+        self.process_announcement_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx, _params), ret(level = "debug"))]
+    async fn judge_update_announcement(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+        _params: &domain::UpdateAnnouncementParams,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::UpdateAnnouncement {
+            group_id,
+            announcement_id,
+        };
+        self.process_announcement_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_delete_announcement(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::DeleteAnnouncement {
+            group_id,
+            announcement_id,
+        };
+        self.process_announcement_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_mark_announcement_read(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::MarkAnnouncementRead {
+            group_id,
+            announcement_id,
+        };
+        self.process_announcement_request(by, ctx, r).await
+    }
+}
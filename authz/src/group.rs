@@ -11,8 +11,21 @@ pub(crate) struct GroupEngine {
     action_create: EntityUid,
     action_update: EntityUid,
     action_update_members: EntityUid,
+    action_add_members: EntityUid,
+    action_remove_member: EntityUid,
+    action_leave: EntityUid,
+    action_transfer_ownership: EntityUid,
+    action_archive: EntityUid,
+    action_delete: EntityUid,
+    action_create_invite: EntityUid,
+    action_accept_invite: EntityUid,
+    action_list_join_requests: EntityUid,
+    action_create_join_request: EntityUid,
+    action_decide_join_request: EntityUid,
     resource_create_group: EntityUid,
-    resource_list_groups: EntityUid,
+    list_groups_type: cedar_policy::EntityTypeName,
+    group_invite_type: cedar_policy::EntityTypeName,
+    group_join_request_type: cedar_policy::EntityTypeName,
 }
 
 impl GroupEngine {
@@ -22,8 +35,21 @@ impl GroupEngine {
     pub(crate) const CREATE_ID: &str = "create-group";
     pub(crate) const UPDATE_ID: &str = "update-group";
     pub(crate) const UPDATE_MEMBERS_ID: &str = "update-group-members";
+    pub(crate) const ADD_MEMBERS_ID: &str = "add-group-members";
+    pub(crate) const REMOVE_MEMBER_ID: &str = "remove-group-member";
+    pub(crate) const LEAVE_ID: &str = "leave-group";
+    pub(crate) const TRANSFER_OWNERSHIP_ID: &str = "transfer-group-ownership";
+    pub(crate) const ARCHIVE_ID: &str = "archive-group";
+    pub(crate) const DELETE_ID: &str = "delete-group";
+    pub(crate) const CREATE_INVITE_ID: &str = "create-group-invite";
+    pub(crate) const ACCEPT_INVITE_ID: &str = "accept-group-invite";
+    pub(crate) const LIST_JOIN_REQUESTS_ID: &str = "list-group-join-requests";
+    pub(crate) const CREATE_JOIN_REQUEST_ID: &str = "create-group-join-request";
+    pub(crate) const DECIDE_JOIN_REQUEST_ID: &str = "decide-group-join-request";
     pub(crate) const CREATE_GROUP_TYPE: &str = "CreateGroup";
     pub(crate) const LIST_GROUPS_TYPE: &str = "ListGroups";
+    pub(crate) const GROUP_INVITE_TYPE: &str = "GroupInvite";
+    pub(crate) const GROUP_JOIN_REQUEST_TYPE: &str = "GroupJoinRequest";
 
     pub(crate) fn new() -> anyhow::Result<Self> {
         use cedar_policy::EntityId;
@@ -37,19 +63,57 @@ impl GroupEngine {
         let create = EntityId::new(Self::CREATE_ID);
         let update = EntityId::new(Self::UPDATE_ID);
         let update_members = EntityId::new(Self::UPDATE_MEMBERS_ID);
+        let add_members = EntityId::new(Self::ADD_MEMBERS_ID);
+        let remove_member = EntityId::new(Self::REMOVE_MEMBER_ID);
+        let leave = EntityId::new(Self::LEAVE_ID);
+        let transfer_ownership = EntityId::new(Self::TRANSFER_OWNERSHIP_ID);
+        let archive = EntityId::new(Self::ARCHIVE_ID);
+        let delete = EntityId::new(Self::DELETE_ID);
+        let create_invite = EntityId::new(Self::CREATE_INVITE_ID);
+        let accept_invite = EntityId::new(Self::ACCEPT_INVITE_ID);
+        let list_join_requests = EntityId::new(Self::LIST_JOIN_REQUESTS_ID);
+        let create_join_request = EntityId::new(Self::CREATE_JOIN_REQUEST_ID);
+        let decide_join_request = EntityId::new(Self::DECIDE_JOIN_REQUEST_ID);
         let resource_create_group =
             EntityUid::from_type_name_and_id(Self::create_group_type()?, EntityId::new(""));
-        let resource_list_groups =
-            EntityUid::from_type_name_and_id(Self::list_groups_type()?, EntityId::new(""));
         Ok(Self {
             policies,
             action_get: EntityUid::from_type_name_and_id(action.clone(), get),
             action_list: EntityUid::from_type_name_and_id(action.clone(), list),
             action_create: EntityUid::from_type_name_and_id(action.clone(), create),
             action_update: EntityUid::from_type_name_and_id(action.clone(), update),
-            action_update_members: EntityUid::from_type_name_and_id(action, update_members),
+            action_update_members: EntityUid::from_type_name_and_id(action.clone(), update_members),
+            action_add_members: EntityUid::from_type_name_and_id(action.clone(), add_members),
+            action_remove_member: EntityUid::from_type_name_and_id(action.clone(), remove_member),
+            action_leave: EntityUid::from_type_name_and_id(action.clone(), leave),
+            action_transfer_ownership: EntityUid::from_type_name_and_id(
+                action.clone(),
+                transfer_ownership,
+            ),
+            action_archive: EntityUid::from_type_name_and_id(action.clone(), archive),
+            action_delete: EntityUid::from_type_name_and_id(action.clone(), delete),
+            action_create_invite: EntityUid::from_type_name_and_id(action.clone(), create_invite),
+            action_accept_invite: EntityUid::from_type_name_and_id(action.clone(), accept_invite),
+            action_list_join_requests: EntityUid::from_type_name_and_id(
+                action.clone(),
+                list_join_requests,
+            ),
+            action_create_join_request: EntityUid::from_type_name_and_id(
+                action.clone(),
+                create_join_request,
+            ),
+            action_decide_join_request: EntityUid::from_type_name_and_id(
+                action,
+                decide_join_request,
+            ),
             resource_create_group,
-            resource_list_groups,
+            list_groups_type: Self::list_groups_type()?,
+            group_invite_type: Self::GROUP_INVITE_TYPE
+                .parse()
+                .context("Failed to parse group invite type")?,
+            group_join_request_type: Self::GROUP_JOIN_REQUEST_TYPE
+                .parse()
+                .context("Failed to parse group join request type")?,
         })
     }
 
@@ -64,16 +128,36 @@ impl GroupEngine {
             .parse()
             .context("Failed to parse list group type")
     }
+
+    /// すべての policy template の `?principal` を `group` に結びつけた policy set を返します。
+    fn link_templates(&self, group: &EntityUid) -> anyhow::Result<cedar_policy::PolicySet> {
+        crate::link_group_templates(&self.policies, group)
+    }
 }
 
 // MARK: GroupEntityRepository
 
 pub trait GroupEntityRepository<Context, E>: Send + Sync {
-    fn get_group_members(
+    /// グループが存在しなければ `None` を返します。
+    fn get_group_entity(
         &self,
         ctx: Context,
         id: domain::GroupId,
-    ) -> impl Future<Output = Result<Vec<domain::UserId>, E>> + Send;
+    ) -> impl Future<Output = Result<Option<domain::Group>, E>> + Send;
+
+    /// 招待コードが存在しなければ `None` を返します。
+    fn get_group_invite_entity(
+        &self,
+        ctx: Context,
+        code: &domain::GroupInviteCode,
+    ) -> impl Future<Output = Result<Option<domain::GroupInvite>, E>> + Send;
+
+    /// 参加リクエストが存在しなければ `None` を返します。
+    fn get_group_join_request_entity(
+        &self,
+        ctx: Context,
+        id: domain::GroupJoinRequestId,
+    ) -> impl Future<Output = Result<Option<domain::GroupJoinRequest>, E>> + Send;
 }
 
 impl<R, C, E> GroupEntityRepository<C, E> for &R
@@ -81,12 +165,28 @@ where
     R: GroupEntityRepository<C, E>,
     C: Send,
 {
-    async fn get_group_members(
+    async fn get_group_entity(
         &self,
         ctx: C,
         id: domain::GroupId,
-    ) -> Result<Vec<domain::UserId>, E> {
-        R::get_group_members(self, ctx, id).await
+    ) -> Result<Option<domain::Group>, E> {
+        R::get_group_entity(self, ctx, id).await
+    }
+
+    async fn get_group_invite_entity(
+        &self,
+        ctx: C,
+        code: &domain::GroupInviteCode,
+    ) -> Result<Option<domain::GroupInvite>, E> {
+        R::get_group_invite_entity(self, ctx, code).await
+    }
+
+    async fn get_group_join_request_entity(
+        &self,
+        ctx: C,
+        id: domain::GroupJoinRequestId,
+    ) -> Result<Option<domain::GroupJoinRequest>, E> {
+        R::get_group_join_request_entity(self, ctx, id).await
     }
 }
 
@@ -102,12 +202,30 @@ pub trait ProvideGroupEntityRepository: Send + Sync {
     fn context(&self) -> Self::Context<'_>;
     fn group_entity_repository(&self) -> &Self::GroupEntityRepository<'_>;
 
-    fn get_group_members(
+    fn get_group_entity(
         &self,
         id: domain::GroupId,
-    ) -> impl Future<Output = Result<Vec<domain::UserId>, Self::Error>> + Send {
+    ) -> impl Future<Output = Result<Option<domain::Group>, Self::Error>> + Send {
         let ctx = self.context();
-        self.group_entity_repository().get_group_members(ctx, id)
+        self.group_entity_repository().get_group_entity(ctx, id)
+    }
+
+    fn get_group_invite_entity(
+        &self,
+        code: &domain::GroupInviteCode,
+    ) -> impl Future<Output = Result<Option<domain::GroupInvite>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_entity_repository()
+            .get_group_invite_entity(ctx, code)
+    }
+
+    fn get_group_join_request_entity(
+        &self,
+        id: domain::GroupJoinRequestId,
+    ) -> impl Future<Output = Result<Option<domain::GroupJoinRequest>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_entity_repository()
+            .get_group_join_request_entity(ctx, id)
     }
 }
 
@@ -135,160 +253,506 @@ where
 
 // MARK: Request
 
+// 参加リクエストの variant はドメインの `GroupJoinRequest` に名前を合わせる
+#[expect(clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
 pub(crate) enum Request<'a> {
     GetGroup(domain::GroupId),
-    ListGroups,
+    ListGroups(domain::ListGroupsFilter),
     CreateGroup {
-        members: &'a [domain::UserId],
+        members: &'a [domain::GroupMember],
+        parent: Option<domain::GroupId>,
     },
     UpdateGroup(domain::GroupId),
     UpdateGroupMembers {
         id: domain::GroupId,
-        members: &'a [domain::UserId],
+        members: &'a [domain::GroupMember],
+    },
+    AddGroupMembers {
+        id: domain::GroupId,
+        members: &'a [domain::GroupMember],
+    },
+    RemoveGroupMember {
+        id: domain::GroupId,
+        user_id: domain::UserId,
+    },
+    LeaveGroup(domain::GroupId),
+    TransferGroupOwnership {
+        id: domain::GroupId,
+        to: domain::UserId,
+    },
+    ArchiveGroup(domain::GroupId),
+    DeleteGroup(domain::GroupId),
+    CreateGroupInvite(domain::GroupId),
+    AcceptGroupInvite(&'a domain::GroupInviteCode),
+    ListGroupJoinRequests(domain::GroupId),
+    RequestToJoinGroup(domain::GroupId),
+    DecideGroupJoinRequest {
+        id: domain::GroupId,
+        request_id: domain::GroupJoinRequestId,
     },
 }
 
 impl crate::Engine {
+    /// `{ members: id[], owners: id[], admins: id[], parent?: Group }`
     fn encode_create_group_entity(
         &self,
-        members: &[domain::UserId],
+        members: &[domain::GroupMember],
+        parent: Option<domain::GroupId>,
     ) -> anyhow::Result<cedar_policy::Entity> {
         use std::collections::{HashMap, HashSet};
 
+        use cedar_policy::RestrictedExpression;
+
         let uid = self.group().resource_create_group.clone();
-        let members = self.encode_group_members(members)?;
-        let attrs: HashMap<_, _> = [("members".to_string(), members)].into_iter().collect();
+        let parent = parent
+            .map(|p| {
+                let uid = self.encode_group_id(p)?;
+                anyhow::Ok((
+                    "parent".to_string(),
+                    RestrictedExpression::new_entity_uid(uid),
+                ))
+            })
+            .transpose()?;
+        let attrs: HashMap<_, _> = self
+            .encode_group_members(members)
+            .into_iter()
+            .chain(parent)
+            .collect();
         cedar_policy::Entity::new(uid, attrs, HashSet::new())
             .context("Failed to make entity of create-group")
     }
 
+    /// filter -> `ListGroups` entity
+    ///
+    /// `{ filter: "joined" | "public" }`
+    fn encode_list_groups_entity(
+        &self,
+        filter: domain::ListGroupsFilter,
+    ) -> anyhow::Result<cedar_policy::Entity> {
+        use std::collections::{HashMap, HashSet};
+
+        use cedar_policy::{EntityId, RestrictedExpression};
+
+        let filter = match filter {
+            domain::ListGroupsFilter::Joined => "joined",
+            domain::ListGroupsFilter::Public => "public",
+        };
+        let ty = self.group().list_groups_type.clone();
+        let uid = EntityUid::from_type_name_and_id(ty, EntityId::new(filter));
+        let attrs: HashMap<_, _> = [(
+            "filter".to_string(),
+            RestrictedExpression::new_string(filter.to_string()),
+        )]
+        .into_iter()
+        .collect();
+        cedar_policy::Entity::new(uid, attrs, HashSet::new())
+            .context("Failed to make entity of list-groups")
+    }
+
+    /// invite -> `GroupInvite` entity
+    ///
+    /// `{ group: Group, single_use: bool, use_count: long, expires_at: long }`
+    /// - `expires_at`: UNIX 時間 (秒)
+    fn encode_group_invite_entity(
+        &self,
+        invite: &domain::GroupInvite,
+    ) -> anyhow::Result<cedar_policy::Entity> {
+        use std::collections::{HashMap, HashSet};
+
+        use cedar_policy::RestrictedExpression;
+
+        let uid = self.encode_group_invite_code(&invite.code)?;
+        let group = RestrictedExpression::new_entity_uid(self.encode_group_id(invite.group_id)?);
+        let attrs: HashMap<_, _> = [
+            ("group".to_string(), group),
+            (
+                "single_use".to_string(),
+                RestrictedExpression::new_bool(invite.single_use),
+            ),
+            (
+                "use_count".to_string(),
+                RestrictedExpression::new_long(invite.use_count.into()),
+            ),
+            (
+                "expires_at".to_string(),
+                RestrictedExpression::new_long(invite.expires_at.timestamp()),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        cedar_policy::Entity::new(uid, attrs, HashSet::new())
+            .context("Failed to make entity of group invite")
+    }
+
+    /// join request -> `GroupJoinRequest` entity
+    ///
+    /// `{ group: Group, status: "pending" | "approved" | "rejected" }`
+    fn encode_group_join_request_entity(
+        &self,
+        request: &domain::GroupJoinRequest,
+    ) -> anyhow::Result<cedar_policy::Entity> {
+        use std::collections::{HashMap, HashSet};
+
+        use cedar_policy::RestrictedExpression;
+
+        let uid = self.encode_group_join_request_id(request.id)?;
+        let group = RestrictedExpression::new_entity_uid(self.encode_group_id(request.group_id)?);
+        let status = match request.status {
+            domain::GroupJoinRequestStatus::Pending => "pending",
+            domain::GroupJoinRequestStatus::Approved => "approved",
+            domain::GroupJoinRequestStatus::Rejected => "rejected",
+        };
+        let attrs: HashMap<_, _> = [
+            ("group".to_string(), group),
+            (
+                "status".to_string(),
+                RestrictedExpression::new_string(status.to_string()),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        cedar_policy::Entity::new(uid, attrs, HashSet::new())
+            .context("Failed to make entity of group join request")
+    }
+
+    fn encode_group_join_request_id(
+        &self,
+        id: domain::GroupJoinRequestId,
+    ) -> anyhow::Result<cedar_policy::EntityUid> {
+        let ty = self.group().group_join_request_type.clone();
+        let id = id
+            .to_string()
+            .parse()
+            .context("Failed to parse GroupJoinRequestId as entity ID")?;
+        Ok(EntityUid::from_type_name_and_id(ty, id))
+    }
+
+    fn encode_group_invite_code(
+        &self,
+        code: &domain::GroupInviteCode,
+    ) -> anyhow::Result<cedar_policy::EntityUid> {
+        let ty = self.group().group_invite_type.clone();
+        let id = code
+            .as_inner()
+            .parse()
+            .context("Failed to parse GroupInviteCode as entity ID")?;
+        Ok(EntityUid::from_type_name_and_id(ty, id))
+    }
+
+    /// 現在時刻を `{ now: long }` (UNIX 時間, 秒) の context にします。
+    fn encode_now_context(&self) -> anyhow::Result<cedar_policy::Context> {
+        let now = cedar_policy::RestrictedExpression::new_long(chrono::Utc::now().timestamp());
+        cedar_policy::Context::from_pairs([("now".to_string(), now)])
+            .context("Failed to make context of current time")
+    }
+
+    /// `lineage` のうちメンバーであるグループを親に持つ principal entity を作ります。
+    fn encode_group_principal_entity(
+        &self,
+        by: service::Principal,
+        lineage: &[domain::Group],
+    ) -> anyhow::Result<cedar_policy::Entity> {
+        let groups = lineage.iter().filter_map(|g| match by {
+            service::Principal::User(user_id) | service::Principal::PendingDeletion(user_id)
+                if g.role_of(user_id).is_some() =>
+            {
+                Some(g.id)
+            }
+            service::Principal::User(_)
+            | service::Principal::PendingDeletion(_)
+            | service::Principal::Anonymous => None,
+        });
+        self.encode_principal_entity(by, groups)
+    }
+
+    /// principal と `lineage` の各グループの entity を作ります。
+    ///
+    /// グループ間の親子関係から `principal in Group::"..."` が推移的に判定されます。
+    /// 各グループの `ancestor_owners` には, `lineage` でそれより後ろにあるグループのオーナーが入ります。
+    pub(crate) fn encode_group_lineage_entities(
+        &self,
+        by: service::Principal,
+        lineage: &[domain::Group],
+    ) -> anyhow::Result<Vec<cedar_policy::Entity>> {
+        let principal = self.encode_group_principal_entity(by, lineage)?;
+        let groups = lineage.iter().enumerate().map(|(i, g)| {
+            let ancestor_owners = lineage[i + 1..]
+                .iter()
+                .flat_map(|a| &a.members)
+                .filter(|m| m.role == domain::GroupRole::Owner)
+                .map(|m| m.user_id);
+            self.encode_group_entity(g, ancestor_owners)
+        });
+        std::iter::once(Ok(principal)).chain(groups).collect()
+    }
+
+    /// メンバー編集後の状態と差分を context にします。
+    ///
+    /// `{ members, owners, admins, added, removed, changed }`
+    /// - `members`, `owners`, `admins`: 編集後のメンバー
+    /// - `added`: 追加されるユーザー
+    /// - `removed`: 削除されるユーザー
+    /// - `changed`: ロールが変わるユーザー
+    fn encode_update_members_context(
+        &self,
+        old: &domain::Group,
+        new: &[domain::GroupMember],
+    ) -> anyhow::Result<cedar_policy::Context> {
+        let role_in_new = |user_id| new.iter().find(|m| m.user_id == user_id).map(|m| m.role);
+        let added = new
+            .iter()
+            .filter(|m| old.role_of(m.user_id).is_none())
+            .map(|m| m.user_id);
+        let removed = old
+            .members
+            .iter()
+            .filter(|m| role_in_new(m.user_id).is_none())
+            .map(|m| m.user_id);
+        let changed = new
+            .iter()
+            .filter(|m| old.role_of(m.user_id).is_some_and(|r| r != m.role))
+            .map(|m| m.user_id);
+        let pairs = self.encode_group_members(new).into_iter().chain([
+            ("added".to_string(), self.encode_user_id_set(added)),
+            ("removed".to_string(), self.encode_user_id_set(removed)),
+            ("changed".to_string(), self.encode_user_id_set(changed)),
+        ]);
+        cedar_policy::Context::from_pairs(pairs).context("Failed to make context of members update")
+    }
+
     pub(crate) async fn process_group_request<E: crate::Error>(
         &self,
         by: service::Principal,
         repo: impl ProvideGroupEntityRepository<Error = E>,
         request: Request<'_>,
     ) -> Result<service::Judgement, E> {
-        use Request::{CreateGroup, GetGroup, ListGroups, UpdateGroup, UpdateGroupMembers};
+        use Request::{
+            AcceptGroupInvite, AddGroupMembers, ArchiveGroup, CreateGroup, CreateGroupInvite,
+            DecideGroupJoinRequest, DeleteGroup, GetGroup, LeaveGroup, ListGroupJoinRequests,
+            ListGroups, RemoveGroupMember, RequestToJoinGroup, TransferGroupOwnership, UpdateGroup,
+            UpdateGroupMembers,
+        };
 
         let engine = self.group();
-        let (action, resource, entities, policies) = match request {
+        let action = match &request {
+            GetGroup(_) => engine.action_get.clone(),
+            ListGroups(_) => engine.action_list.clone(),
+            CreateGroup { .. } => engine.action_create.clone(),
+            UpdateGroup(_) => engine.action_update.clone(),
+            UpdateGroupMembers { .. } => engine.action_update_members.clone(),
+            AddGroupMembers { .. } => engine.action_add_members.clone(),
+            RemoveGroupMember { .. } => engine.action_remove_member.clone(),
+            LeaveGroup(_) => engine.action_leave.clone(),
+            TransferGroupOwnership { .. } => engine.action_transfer_ownership.clone(),
+            ArchiveGroup(_) => engine.action_archive.clone(),
+            DeleteGroup(_) => engine.action_delete.clone(),
+            CreateGroupInvite(_) => engine.action_create_invite.clone(),
+            AcceptGroupInvite(_) => engine.action_accept_invite.clone(),
+            ListGroupJoinRequests(_) => engine.action_list_join_requests.clone(),
+            RequestToJoinGroup(_) => engine.action_create_join_request.clone(),
+            DecideGroupJoinRequest { .. } => engine.action_decide_join_request.clone(),
+        };
+        let (resource, entities, context, policies) = match request {
             GetGroup(id) => {
-                let action = engine.action_get.clone();
+                let lineage = fetch_group_lineage(&repo, id).await?;
+                if lineage.is_empty() {
+                    // 許可してリポジトリに not found を返させる
+                    return Ok(service::Judgement::Allow);
+                }
                 let resource = self.encode_group_id(id)?;
-                let entities = cedar_policy::Entities::empty();
-                (action, resource, entities, engine.policies.clone())
+                let entities = self.encode_group_lineage_entities(by, &lineage)?;
+                let entities = cedar_policy::Entities::from_entities(entities, None)
+                    .context("Failed to make cedar entities")?;
+                let context = cedar_policy::Context::empty();
+                (resource, entities, context, engine.policies.clone())
             }
-            ListGroups => {
-                let action = engine.action_list.clone();
-                let resource = engine.resource_list_groups.clone();
-                let entities = cedar_policy::Entities::empty();
-                (action, resource, entities, engine.policies.clone())
+            ListGroups(filter) => {
+                let list_groups = self.encode_list_groups_entity(filter)?;
+                let resource = list_groups.uid();
+                let entities = cedar_policy::Entities::from_entities([list_groups], None)
+                    .context("Failed to make cedar entities")?;
+                let context = cedar_policy::Context::empty();
+                (resource, entities, context, engine.policies.clone())
             }
-            CreateGroup { members } => {
-                let action = engine.action_create.clone();
+            CreateGroup { members, parent } => {
+                let lineage = match parent {
+                    Some(parent) => fetch_group_lineage(&repo, parent).await?,
+                    None => Vec::new(),
+                };
+                if parent.is_some() && lineage.is_empty() {
+                    return Ok(service::Judgement::Deny);
+                }
                 let resource = engine.resource_create_group.clone();
                 let entities = {
-                    let principal = self.encode_principal_entity(by, std::iter::empty())?;
-                    let create_group = self.encode_create_group_entity(members)?;
-                    cedar_policy::Entities::from_entities([principal, create_group], None)
+                    let mut entities = self.encode_group_lineage_entities(by, &lineage)?;
+                    entities.push(self.encode_create_group_entity(members, parent)?);
+                    cedar_policy::Entities::from_entities(entities, None)
                         .context("Failed to make cedar entities")?
                 };
-                (action, resource, entities, engine.policies.clone())
+                let context = cedar_policy::Context::empty();
+                (resource, entities, context, engine.policies.clone())
             }
-            UpdateGroup(id) => {
-                let members = repo.get_group_members(id).await?;
-                let action = engine.action_update.clone();
+            UpdateGroup(id) | ArchiveGroup(id) | DeleteGroup(id) | LeaveGroup(id) => {
+                let lineage = fetch_group_lineage(&repo, id).await?;
+                if lineage.is_empty() {
+                    return Ok(MISSING_GROUP_JUDGEMENT);
+                }
                 let resource = self.encode_group_id(id)?;
-                let entities = {
-                    let principal = match by {
-                        service::Principal::User(user_id) if members.contains(&user_id) => {
-                            self.encode_principal_entity(by, [id])?
-                        }
-                        service::Principal::User(_) | service::Principal::Anonymous => {
-                            self.encode_principal_entity(by, std::iter::empty())?
-                        }
-                    };
-                    let update_group = self.encode_group_entity(id, &members)?;
-                    cedar_policy::Entities::from_entities([principal, update_group], None)
-                        .context("Failed to make cedar entities")?
-                };
-                let policies = {
-                    let mut p = engine.policies.clone();
-                    let template = engine
-                        .policies
-                        .templates()
-                        .find(|t| {
-                            t.annotation("id")
-                                .is_some_and(|i| i == "permit-update-group")
-                        })
-                        .context(r#"Policy template @id("permit-update-group") not found"#)?;
-                    let template_id = template.id().clone();
-                    let policy_id =
-                        cedar_policy::PolicyId::new(format!("permit-update-group-{id}"));
-                    let env: std::collections::HashMap<_, _> =
-                        [(cedar_policy::SlotId::principal(), self.encode_group_id(id)?)]
-                            .into_iter()
-                            .collect();
-                    p.link(template_id, policy_id, env).context(
-                        r#"Failed to link the policy template @id("permit-update-group")"#,
-                    )?;
-                    p
-                };
-                (action, resource, entities, policies)
+                let entities = self.encode_group_lineage_entities(by, &lineage)?;
+                let entities = cedar_policy::Entities::from_entities(entities, None)
+                    .context("Failed to make cedar entities")?;
+                let context = cedar_policy::Context::empty();
+                let policies = engine.link_templates(&resource)?;
+                (resource, entities, context, policies)
+            }
+            CreateGroupInvite(id) | ListGroupJoinRequests(id) | RequestToJoinGroup(id) => {
+                let lineage = fetch_group_lineage(&repo, id).await?;
+                if lineage.is_empty() {
+                    return Ok(service::Judgement::Deny);
+                }
+                let resource = self.encode_group_id(id)?;
+                let entities = self.encode_group_lineage_entities(by, &lineage)?;
+                let entities = cedar_policy::Entities::from_entities(entities, None)
+                    .context("Failed to make cedar entities")?;
+                let context = cedar_policy::Context::empty();
+                let policies = engine.link_templates(&resource)?;
+                (resource, entities, context, policies)
             }
             UpdateGroupMembers { id, members } => {
-                let old_members = repo.get_group_members(id).await?;
-                let action = engine.action_update_members.clone();
+                let lineage = fetch_group_lineage(&repo, id).await?;
+                let Some(group) = lineage.first() else {
+                    return Ok(MISSING_GROUP_JUDGEMENT);
+                };
                 let resource = self.encode_group_id(id)?;
+                let context = self.encode_update_members_context(group, members)?;
+                let entities = self.encode_group_lineage_entities(by, &lineage)?;
+                let entities = cedar_policy::Entities::from_entities(entities, None)
+                    .context("Failed to make cedar entities")?;
+                let policies = engine.link_templates(&resource)?;
+                (resource, entities, context, policies)
+            }
+            AddGroupMembers { id, members } => {
+                let lineage = fetch_group_lineage(&repo, id).await?;
+                if lineage.is_empty() {
+                    return Ok(MISSING_GROUP_JUDGEMENT);
+                }
+                let resource = self.encode_group_id(id)?;
+                let context = cedar_policy::Context::from_pairs(self.encode_group_members(members))
+                    .context("Failed to make context of members addition")?;
+                let entities = self.encode_group_lineage_entities(by, &lineage)?;
+                let entities = cedar_policy::Entities::from_entities(entities, None)
+                    .context("Failed to make cedar entities")?;
+                let policies = engine.link_templates(&resource)?;
+                (resource, entities, context, policies)
+            }
+            TransferGroupOwnership { id, to } => {
+                let lineage = fetch_group_lineage(&repo, id).await?;
+                if lineage.is_empty() {
+                    return Ok(MISSING_GROUP_JUDGEMENT);
+                }
+                let resource = self.encode_group_id(id)?;
+                let to = cedar_policy::RestrictedExpression::new_string(to.to_string());
+                let context = cedar_policy::Context::from_pairs([("to".to_string(), to)])
+                    .context("Failed to make context of ownership transfer")?;
+                let entities = self.encode_group_lineage_entities(by, &lineage)?;
+                let entities = cedar_policy::Entities::from_entities(entities, None)
+                    .context("Failed to make cedar entities")?;
+                let policies = engine.link_templates(&resource)?;
+                (resource, entities, context, policies)
+            }
+            RemoveGroupMember { id, user_id } => {
+                let lineage = fetch_group_lineage(&repo, id).await?;
+                if lineage.is_empty() {
+                    return Ok(MISSING_GROUP_JUDGEMENT);
+                }
+                let resource = self.encode_group_id(id)?;
+                let user = cedar_policy::RestrictedExpression::new_string(user_id.to_string());
+                let context = cedar_policy::Context::from_pairs([("user".to_string(), user)])
+                    .context("Failed to make context of member removal")?;
+                let entities = self.encode_group_lineage_entities(by, &lineage)?;
+                let entities = cedar_policy::Entities::from_entities(entities, None)
+                    .context("Failed to make cedar entities")?;
+                let policies = engine.link_templates(&resource)?;
+                (resource, entities, context, policies)
+            }
+            AcceptGroupInvite(code) => {
+                // 存在しない招待コードは拒否せず, 受け入れる処理で not found にする
+                let Some(invite) = repo.get_group_invite_entity(code).await? else {
+                    return Ok(service::Judgement::Allow);
+                };
+                let lineage = fetch_group_lineage(&repo, invite.group_id).await?;
+                if lineage.is_empty() {
+                    return Ok(service::Judgement::Deny);
+                }
+                let resource = self.encode_group_invite_code(code)?;
                 let entities = {
-                    let principal = match by {
-                        service::Principal::User(user_id) if old_members.contains(&user_id) => {
-                            self.encode_principal_entity(by, [id])?
-                        }
-                        service::Principal::User(_) | service::Principal::Anonymous => {
-                            self.encode_principal_entity(by, std::iter::empty())?
-                        }
-                    };
-                    let update_group = self.encode_group_entity(id, members)?;
-                    cedar_policy::Entities::from_entities([principal, update_group], None)
+                    let mut entities = self.encode_group_lineage_entities(by, &lineage)?;
+                    entities.push(self.encode_group_invite_entity(&invite)?);
+                    cedar_policy::Entities::from_entities(entities, None)
                         .context("Failed to make cedar entities")?
                 };
-                let policies = {
-                    let mut p = engine.policies.clone();
-                    let template = p
-                        .templates()
-                        .find(|t| {
-                            t.annotation("id")
-                                .is_some_and(|i| i == "permit-update-group-members")
-                        })
-                        .context(
-                            r#"Policy template @id("permit-update-group-members") not found"#,
-                        )?;
-                    let template_id = template.id().clone();
-                    let policy_id =
-                        cedar_policy::PolicyId::new(format!("permit-update-group-{id}"));
-                    let env: std::collections::HashMap<_, _> =
-                        [(cedar_policy::SlotId::principal(), self.encode_group_id(id)?)]
-                            .into_iter()
-                            .collect();
-                    p.link(template_id, policy_id, env).context(
-                        r#"Failed to link the policy template @id("permit-update-group-members")"#,
-                    )?;
-                    p
+                let context = self.encode_now_context()?;
+                (resource, entities, context, engine.policies.clone())
+            }
+            DecideGroupJoinRequest { id, request_id } => {
+                let Some(join_request) = repo.get_group_join_request_entity(request_id).await?
+                else {
+                    return Ok(service::Judgement::Deny);
+                };
+                // 別グループのリクエストは扱えない
+                if join_request.group_id != id {
+                    return Ok(service::Judgement::Deny);
+                }
+                let lineage = fetch_group_lineage(&repo, id).await?;
+                if lineage.is_empty() {
+                    return Ok(service::Judgement::Deny);
+                }
+                let resource = self.encode_group_join_request_id(request_id)?;
+                let entities = {
+                    let mut entities = self.encode_group_lineage_entities(by, &lineage)?;
+                    entities.push(self.encode_group_join_request_entity(&join_request)?);
+                    cedar_policy::Entities::from_entities(entities, None)
+                        .context("Failed to make cedar entities")?
                 };
-                (action, resource, entities, policies)
+                let context = cedar_policy::Context::empty();
+                let policies = engine.link_templates(&self.encode_group_id(id)?)?;
+                (resource, entities, context, policies)
             }
         };
-        let context = cedar_policy::Context::empty();
         let request = self.make_request(by, action, resource, context)?;
-        let response = self
-            .authorizer()
-            .is_authorized(&request, &policies, &entities);
-        Ok(self.read_response(response))
+        Ok(self.authorize(by, &request, &policies, &entities)?)
     }
 }
 
+/// 存在しないグループを変更する要求への判定
+///
+/// 拒否すると 403 になってしまうので許可し, リポジトリに not found を返させます。
+const MISSING_GROUP_JUDGEMENT: service::Judgement = service::Judgement::Allow;
+
+/// `id` のグループとその祖先を近い順に取得します。
+///
+/// グループが存在しなければ空の `Vec` を返します。
+pub(crate) async fn fetch_group_lineage<E>(
+    repo: &impl ProvideGroupEntityRepository<Error = E>,
+    id: domain::GroupId,
+) -> Result<Vec<domain::Group>, E> {
+    let mut lineage: Vec<domain::Group> = Vec::new();
+    let mut next = Some(id);
+    while let Some(id) = next {
+        // 親子関係が循環していても止まるようにする
+        if lineage.iter().any(|g| g.id == id) {
+            break;
+        }
+        let Some(group) = repo.get_group_entity(id).await? else {
+            break;
+        };
+        next = group.parent_id;
+        lineage.push(group);
+    }
+    Ok(lineage)
+}
+
 // MARK: GroupAccessControl for Engine
 
 impl<C, E> service::GroupAccessControl<C, E> for crate::Engine
@@ -312,8 +776,9 @@ where
         &self,
         ctx: C,
         by: service::Principal,
+        filter: domain::ListGroupsFilter,
     ) -> Result<service::Judgement, E> {
-        let r = Request::ListGroups;
+        let r = Request::ListGroups(filter);
         self.process_group_request(by, ctx, r).await
     }
 
@@ -326,6 +791,7 @@ where
     ) -> Result<service::Judgement, E> {
         let r = Request::CreateGroup {
             members: &params.members,
+            parent: params.parent_id,
         };
         self.process_group_request(by, ctx, r).await
     }
@@ -348,7 +814,7 @@ where
         ctx: C,
         by: service::Principal,
         group_id: domain::GroupId,
-        members: &[domain::UserId],
+        members: &[domain::GroupMember],
     ) -> Result<service::Judgement, E> {
         let r = Request::UpdateGroupMembers {
             id: group_id,
@@ -356,4 +822,566 @@ where
         };
         self.process_group_request(by, ctx, r).await
     }
+
+    #[tracing::instrument(skip(self, ctx, members), ret(level = "debug"))]
+    async fn judge_add_group_members(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        members: &[domain::GroupMember],
+    ) -> Result<service::Judgement, E> {
+        let r = Request::AddGroupMembers {
+            id: group_id,
+            members,
+        };
+        self.process_group_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_remove_group_member(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        user_id: domain::UserId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::RemoveGroupMember {
+            id: group_id,
+            user_id,
+        };
+        self.process_group_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_leave_group(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::LeaveGroup(group_id);
+        self.process_group_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_transfer_group_ownership(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        to: domain::UserId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::TransferGroupOwnership { id: group_id, to };
+        self.process_group_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_archive_group(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::ArchiveGroup(group_id);
+        self.process_group_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_delete_group(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::DeleteGroup(group_id);
+        self.process_group_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx, _params), ret(level = "debug"))]
+    async fn judge_create_group_invite(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        _params: &domain::CreateGroupInviteParams,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::CreateGroupInvite(group_id);
+        self.process_group_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx, code), ret(level = "debug"))]
+    async fn judge_accept_group_invite(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        code: &domain::GroupInviteCode,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::AcceptGroupInvite(code);
+        self.process_group_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_list_group_join_requests(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::ListGroupJoinRequests(group_id);
+        self.process_group_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_create_group_join_request(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::RequestToJoinGroup(group_id);
+        self.process_group_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_decide_group_join_request(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        request_id: domain::GroupJoinRequestId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::DecideGroupJoinRequest {
+            id: group_id,
+            request_id,
+        };
+        self.process_group_request(by, ctx, r).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use domain::GroupRole::{Admin, Member, Owner};
+    use service::Judgement::{Allow, Deny};
+
+    use super::Request;
+
+    #[derive(Debug)]
+    struct TestError(anyhow::Error);
+
+    impl std::fmt::Display for TestError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+
+    impl std::error::Error for TestError {}
+
+    impl From<anyhow::Error> for TestError {
+        fn from(err: anyhow::Error) -> Self {
+            TestError(err)
+        }
+    }
+
+    impl crate::Error for TestError {}
+
+    /// メモリ上の entity で判定するためのリポジトリ
+    #[derive(Debug, Default)]
+    struct Entities {
+        groups: HashMap<domain::GroupId, domain::Group>,
+        invites: HashMap<domain::GroupInviteCode, domain::GroupInvite>,
+    }
+
+    impl super::GroupEntityRepository<(), TestError> for Entities {
+        async fn get_group_entity(
+            &self,
+            _ctx: (),
+            id: domain::GroupId,
+        ) -> Result<Option<domain::Group>, TestError> {
+            Ok(self.groups.get(&id).cloned())
+        }
+
+        async fn get_group_invite_entity(
+            &self,
+            _ctx: (),
+            code: &domain::GroupInviteCode,
+        ) -> Result<Option<domain::GroupInvite>, TestError> {
+            Ok(self.invites.get(code).cloned())
+        }
+
+        async fn get_group_join_request_entity(
+            &self,
+            _ctx: (),
+            _id: domain::GroupJoinRequestId,
+        ) -> Result<Option<domain::GroupJoinRequest>, TestError> {
+            Ok(None)
+        }
+    }
+
+    impl super::ProvideGroupEntityRepository for Entities {
+        type Context<'a> = ();
+        type GroupEntityRepository<'a> = Self;
+        type Error = TestError;
+
+        fn context(&self) -> Self::Context<'_> {}
+
+        fn group_entity_repository(&self) -> &Self::GroupEntityRepository<'_> {
+            self
+        }
+    }
+
+    impl Entities {
+        fn add_group(
+            &mut self,
+            members: &[(domain::UserId, domain::GroupRole)],
+            parent_id: Option<domain::GroupId>,
+        ) -> domain::GroupId {
+            let now = chrono::Utc::now();
+            let id = domain::GroupId::new(uuid::Uuid::now_v7());
+            let members = members
+                .iter()
+                .map(|&(user_id, role)| domain::GroupMember { user_id, role })
+                .collect();
+            let group = domain::Group {
+                id,
+                name: "group".to_string(),
+                public: false,
+                description: String::new(),
+                icon_url: None,
+                website_url: None,
+                parent_id,
+                created_at: now,
+                updated_at: now,
+                archived_at: None,
+                members,
+            };
+            self.groups.insert(id, group);
+            id
+        }
+
+        fn group_mut(&mut self, id: domain::GroupId) -> &mut domain::Group {
+            self.groups.get_mut(&id).unwrap()
+        }
+
+        fn add_invite(
+            &mut self,
+            group_id: domain::GroupId,
+            single_use: bool,
+            use_count: i32,
+            expires_in: chrono::TimeDelta,
+        ) -> domain::GroupInviteCode {
+            let now = chrono::Utc::now();
+            let code = domain::GroupInviteCode::new(format!("code{}", self.invites.len()));
+            let invite = domain::GroupInvite {
+                code: code.clone(),
+                group_id,
+                created_by: self.groups[&group_id].members[0].user_id,
+                single_use,
+                use_count,
+                expires_at: now + expires_in,
+                created_at: now,
+            };
+            self.invites.insert(code.clone(), invite);
+            code
+        }
+
+        async fn judge(&self, by: domain::UserId, request: Request<'_>) -> service::Judgement {
+            let engine = crate::Engine::new().unwrap();
+            let by = service::Principal::User(by);
+            engine
+                .process_group_request(by, self, request)
+                .await
+                .unwrap()
+        }
+    }
+
+    fn user() -> domain::UserId {
+        domain::UserId::new(uuid::Uuid::now_v7())
+    }
+
+    fn member(user_id: domain::UserId, role: domain::GroupRole) -> domain::GroupMember {
+        domain::GroupMember { user_id, role }
+    }
+
+    /// オーナー, 管理者, 一般メンバーからなるグループと部外者
+    struct Fixture {
+        entities: Entities,
+        group: domain::GroupId,
+        owner: domain::UserId,
+        admin: domain::UserId,
+        member: domain::UserId,
+        outsider: domain::UserId,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let (owner, admin, member, outsider) = (user(), user(), user(), user());
+            let mut entities = Entities::default();
+            let group =
+                entities.add_group(&[(owner, Owner), (admin, Admin), (member, Member)], None);
+            Self {
+                entities,
+                group,
+                owner,
+                admin,
+                member,
+                outsider,
+            }
+        }
+
+        fn members(&self) -> Vec<domain::GroupMember> {
+            self.entities.groups[&self.group].members.clone()
+        }
+
+        /// オーナー, 管理者, 一般メンバー, 部外者それぞれの判定を返します。
+        async fn judge_each(&self, request: Request<'_>) -> [service::Judgement; 4] {
+            [
+                self.entities.judge(self.owner, request.clone()).await,
+                self.entities.judge(self.admin, request.clone()).await,
+                self.entities.judge(self.member, request.clone()).await,
+                self.entities.judge(self.outsider, request).await,
+            ]
+        }
+    }
+
+    #[tokio::test]
+    async fn roles_on_group_actions() {
+        let f = Fixture::new();
+        let id = f.group;
+        let added = [member(user(), Member)];
+
+        let cases = [
+            (Request::GetGroup(id), [Allow, Allow, Allow, Deny]),
+            (Request::UpdateGroup(id), [Allow, Allow, Deny, Deny]),
+            (Request::ArchiveGroup(id), [Allow, Deny, Deny, Deny]),
+            (Request::DeleteGroup(id), [Allow, Deny, Deny, Deny]),
+            (Request::LeaveGroup(id), [Allow, Allow, Allow, Deny]),
+            (Request::CreateGroupInvite(id), [Allow, Allow, Deny, Deny]),
+            (
+                Request::ListGroupJoinRequests(id),
+                [Allow, Allow, Deny, Deny],
+            ),
+            (
+                Request::AddGroupMembers {
+                    id,
+                    members: &added,
+                },
+                [Allow, Allow, Deny, Deny],
+            ),
+            (
+                Request::RemoveGroupMember {
+                    id,
+                    user_id: f.member,
+                },
+                [Allow, Allow, Deny, Deny],
+            ),
+            (
+                Request::TransferGroupOwnership { id, to: f.member },
+                [Allow, Deny, Deny, Deny],
+            ),
+        ];
+        for (request, expected) in cases {
+            let name = format!("{request:?}");
+            assert_eq!(f.judge_each(request).await, expected, "{name}");
+        }
+    }
+
+    #[tokio::test]
+    async fn join_request_only_from_outsiders_of_public_group() {
+        let mut f = Fixture::new();
+        let id = f.group;
+        let r = Request::RequestToJoinGroup(id);
+        assert_eq!(f.judge_each(r.clone()).await, [Deny, Deny, Deny, Deny]);
+        f.entities.group_mut(id).public = true;
+        assert_eq!(f.judge_each(r).await, [Deny, Deny, Deny, Allow]);
+    }
+
+    #[tokio::test]
+    async fn member_can_remove_only_themselves() {
+        let f = Fixture::new();
+        let id = f.group;
+        let without = |user_id| {
+            f.members()
+                .into_iter()
+                .filter(|m| m.user_id != user_id)
+                .collect::<Vec<_>>()
+        };
+
+        let leave = without(f.member);
+        let r = Request::UpdateGroupMembers {
+            id,
+            members: &leave,
+        };
+        assert_eq!(f.entities.judge(f.member, r).await, Allow);
+
+        let remove_admin = without(f.admin);
+        let r = Request::UpdateGroupMembers {
+            id,
+            members: &remove_admin,
+        };
+        assert_eq!(f.entities.judge(f.member, r).await, Deny);
+
+        let remove_owner = without(f.owner);
+        let r = Request::UpdateGroupMembers {
+            id,
+            members: &remove_owner,
+        };
+        assert_eq!(f.entities.judge(f.member, r).await, Deny);
+
+        let r = Request::RemoveGroupMember {
+            id,
+            user_id: f.admin,
+        };
+        assert_eq!(f.entities.judge(f.member, r).await, Deny);
+    }
+
+    #[tokio::test]
+    async fn admin_cannot_make_owners() {
+        let f = Fixture::new();
+        let id = f.group;
+
+        let promoted: Vec<_> = f
+            .members()
+            .into_iter()
+            .map(|m| {
+                if m.user_id == f.member {
+                    member(m.user_id, Owner)
+                } else {
+                    m
+                }
+            })
+            .collect();
+        let r = || Request::UpdateGroupMembers {
+            id,
+            members: &promoted,
+        };
+        assert_eq!(f.entities.judge(f.admin, r()).await, Deny);
+        assert_eq!(f.entities.judge(f.owner, r()).await, Allow);
+
+        let new_owner = [member(user(), Owner)];
+        let r = || Request::AddGroupMembers {
+            id,
+            members: &new_owner,
+        };
+        assert_eq!(f.entities.judge(f.admin, r()).await, Deny);
+        assert_eq!(f.entities.judge(f.owner, r()).await, Allow);
+
+        let r = Request::TransferGroupOwnership { id, to: f.member };
+        assert_eq!(f.entities.judge(f.admin, r).await, Deny);
+    }
+
+    #[tokio::test]
+    async fn archived_group_is_read_only() {
+        let mut f = Fixture::new();
+        let id = f.group;
+        f.entities.group_mut(id).archived_at = Some(chrono::Utc::now());
+
+        let expected_owner = [
+            (Request::GetGroup(id), Allow),
+            (Request::DeleteGroup(id), Allow),
+            (Request::UpdateGroup(id), Deny),
+            (Request::ArchiveGroup(id), Deny),
+            (Request::LeaveGroup(id), Deny),
+            (Request::CreateGroupInvite(id), Deny),
+            (Request::TransferGroupOwnership { id, to: f.member }, Deny),
+            (
+                Request::RemoveGroupMember {
+                    id,
+                    user_id: f.member,
+                },
+                Deny,
+            ),
+        ];
+        for (request, expected) in expected_owner {
+            let name = format!("{request:?}");
+            assert_eq!(f.entities.judge(f.owner, request).await, expected, "{name}");
+        }
+
+        let code = f
+            .entities
+            .add_invite(id, false, 0, chrono::TimeDelta::days(1));
+        let r = Request::AcceptGroupInvite(&code);
+        assert_eq!(f.entities.judge(f.outsider, r).await, Deny);
+
+        let parent = Some(id);
+        let members = [member(f.owner, Owner)];
+        let r = Request::CreateGroup {
+            members: &members,
+            parent,
+        };
+        assert_eq!(f.entities.judge(f.owner, r).await, Deny);
+    }
+
+    #[tokio::test]
+    async fn ancestor_owner_manages_subgroups() {
+        let (root_owner, parent_owner, parent_admin, child_owner) =
+            (user(), user(), user(), user());
+        let mut entities = Entities::default();
+        let root = entities.add_group(&[(root_owner, Owner)], None);
+        let parent =
+            entities.add_group(&[(parent_owner, Owner), (parent_admin, Admin)], Some(root));
+        let child = entities.add_group(&[(child_owner, Owner)], Some(parent));
+
+        let child_members = [member(child_owner, Owner), member(user(), Member)];
+        let requests = || {
+            [
+                Request::GetGroup(child),
+                Request::UpdateGroup(child),
+                Request::ArchiveGroup(child),
+                Request::DeleteGroup(child),
+                Request::CreateGroupInvite(child),
+                Request::ListGroupJoinRequests(child),
+                Request::UpdateGroupMembers {
+                    id: child,
+                    members: &child_members,
+                },
+            ]
+        };
+        for ancestor_owner in [parent_owner, root_owner] {
+            for request in requests() {
+                let name = format!("{request:?}");
+                assert_eq!(
+                    entities.judge(ancestor_owner, request).await,
+                    Allow,
+                    "{name}"
+                );
+            }
+        }
+        for request in requests() {
+            let name = format!("{request:?}");
+            assert_eq!(entities.judge(parent_admin, request).await, Deny, "{name}");
+        }
+
+        // 子のオーナーは親を管理できない
+        let r = Request::UpdateGroup(parent);
+        assert_eq!(entities.judge(child_owner, r).await, Deny);
+    }
+
+    #[tokio::test]
+    async fn only_valid_invites_can_be_accepted() {
+        let mut f = Fixture::new();
+        let id = f.group;
+        let day = chrono::TimeDelta::days(1);
+
+        let valid = f.entities.add_invite(id, false, 3, day);
+        let expired = f.entities.add_invite(id, false, 0, -day);
+        let unused = f.entities.add_invite(id, true, 0, day);
+        let exhausted = f.entities.add_invite(id, true, 1, day);
+        let cases = [
+            (&valid, Allow),
+            (&expired, Deny),
+            (&unused, Allow),
+            (&exhausted, Deny),
+        ];
+        for (code, expected) in cases {
+            let r = Request::AcceptGroupInvite(code);
+            assert_eq!(f.entities.judge(f.outsider, r).await, expected, "{code}");
+        }
+
+        // メンバーは有効な招待コードでも使えない
+        let r = Request::AcceptGroupInvite(&valid);
+        assert_eq!(f.entities.judge(f.member, r).await, Deny);
+    }
 }
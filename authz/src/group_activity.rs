@@ -0,0 +1,87 @@
+use anyhow::Context;
+use cedar_policy::EntityUid;
+
+use crate::group::{ProvideGroupEntityRepository, fetch_group_lineage};
+
+// MARK: GroupActivityEngine
+
+#[derive(Debug, Clone)]
+pub(crate) struct GroupActivityEngine {
+    policies: cedar_policy::PolicySet,
+    action_list: EntityUid,
+}
+
+impl GroupActivityEngine {
+    pub(crate) const POLICIES: &str = include_str!("policies/group_activity.cedar");
+    pub(crate) const LIST_ID: &str = "list-group-activities";
+
+    pub(crate) fn new() -> anyhow::Result<Self> {
+        use cedar_policy::EntityId;
+
+        let policies = Self::POLICIES
+            .parse()
+            .context("Failed to parse group activity policies")?;
+        let action = crate::Engine::action_type();
+        let list = EntityId::new(Self::LIST_ID);
+        Ok(Self {
+            policies,
+            action_list: EntityUid::from_type_name_and_id(action, list),
+        })
+    }
+}
+
+// MARK: Request
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Request {
+    ListGroupActivities(domain::GroupId),
+}
+
+impl crate::Engine {
+    pub(crate) async fn process_group_activity_request<E, R>(
+        &self,
+        by: service::Principal,
+        repo: R,
+        request: Request,
+    ) -> Result<service::Judgement, E>
+    where
+        E: crate::Error,
+        R: ProvideGroupEntityRepository<Error = E>,
+    {
+        let engine = self.group_activity();
+        let (action, group_id) = match request {
+            Request::ListGroupActivities(group_id) => (engine.action_list.clone(), group_id),
+        };
+        let lineage = fetch_group_lineage(&repo, group_id).await?;
+        if lineage.is_empty() {
+            return Ok(service::Judgement::Deny);
+        }
+        let resource = self.encode_group_id(group_id)?;
+        let entities = self.encode_group_lineage_entities(by, &lineage)?;
+        let entities = cedar_policy::Entities::from_entities(entities, None)
+            .context("Failed to make cedar entities")?;
+        let context = cedar_policy::Context::empty();
+        let policies = crate::link_group_templates(&engine.policies, &resource)?;
+        let request = self.make_request(by, action, resource, context)?;
+        Ok(self.authorize(by, &request, &policies, &entities)?)
+    }
+}
+
+// MARK: GroupActivityAccessControl for Engine
+
+impl<C, E> service::GroupActivityAccessControl<C, E> for crate::Engine
+where
+    C: ProvideGroupEntityRepository<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_list_group_activities(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::ListGroupActivities(group_id);
+        self.process_group_activity_request(by, ctx, r).await
+    }
+}
@@ -0,0 +1,326 @@
+use anyhow::Context;
+use cedar_policy::EntityUid;
+
+use crate::group::{ProvideGroupEntityRepository, fetch_group_lineage};
+
+// MARK: GroupEventEngine
+
+#[derive(Debug, Clone)]
+pub(crate) struct GroupEventEngine {
+    policies: cedar_policy::PolicySet,
+    action_list: EntityUid,
+    action_get: EntityUid,
+    action_create: EntityUid,
+    action_update: EntityUid,
+    action_delete: EntityUid,
+    group_event_type: cedar_policy::EntityTypeName,
+}
+
+impl GroupEventEngine {
+    pub(crate) const POLICIES: &str = include_str!("policies/group_event.cedar");
+    pub(crate) const LIST_ID: &str = "list-group-events";
+    pub(crate) const GET_ID: &str = "get-group-event";
+    pub(crate) const CREATE_ID: &str = "create-group-event";
+    pub(crate) const UPDATE_ID: &str = "update-group-event";
+    pub(crate) const DELETE_ID: &str = "delete-group-event";
+    pub(crate) const MEETING_POLL_TYPE: &str = "GroupEvent";
+
+    pub(crate) fn new() -> anyhow::Result<Self> {
+        use cedar_policy::EntityId;
+
+        let policies = Self::POLICIES
+            .parse()
+            .context("Failed to parse group event policies")?;
+        let action = crate::Engine::action_type();
+        let list = EntityId::new(Self::LIST_ID);
+        let get = EntityId::new(Self::GET_ID);
+        let create = EntityId::new(Self::CREATE_ID);
+        let update = EntityId::new(Self::UPDATE_ID);
+        let delete = EntityId::new(Self::DELETE_ID);
+        Ok(Self {
+            policies,
+            action_list: EntityUid::from_type_name_and_id(action.clone(), list),
+            action_get: EntityUid::from_type_name_and_id(action.clone(), get),
+            action_create: EntityUid::from_type_name_and_id(action.clone(), create),
+            action_update: EntityUid::from_type_name_and_id(action.clone(), update),
+            action_delete: EntityUid::from_type_name_and_id(action, delete),
+            group_event_type: Self::MEETING_POLL_TYPE
+                .parse()
+                .context("Failed to parse group event type")?,
+        })
+    }
+}
+
+// MARK: GroupEventEntityRepository
+
+pub trait GroupEventEntityRepository<Context, E>: Send + Sync {
+    /// イベントが存在しなければ `None` を返します。
+    fn get_group_event_entity(
+        &self,
+        ctx: Context,
+        id: domain::GroupEventId,
+    ) -> impl Future<Output = Result<Option<domain::GroupEvent>, E>> + Send;
+}
+
+impl<R, C, E> GroupEventEntityRepository<C, E> for &R
+where
+    R: GroupEventEntityRepository<C, E>,
+    C: Send,
+{
+    async fn get_group_event_entity(
+        &self,
+        ctx: C,
+        id: domain::GroupEventId,
+    ) -> Result<Option<domain::GroupEvent>, E> {
+        R::get_group_event_entity(self, ctx, id).await
+    }
+}
+
+pub trait ProvideGroupEventEntityRepository: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type GroupEventEntityRepository<'a>: GroupEventEntityRepository<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+    type Error;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn group_event_entity_repository(&self) -> &Self::GroupEventEntityRepository<'_>;
+
+    fn get_group_event_entity(
+        &self,
+        id: domain::GroupEventId,
+    ) -> impl Future<Output = Result<Option<domain::GroupEvent>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_entity_repository()
+            .get_group_event_entity(ctx, id)
+    }
+}
+
+impl<R> ProvideGroupEventEntityRepository for &R
+where
+    R: ProvideGroupEventEntityRepository,
+{
+    type Context<'a>
+        = R::Context<'a>
+    where
+        Self: 'a;
+    type Error = R::Error;
+    type GroupEventEntityRepository<'a>
+        = R::GroupEventEntityRepository<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        R::context(self)
+    }
+    fn group_event_entity_repository(&self) -> &Self::GroupEventEntityRepository<'_> {
+        R::group_event_entity_repository(self)
+    }
+}
+
+// MARK: Request
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Request {
+    ListGroupEvents(domain::GroupId),
+    GetGroupEvent {
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    },
+    CreateGroupEvent(domain::GroupId),
+    UpdateGroupEvent {
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    },
+    DeleteGroupEvent {
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    },
+}
+
+impl Request {
+    /// イベントを持つグループ
+    fn group_id(self) -> domain::GroupId {
+        match self {
+            Self::ListGroupEvents(group_id)
+            | Self::CreateGroupEvent(group_id)
+            | Self::GetGroupEvent { group_id, .. }
+            | Self::UpdateGroupEvent { group_id, .. }
+            | Self::DeleteGroupEvent { group_id, .. } => group_id,
+        }
+    }
+}
+
+impl crate::Engine {
+    /// event -> `GroupEvent` entity
+    ///
+    /// `{ group: Group, created_by?: id }`
+    ///
+    /// 作成者のアカウントが消去されていれば `created_by` を持ちません。
+    fn encode_group_event_entity(
+        &self,
+        event: &domain::GroupEvent,
+    ) -> anyhow::Result<cedar_policy::Entity> {
+        use std::collections::{HashMap, HashSet};
+
+        use cedar_policy::RestrictedExpression;
+
+        let uid = self.encode_group_event_id(event.id)?;
+        let group = RestrictedExpression::new_entity_uid(self.encode_group_id(event.group_id)?);
+        let created_by = event.created_by.map(|id| {
+            (
+                "created_by".to_string(),
+                RestrictedExpression::new_string(id.to_string()),
+            )
+        });
+        let attrs: HashMap<_, _> = [("group".to_string(), group)]
+            .into_iter()
+            .chain(created_by)
+            .collect();
+        cedar_policy::Entity::new(uid, attrs, HashSet::new())
+            .context("Failed to make entity of group event")
+    }
+
+    fn encode_group_event_id(
+        &self,
+        id: domain::GroupEventId,
+    ) -> anyhow::Result<cedar_policy::EntityUid> {
+        let ty = self.group_event().group_event_type.clone();
+        let id = id
+            .to_string()
+            .parse()
+            .context("Failed to parse GroupEventId as entity ID")?;
+        Ok(EntityUid::from_type_name_and_id(ty, id))
+    }
+
+    pub(crate) async fn process_group_event_request<E, R>(
+        &self,
+        by: service::Principal,
+        repo: R,
+        request: Request,
+    ) -> Result<service::Judgement, E>
+    where
+        E: crate::Error,
+        R: ProvideGroupEntityRepository<Error = E> + ProvideGroupEventEntityRepository<Error = E>,
+    {
+        use Request::{
+            CreateGroupEvent, DeleteGroupEvent, GetGroupEvent, ListGroupEvents, UpdateGroupEvent,
+        };
+
+        let engine = self.group_event();
+        let action = match request {
+            ListGroupEvents(_) => engine.action_list.clone(),
+            GetGroupEvent { .. } => engine.action_get.clone(),
+            CreateGroupEvent(_) => engine.action_create.clone(),
+            UpdateGroupEvent { .. } => engine.action_update.clone(),
+            DeleteGroupEvent { .. } => engine.action_delete.clone(),
+        };
+        let (resource, entities) = match request {
+            ListGroupEvents(group_id) | CreateGroupEvent(group_id) => {
+                let lineage = fetch_group_lineage(&repo, group_id).await?;
+                if lineage.is_empty() {
+                    return Ok(service::Judgement::Deny);
+                }
+                let resource = self.encode_group_id(group_id)?;
+                let entities = self.encode_group_lineage_entities(by, &lineage)?;
+                (resource, entities)
+            }
+            GetGroupEvent { group_id, event_id }
+            | UpdateGroupEvent { group_id, event_id }
+            | DeleteGroupEvent { group_id, event_id } => {
+                let Some(event) = repo.get_group_event_entity(event_id).await? else {
+                    return Ok(service::Judgement::Deny);
+                };
+                // 別グループのイベントは扱えない
+                if event.group_id != group_id {
+                    return Ok(service::Judgement::Deny);
+                }
+                let lineage = fetch_group_lineage(&repo, group_id).await?;
+                if lineage.is_empty() {
+                    return Ok(service::Judgement::Deny);
+                }
+                let resource = self.encode_group_event_id(event_id)?;
+                let mut entities = self.encode_group_lineage_entities(by, &lineage)?;
+                entities.push(self.encode_group_event_entity(&event)?);
+                (resource, entities)
+            }
+        };
+        let entities = cedar_policy::Entities::from_entities(entities, None)
+            .context("Failed to make cedar entities")?;
+        let context = cedar_policy::Context::empty();
+        let group = self.encode_group_id(request.group_id())?;
+        let policies = crate::link_group_templates(&engine.policies, &group)?;
+        let request = self.make_request(by, action, resource, context)?;
+        Ok(self.authorize(by, &request, &policies, &entities)?)
+    }
+}
+
+// MARK: GroupEventAccessControl for Engine
+
+impl<C, E> service::GroupEventAccessControl<C, E> for crate::Engine
+where
+    C: ProvideGroupEntityRepository<Error = E> + ProvideGroupEventEntityRepository<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_list_group_events(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::ListGroupEvents(group_id);
+        self.process_group_event_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_get_group_event(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::GetGroupEvent { group_id, event_id };
+        self.process_group_event_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx, _params), ret(level = "debug"))]
+    async fn judge_create_group_event(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        _params: &domain::CreateGroupEventParams,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::CreateGroupEvent(group_id);
+        self.process_group_event_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx, _params), ret(level = "debug"))]
+    async fn judge_update_group_event(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+        _params: &domain::UpdateGroupEventParams,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::UpdateGroupEvent { group_id, event_id };
+        self.process_group_event_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_delete_group_event(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::DeleteGroupEvent { group_id, event_id };
+        self.process_group_event_request(by, ctx, r).await
+    }
+}
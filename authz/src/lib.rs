@@ -1,7 +1,16 @@
+mod account;
+mod announcement;
 mod group;
+mod group_activity;
+mod group_event;
+mod media;
+mod meeting_poll;
 mod user;
 
+pub use announcement::{AnnouncementEntityRepository, ProvideAnnouncementEntityRepository};
 pub use group::{GroupEntityRepository, ProvideGroupEntityRepository};
+pub use group_event::{GroupEventEntityRepository, ProvideGroupEventEntityRepository};
+pub use meeting_poll::{MeetingPollEntityRepository, ProvideMeetingPollEntityRepository};
 
 #[derive(Debug, Clone)]
 pub struct Engine(std::sync::Arc<EngineInner>);
@@ -9,8 +18,14 @@ pub struct Engine(std::sync::Arc<EngineInner>);
 #[derive(Debug, Clone)]
 struct EngineInner {
     authorizer: cedar_policy::Authorizer,
+    account: account::AccountEngine,
     user: user::UserEngine,
     group: group::GroupEngine,
+    group_activity: group_activity::GroupActivityEngine,
+    announcement: announcement::AnnouncementEngine,
+    group_event: group_event::GroupEventEngine,
+    media: media::MediaEngine,
+    meeting_poll: meeting_poll::MeetingPollEngine,
     user_type: cedar_policy::EntityTypeName,
     group_type: cedar_policy::EntityTypeName,
     anonymous_id: cedar_policy::EntityId,
@@ -26,8 +41,14 @@ impl Engine {
         use anyhow::Context;
 
         let authorizer = cedar_policy::Authorizer::new();
+        let account = account::AccountEngine::new()?;
         let user = user::UserEngine::new()?;
         let group = group::GroupEngine::new()?;
+        let group_activity = group_activity::GroupActivityEngine::new()?;
+        let group_event = group_event::GroupEventEngine::new()?;
+        let announcement = announcement::AnnouncementEngine::new()?;
+        let media = media::MediaEngine::new()?;
+        let meeting_poll = meeting_poll::MeetingPollEngine::new()?;
         let user_type = Self::USER_TYPE
             .parse()
             .context("Failed to parse user type")?;
@@ -37,8 +58,14 @@ impl Engine {
         let anonymous_id = cedar_policy::EntityId::new(Self::ANONYMOUS_ID);
         let inner = EngineInner {
             authorizer,
+            account,
             user,
             group,
+            announcement,
+            group_activity,
+            group_event,
+            media,
+            meeting_poll,
             user_type,
             group_type,
             anonymous_id,
@@ -50,6 +77,10 @@ impl Engine {
         &self.0.authorizer
     }
 
+    fn account(&self) -> &account::AccountEngine {
+        &self.0.account
+    }
+
     fn user(&self) -> &user::UserEngine {
         &self.0.user
     }
@@ -58,6 +89,26 @@ impl Engine {
         &self.0.group
     }
 
+    fn announcement(&self) -> &announcement::AnnouncementEngine {
+        &self.0.announcement
+    }
+
+    fn group_activity(&self) -> &group_activity::GroupActivityEngine {
+        &self.0.group_activity
+    }
+
+    fn group_event(&self) -> &group_event::GroupEventEngine {
+        &self.0.group_event
+    }
+
+    fn media(&self) -> &media::MediaEngine {
+        &self.0.media
+    }
+
+    fn meeting_poll(&self) -> &meeting_poll::MeetingPollEngine {
+        &self.0.meeting_poll
+    }
+
     fn user_type(&self) -> &cedar_policy::EntityTypeName {
         &self.0.user_type
     }
@@ -86,7 +137,7 @@ impl Engine {
 
         let uid = match p {
             service::Principal::Anonymous => self.principal_anonymous(),
-            service::Principal::User(id) => {
+            service::Principal::User(id) | service::Principal::PendingDeletion(id) => {
                 let p = format!(r#"User::"{id}""#);
                 p.parse().context("Failed to parse user principal")?
             }
@@ -127,14 +178,21 @@ impl Engine {
         use anyhow::Context;
 
         let uid = self.principal_uid(p)?;
-        let attr_id = match p {
-            service::Principal::Anonymous => "anonymous".to_string(),
-            service::Principal::User(u) => u.to_string(),
+        let (attr_id, deleted) = match p {
+            service::Principal::Anonymous => ("anonymous".to_string(), false),
+            service::Principal::User(u) => (u.to_string(), false),
+            service::Principal::PendingDeletion(u) => (u.to_string(), true),
         };
-        let attrs: HashMap<_, _> = [(
-            "id".to_string(),
-            cedar_policy::RestrictedExpression::new_string(attr_id),
-        )]
+        let attrs: HashMap<_, _> = [
+            (
+                "id".to_string(),
+                cedar_policy::RestrictedExpression::new_string(attr_id),
+            ),
+            (
+                "deleted".to_string(),
+                cedar_policy::RestrictedExpression::new_bool(deleted),
+            ),
+        ]
         .into_iter()
         .collect();
         let groups: HashSet<_> = groups
@@ -169,37 +227,83 @@ impl Engine {
         cedar_policy::Entity::new(uid, attrs, groups).context("Failed to make entity of user")
     }
 
-    fn encode_group_members(
+    fn encode_user_id_set(
         &self,
-        members: &[domain::UserId],
-    ) -> anyhow::Result<cedar_policy::RestrictedExpression> {
+        users: impl IntoIterator<Item = domain::UserId>,
+    ) -> cedar_policy::RestrictedExpression {
         use cedar_policy::RestrictedExpression;
 
-        let members: Vec<_> = members
-            .iter()
-            .map(|m| RestrictedExpression::new_string(m.to_string()))
+        let users: Vec<_> = users
+            .into_iter()
+            .map(|u| RestrictedExpression::new_string(u.to_string()))
             .collect();
-        Ok(RestrictedExpression::new_set(members))
+        RestrictedExpression::new_set(users)
+    }
+
+    /// members -> `{ members: id[], owners: id[], admins: id[] }`
+    fn encode_group_members(
+        &self,
+        members: &[domain::GroupMember],
+    ) -> [(String, cedar_policy::RestrictedExpression); 3] {
+        use domain::GroupRole;
+
+        let with_role = |role: GroupRole| {
+            let users = members
+                .iter()
+                .filter(move |m| m.role == role)
+                .map(|m| m.user_id);
+            self.encode_user_id_set(users)
+        };
+        [
+            (
+                "members".to_string(),
+                self.encode_user_id_set(members.iter().map(|m| m.user_id)),
+            ),
+            ("owners".to_string(), with_role(GroupRole::Owner)),
+            ("admins".to_string(), with_role(GroupRole::Admin)),
+        ]
     }
 
     /// group -> `Group` entity
+    ///
+    /// 親グループがあれば `parent` 属性に入れ, entity の親にもします。
+    /// `ancestor_owners` には祖先のグループすべてのオーナーを渡します。
     fn encode_group_entity(
         &self,
-        id: domain::GroupId,
-        members: &[domain::UserId],
+        group: &domain::Group,
+        ancestor_owners: impl IntoIterator<Item = domain::UserId>,
     ) -> anyhow::Result<cedar_policy::Entity> {
         use std::collections::{HashMap, HashSet};
 
         use anyhow::Context;
 
-        let uid = self.encode_group_id(id)?;
-        let id = cedar_policy::RestrictedExpression::new_string(id.to_string());
-        let members = self.encode_group_members(members)?;
-        let attrs: HashMap<_, _> = [("id".to_string(), id), ("members".to_string(), members)]
-            .into_iter()
-            .collect();
-        cedar_policy::Entity::new(uid, attrs, HashSet::new())
-            .context("Failed to make entity of group")
+        let uid = self.encode_group_id(group.id)?;
+        let id = cedar_policy::RestrictedExpression::new_string(group.id.to_string());
+        let archived = cedar_policy::RestrictedExpression::new_bool(group.archived_at.is_some());
+        let public = cedar_policy::RestrictedExpression::new_bool(group.public);
+        let ancestor_owners = self.encode_user_id_set(ancestor_owners);
+        let parent = group
+            .parent_id
+            .map(|p| self.encode_group_id(p))
+            .transpose()?;
+        let parent_attr = parent.clone().map(|p| {
+            (
+                "parent".to_string(),
+                cedar_policy::RestrictedExpression::new_entity_uid(p),
+            )
+        });
+        let attrs: HashMap<_, _> = [
+            ("id".to_string(), id),
+            ("archived".to_string(), archived),
+            ("public".to_string(), public),
+            ("ancestor_owners".to_string(), ancestor_owners),
+        ]
+        .into_iter()
+        .chain(parent_attr)
+        .chain(self.encode_group_members(&group.members))
+        .collect();
+        let parents: HashSet<_> = parent.into_iter().collect();
+        cedar_policy::Entity::new(uid, attrs, parents).context("Failed to make entity of group")
     }
 
     fn make_request(
@@ -218,6 +322,21 @@ impl Engine {
         Ok(request)
     }
 
+    /// アカウントの状態と `policies` の両方が許すときだけ許可します。
+    fn authorize(
+        &self,
+        by: service::Principal,
+        request: &cedar_policy::Request,
+        policies: &cedar_policy::PolicySet,
+        entities: &cedar_policy::Entities,
+    ) -> anyhow::Result<service::Judgement> {
+        if self.judge_account(by, request)? == service::Judgement::Deny {
+            return Ok(service::Judgement::Deny);
+        }
+        let response = self.authorizer().is_authorized(request, policies, entities);
+        Ok(self.read_response(response))
+    }
+
     fn read_response(&self, response: cedar_policy::Response) -> service::Judgement {
         tracing::debug!(?response);
         for e in response.diagnostics().errors() {
@@ -230,6 +349,30 @@ impl Engine {
     }
 }
 
+/// `policies` のすべての policy template の `?principal` を `group` に結びつけます。
+fn link_group_templates(
+    policies: &cedar_policy::PolicySet,
+    group: &cedar_policy::EntityUid,
+) -> anyhow::Result<cedar_policy::PolicySet> {
+    use anyhow::Context;
+
+    let mut linked = policies.clone();
+    let env: std::collections::HashMap<_, _> = [(cedar_policy::SlotId::principal(), group.clone())]
+        .into_iter()
+        .collect();
+    for template in policies.templates() {
+        let annotation = template.annotation("id").unwrap_or(template.id().as_ref());
+        let policy_id =
+            cedar_policy::PolicyId::new(format!("{annotation}-{}", group.id().unescaped()));
+        linked
+            .link(template.id().clone(), policy_id, env.clone())
+            .with_context(|| {
+                format!(r#"Failed to link the policy template @id("{annotation}")"#)
+            })?;
+    }
+    Ok(linked)
+}
+
 // MARK: trait Error
 
 pub trait Error: domain::Error + From<anyhow::Error> {}
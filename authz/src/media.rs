@@ -0,0 +1,82 @@
+use anyhow::Context;
+use cedar_policy::EntityUid;
+
+// MARK: MediaEngine
+
+#[derive(Debug, Clone)]
+pub(crate) struct MediaEngine {
+    policies: cedar_policy::PolicySet,
+    action_get: EntityUid,
+    media_type: cedar_policy::EntityTypeName,
+}
+
+impl MediaEngine {
+    pub(crate) const POLICIES: &str = include_str!("policies/media.cedar");
+    pub(crate) const GET_ID: &str = "get-media";
+    pub(crate) const MEDIA_TYPE: &str = "Media";
+
+    pub(crate) fn new() -> anyhow::Result<Self> {
+        use cedar_policy::EntityId;
+
+        let policies = Self::POLICIES
+            .parse()
+            .context("Failed to parse media policies")?;
+        let action = crate::Engine::action_type();
+        let get = EntityId::new(Self::GET_ID);
+        let media_type = Self::MEDIA_TYPE
+            .parse()
+            .context("Failed to parse media type")?;
+        Ok(Self {
+            policies,
+            action_get: EntityUid::from_type_name_and_id(action, get),
+            media_type,
+        })
+    }
+}
+
+// MARK: Request
+
+#[derive(Debug, Clone)]
+pub(crate) enum Request {
+    GetMedia(domain::MediaHash),
+}
+
+impl crate::Engine {
+    pub(crate) async fn process_media_request<E: crate::Error>(
+        &self,
+        by: service::Principal,
+        request: Request,
+    ) -> Result<service::Judgement, E> {
+        let engine = self.media();
+        let (action, hash) = match request {
+            Request::GetMedia(hash) => (engine.action_get.clone(), hash),
+        };
+        let resource = EntityUid::from_type_name_and_id(
+            engine.media_type.clone(),
+            cedar_policy::EntityId::new(hash.as_inner()),
+        );
+        let context = cedar_policy::Context::empty();
+        let entities = cedar_policy::Entities::empty();
+        let request = self.make_request(by, action, resource, context)?;
+        Ok(self.authorize(by, &request, &engine.policies, &entities)?)
+    }
+}
+
+// MARK: MediaAccessControl for Engine
+
+impl<C, E> service::MediaAccessControl<C, E> for crate::Engine
+where
+    C: Send + Sync,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip(self, _ctx), ret(level = "debug"))]
+    async fn judge_get_media(
+        &self,
+        _ctx: C,
+        by: service::Principal,
+        hash: &domain::MediaHash,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::GetMedia(hash.clone());
+        self.process_media_request::<E>(by, r).await
+    }
+}
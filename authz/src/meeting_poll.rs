@@ -0,0 +1,332 @@
+use anyhow::Context;
+use cedar_policy::EntityUid;
+
+use crate::group::{ProvideGroupEntityRepository, fetch_group_lineage};
+
+// MARK: MeetingPollEngine
+
+#[derive(Debug, Clone)]
+pub(crate) struct MeetingPollEngine {
+    policies: cedar_policy::PolicySet,
+    action_list: EntityUid,
+    action_get: EntityUid,
+    action_create: EntityUid,
+    action_vote: EntityUid,
+    action_finalize: EntityUid,
+    meeting_poll_type: cedar_policy::EntityTypeName,
+}
+
+impl MeetingPollEngine {
+    pub(crate) const POLICIES: &str = include_str!("policies/meeting_poll.cedar");
+    pub(crate) const LIST_ID: &str = "list-meeting-polls";
+    pub(crate) const GET_ID: &str = "get-meeting-poll";
+    pub(crate) const CREATE_ID: &str = "create-meeting-poll";
+    pub(crate) const VOTE_ID: &str = "vote-meeting-poll";
+    pub(crate) const FINALIZE_ID: &str = "finalize-meeting-poll";
+    pub(crate) const MEETING_POLL_TYPE: &str = "MeetingPoll";
+
+    pub(crate) fn new() -> anyhow::Result<Self> {
+        use cedar_policy::EntityId;
+
+        let policies = Self::POLICIES
+            .parse()
+            .context("Failed to parse meeting poll policies")?;
+        let action = crate::Engine::action_type();
+        let list = EntityId::new(Self::LIST_ID);
+        let get = EntityId::new(Self::GET_ID);
+        let create = EntityId::new(Self::CREATE_ID);
+        let vote = EntityId::new(Self::VOTE_ID);
+        let finalize = EntityId::new(Self::FINALIZE_ID);
+        Ok(Self {
+            policies,
+            action_list: EntityUid::from_type_name_and_id(action.clone(), list),
+            action_get: EntityUid::from_type_name_and_id(action.clone(), get),
+            action_create: EntityUid::from_type_name_and_id(action.clone(), create),
+            action_vote: EntityUid::from_type_name_and_id(action.clone(), vote),
+            action_finalize: EntityUid::from_type_name_and_id(action, finalize),
+            meeting_poll_type: Self::MEETING_POLL_TYPE
+                .parse()
+                .context("Failed to parse meeting poll type")?,
+        })
+    }
+}
+
+// MARK: MeetingPollEntityRepository
+
+pub trait MeetingPollEntityRepository<Context, E>: Send + Sync {
+    /// 日程調整が存在しなければ `None` を返します。
+    fn get_meeting_poll_entity(
+        &self,
+        ctx: Context,
+        id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Option<domain::MeetingPoll>, E>> + Send;
+}
+
+impl<R, C, E> MeetingPollEntityRepository<C, E> for &R
+where
+    R: MeetingPollEntityRepository<C, E>,
+    C: Send,
+{
+    async fn get_meeting_poll_entity(
+        &self,
+        ctx: C,
+        id: domain::MeetingPollId,
+    ) -> Result<Option<domain::MeetingPoll>, E> {
+        R::get_meeting_poll_entity(self, ctx, id).await
+    }
+}
+
+pub trait ProvideMeetingPollEntityRepository: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type MeetingPollEntityRepository<'a>: MeetingPollEntityRepository<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+    type Error;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn meeting_poll_entity_repository(&self) -> &Self::MeetingPollEntityRepository<'_>;
+
+    fn get_meeting_poll_entity(
+        &self,
+        id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Option<domain::MeetingPoll>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_entity_repository()
+            .get_meeting_poll_entity(ctx, id)
+    }
+}
+
+impl<R> ProvideMeetingPollEntityRepository for &R
+where
+    R: ProvideMeetingPollEntityRepository,
+{
+    type Context<'a>
+        = R::Context<'a>
+    where
+        Self: 'a;
+    type Error = R::Error;
+    type MeetingPollEntityRepository<'a>
+        = R::MeetingPollEntityRepository<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        R::context(self)
+    }
+    fn meeting_poll_entity_repository(&self) -> &Self::MeetingPollEntityRepository<'_> {
+        R::meeting_poll_entity_repository(self)
+    }
+}
+
+// MARK: Request
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Request {
+    ListMeetingPolls(domain::GroupId),
+    GetMeetingPoll {
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    },
+    CreateMeetingPoll(domain::GroupId),
+    VoteMeetingPoll {
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    },
+    FinalizeMeetingPoll {
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    },
+}
+
+impl Request {
+    /// 日程調整を行うグループ
+    fn group_id(self) -> domain::GroupId {
+        match self {
+            Self::ListMeetingPolls(group_id)
+            | Self::CreateMeetingPoll(group_id)
+            | Self::GetMeetingPoll { group_id, .. }
+            | Self::VoteMeetingPoll { group_id, .. }
+            | Self::FinalizeMeetingPoll { group_id, .. } => group_id,
+        }
+    }
+}
+
+impl crate::Engine {
+    /// poll -> `MeetingPoll` entity
+    ///
+    /// `{ group: Group, organizer?: id, finalized: bool }`
+    ///
+    /// 作成者のアカウントが消去されていれば `organizer` を持ちません。
+    fn encode_meeting_poll_entity(
+        &self,
+        poll: &domain::MeetingPoll,
+    ) -> anyhow::Result<cedar_policy::Entity> {
+        use std::collections::{HashMap, HashSet};
+
+        use cedar_policy::RestrictedExpression;
+
+        let uid = self.encode_meeting_poll_id(poll.id)?;
+        let group = RestrictedExpression::new_entity_uid(self.encode_group_id(poll.group_id)?);
+        let organizer = poll.organizer_id.map(|id| {
+            (
+                "organizer".to_string(),
+                RestrictedExpression::new_string(id.to_string()),
+            )
+        });
+        let attrs: HashMap<_, _> = [
+            ("group".to_string(), group),
+            (
+                "finalized".to_string(),
+                RestrictedExpression::new_bool(poll.finalized_candidate_id.is_some()),
+            ),
+        ]
+        .into_iter()
+        .chain(organizer)
+        .collect();
+        cedar_policy::Entity::new(uid, attrs, HashSet::new())
+            .context("Failed to make entity of meeting poll")
+    }
+
+    fn encode_meeting_poll_id(
+        &self,
+        id: domain::MeetingPollId,
+    ) -> anyhow::Result<cedar_policy::EntityUid> {
+        let ty = self.meeting_poll().meeting_poll_type.clone();
+        let id = id
+            .to_string()
+            .parse()
+            .context("Failed to parse MeetingPollId as entity ID")?;
+        Ok(EntityUid::from_type_name_and_id(ty, id))
+    }
+
+    pub(crate) async fn process_meeting_poll_request<E, R>(
+        &self,
+        by: service::Principal,
+        repo: R,
+        request: Request,
+    ) -> Result<service::Judgement, E>
+    where
+        E: crate::Error,
+        R: ProvideGroupEntityRepository<Error = E> + ProvideMeetingPollEntityRepository<Error = E>,
+    {
+        use Request::{
+            CreateMeetingPoll, FinalizeMeetingPoll, GetMeetingPoll, ListMeetingPolls,
+            VoteMeetingPoll,
+        };
+
+        let engine = self.meeting_poll();
+        let action = match request {
+            ListMeetingPolls(_) => engine.action_list.clone(),
+            GetMeetingPoll { .. } => engine.action_get.clone(),
+            CreateMeetingPoll(_) => engine.action_create.clone(),
+            VoteMeetingPoll { .. } => engine.action_vote.clone(),
+            FinalizeMeetingPoll { .. } => engine.action_finalize.clone(),
+        };
+        let (resource, entities) = match request {
+            ListMeetingPolls(group_id) | CreateMeetingPoll(group_id) => {
+                let lineage = fetch_group_lineage(&repo, group_id).await?;
+                if lineage.is_empty() {
+                    return Ok(service::Judgement::Deny);
+                }
+                let resource = self.encode_group_id(group_id)?;
+                let entities = self.encode_group_lineage_entities(by, &lineage)?;
+                (resource, entities)
+            }
+            GetMeetingPoll { group_id, poll_id }
+            | VoteMeetingPoll { group_id, poll_id }
+            | FinalizeMeetingPoll { group_id, poll_id } => {
+                let Some(poll) = repo.get_meeting_poll_entity(poll_id).await? else {
+                    return Ok(service::Judgement::Deny);
+                };
+                // 別グループの日程調整は扱えない
+                if poll.group_id != group_id {
+                    return Ok(service::Judgement::Deny);
+                }
+                let lineage = fetch_group_lineage(&repo, group_id).await?;
+                if lineage.is_empty() {
+                    return Ok(service::Judgement::Deny);
+                }
+                let resource = self.encode_meeting_poll_id(poll_id)?;
+                let mut entities = self.encode_group_lineage_entities(by, &lineage)?;
+                entities.push(self.encode_meeting_poll_entity(&poll)?);
+                (resource, entities)
+            }
+        };
+        let entities = cedar_policy::Entities::from_entities(entities, None)
+            .context("Failed to make cedar entities")?;
+        let context = cedar_policy::Context::empty();
+        let group = self.encode_group_id(request.group_id())?;
+        let policies = crate::link_group_templates(&engine.policies, &group)?;
+        let request = self.make_request(by, action, resource, context)?;
+        Ok(self.authorize(by, &request, &policies, &entities)?)
+    }
+}
+
+// MARK: MeetingPollAccessControl for Engine
+
+impl<C, E> service::MeetingPollAccessControl<C, E> for crate::Engine
+where
+    C: ProvideGroupEntityRepository<Error = E> + ProvideMeetingPollEntityRepository<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_list_meeting_polls(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::ListMeetingPolls(group_id);
+        self.process_meeting_poll_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_get_meeting_poll(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::GetMeetingPoll { group_id, poll_id };
+        self.process_meeting_poll_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx, _params), ret(level = "debug"))]
+    async fn judge_create_meeting_poll(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        _params: &domain::CreateMeetingPollParams,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::CreateMeetingPoll(group_id);
+        self.process_meeting_poll_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_vote_meeting_poll(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::VoteMeetingPoll { group_id, poll_id };
+        self.process_meeting_poll_request(by, ctx, r).await
+    }
+
+    #[tracing::instrument(skip(self, ctx), ret(level = "debug"))]
+    async fn judge_finalize_meeting_poll(
+        &self,
+        ctx: C,
+        by: service::Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::FinalizeMeetingPoll { group_id, poll_id };
+        self.process_meeting_poll_request(by, ctx, r).await
+    }
+}
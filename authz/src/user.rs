@@ -10,8 +10,12 @@ pub(crate) struct UserEngine {
     action_list: EntityUid,
     action_create: EntityUid,
     action_update: EntityUid,
+    action_delete: EntityUid,
+    action_restore: EntityUid,
+    action_export: EntityUid,
     resource_create_user: EntityUid,
     resource_list_users: EntityUid,
+    resource_export_user_data: EntityUid,
 }
 
 impl UserEngine {
@@ -20,8 +24,12 @@ impl UserEngine {
     pub(crate) const LIST_ID: &str = "list-users";
     pub(crate) const CREATE_ID: &str = "create-user";
     pub(crate) const UPDATE_ID: &str = "update-user";
+    pub(crate) const DELETE_ID: &str = "delete-user";
+    pub(crate) const RESTORE_ID: &str = "restore-user";
+    pub(crate) const EXPORT_ID: &str = "export-user-data";
     pub(crate) const CREATE_USER_TYPE: &str = "CreateUser";
     pub(crate) const LIST_USERS_TYPE: &str = "ListUsers";
+    pub(crate) const EXPORT_USER_DATA_TYPE: &str = "ExportUserData";
 
     pub(crate) fn new() -> anyhow::Result<Self> {
         use cedar_policy::EntityId;
@@ -34,18 +42,27 @@ impl UserEngine {
         let list = EntityId::new(Self::LIST_ID);
         let create = EntityId::new(Self::CREATE_ID);
         let update = EntityId::new(Self::UPDATE_ID);
+        let delete = EntityId::new(Self::DELETE_ID);
+        let restore = EntityId::new(Self::RESTORE_ID);
+        let export = EntityId::new(Self::EXPORT_ID);
         let resource_create_user =
             EntityUid::from_type_name_and_id(Self::create_user_type()?, EntityId::new(""));
         let resource_list_users =
             EntityUid::from_type_name_and_id(Self::list_users_type()?, EntityId::new(""));
+        let resource_export_user_data =
+            EntityUid::from_type_name_and_id(Self::export_user_data_type()?, EntityId::new(""));
         Ok(Self {
             policies,
             action_get: EntityUid::from_type_name_and_id(action.clone(), get),
             action_list: EntityUid::from_type_name_and_id(action.clone(), list),
             action_create: EntityUid::from_type_name_and_id(action.clone(), create),
-            action_update: EntityUid::from_type_name_and_id(action, update),
+            action_update: EntityUid::from_type_name_and_id(action.clone(), update),
+            action_delete: EntityUid::from_type_name_and_id(action.clone(), delete),
+            action_restore: EntityUid::from_type_name_and_id(action.clone(), restore),
+            action_export: EntityUid::from_type_name_and_id(action, export),
             resource_create_user,
             resource_list_users,
+            resource_export_user_data,
         })
     }
 
@@ -60,6 +77,12 @@ impl UserEngine {
             .parse()
             .context("Failed to parse list user type")
     }
+
+    fn export_user_data_type() -> anyhow::Result<cedar_policy::EntityTypeName> {
+        Self::EXPORT_USER_DATA_TYPE
+            .parse()
+            .context("Failed to parse export user data type")
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -68,6 +91,9 @@ pub(crate) enum Request {
     ListUsers,
     CreateUser,
     UpdateUser(domain::UserId),
+    DeleteUser(domain::UserId),
+    RestoreUser(domain::UserId),
+    ExportUserData,
 }
 
 impl crate::Engine {
@@ -76,7 +102,9 @@ impl crate::Engine {
         by: service::Principal,
         request: Request,
     ) -> Result<service::Judgement, E> {
-        use Request::{CreateUser, GetUser, ListUsers, UpdateUser};
+        use Request::{
+            CreateUser, DeleteUser, ExportUserData, GetUser, ListUsers, RestoreUser, UpdateUser,
+        };
 
         let engine = self.user();
         let action = match &request {
@@ -84,29 +112,38 @@ impl crate::Engine {
             ListUsers => engine.action_list.clone(),
             CreateUser => engine.action_create.clone(),
             UpdateUser(_) => engine.action_update.clone(),
+            DeleteUser(_) => engine.action_delete.clone(),
+            RestoreUser(_) => engine.action_restore.clone(),
+            ExportUserData => engine.action_export.clone(),
         };
         let resource = match &request {
             GetUser(user_id) => self.encode_user_id(*user_id)?,
             ListUsers => engine.resource_list_users.clone(),
             CreateUser => engine.resource_create_user.clone(),
-            UpdateUser(user_id) => self.encode_user_id(*user_id)?,
+            ExportUserData => engine.resource_export_user_data.clone(),
+            UpdateUser(user_id) | DeleteUser(user_id) | RestoreUser(user_id) => {
+                self.encode_user_id(*user_id)?
+            }
         };
         let context = cedar_policy::Context::empty();
         let entities = match &request {
-            GetUser(_) | ListUsers | CreateUser => cedar_policy::Entities::empty(),
-            UpdateUser(user_id) => {
+            GetUser(_) | ListUsers | CreateUser | ExportUserData => cedar_policy::Entities::empty(),
+            UpdateUser(user_id) | DeleteUser(user_id) | RestoreUser(user_id) => {
                 let principal_entity = self.encode_principal_entity(by, std::iter::empty())?;
-                let resource_entity = self.encode_user_entity(*user_id, std::iter::empty())?;
-                cedar_policy::Entities::from_entities([principal_entity, resource_entity], None)
+                // 自身が対象のときは principal entity が resource entity を兼ねる
+                let resource_entity = if self.principal_uid(by)? == resource {
+                    None
+                } else {
+                    Some(self.encode_user_entity(*user_id, std::iter::empty())?)
+                };
+                let entities = std::iter::once(principal_entity).chain(resource_entity);
+                cedar_policy::Entities::from_entities(entities, None)
                     .context("Failed to make entities of user request")?
             }
         };
         let request = self.make_request(by, action, resource, context)?;
         let policies = &engine.policies;
-        let response = self
-            .authorizer()
-            .is_authorized(&request, policies, &entities);
-        Ok(self.read_response(response))
+        Ok(self.authorize(by, &request, policies, &entities)?)
     }
 }
 
@@ -160,4 +197,35 @@ where
         let r = Request::UpdateUser(user_id);
         self.process_user_request::<E>(by, r).await
     }
+
+    #[tracing::instrument(skip(self, _ctx), ret(level = "debug"))]
+    async fn judge_delete_user(
+        &self,
+        _ctx: C,
+        by: service::Principal,
+        user_id: domain::UserId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::DeleteUser(user_id);
+        self.process_user_request::<E>(by, r).await
+    }
+
+    #[tracing::instrument(skip(self, _ctx), ret(level = "debug"))]
+    async fn judge_restore_user(
+        &self,
+        _ctx: C,
+        by: service::Principal,
+        user_id: domain::UserId,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::RestoreUser(user_id);
+        self.process_user_request::<E>(by, r).await
+    }
+    #[tracing::instrument(skip(self, _ctx), ret(level = "debug"))]
+    async fn judge_export_user_data(
+        &self,
+        _ctx: C,
+        by: service::Principal,
+    ) -> Result<service::Judgement, E> {
+        let r = Request::ExportUserData;
+        self.process_user_request::<E>(by, r).await
+    }
 }
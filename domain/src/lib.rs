@@ -141,8 +141,28 @@ impl std::fmt::Display for UserId {
 pub struct User {
     pub id: UserId,
     pub name: String,
+    /// `@` を除いたユーザー名。英小文字・数字・`_` のみで, 全ユーザーで一意
+    pub handle: Option<String>,
+    pub affiliation: Affiliation,
+    /// 入学年度
+    pub enrollment_year: Option<i32>,
+    /// 自己紹介文
+    pub bio: String,
+    /// アイコン画像の URL
+    pub avatar_url: Option<String>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    /// 削除を申請した日時。猶予期間を過ぎるとアカウントごと消去される
+    pub deleted_at: Option<Timestamp>,
+}
+
+/// 所属 (大学・学部・学科)
+#[must_use]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct Affiliation {
+    pub university: Option<String>,
+    pub faculty: Option<String>,
+    pub department: Option<String>,
 }
 
 #[must_use]
@@ -151,10 +171,69 @@ pub struct CreateUserParams {
     pub name: String,
 }
 
+/// 部分更新のパラメータ。`None` のフィールドは変更しません。
+///
+/// `Option<Option<T>>` のフィールドは `Some(None)` で値を消します。
 #[must_use]
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct UpdateUserParams {
-    pub name: String,
+    pub name: Option<String>,
+    pub handle: Option<Option<String>>,
+    /// 指定すると所属全体を置き換えます。
+    pub affiliation: Option<Affiliation>,
+    pub enrollment_year: Option<Option<i32>>,
+    pub bio: Option<String>,
+    /// 画像のアップロードと削除でのみ設定されます。
+    pub avatar_url: Option<Option<String>>,
+}
+
+/// 所属グループと, その中での役割
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UserGroupMembership {
+    pub group_id: GroupId,
+    pub group_name: String,
+    pub role: GroupRole,
+}
+
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UserMeetingPollVote {
+    pub poll_id: MeetingPollId,
+    pub candidate_id: MeetingPollCandidateId,
+    pub availability: MeetingAvailability,
+    pub updated_at: Timestamp,
+}
+
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UserAnnouncementRead {
+    pub announcement_id: AnnouncementId,
+    pub read_at: Timestamp,
+}
+
+/// ユーザー本人について保存されているデータの一式
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UserDataExport {
+    pub exported_at: Timestamp,
+    pub profile: User,
+    pub group_memberships: Vec<UserGroupMembership>,
+    pub group_join_requests: Vec<GroupJoinRequest>,
+    /// 自身が承認・却下した参加リクエスト
+    pub decided_group_join_requests: Vec<GroupJoinRequest>,
+    /// 自身が作成した招待
+    pub group_invites: Vec<GroupInvite>,
+    pub meeting_poll_votes: Vec<UserMeetingPollVote>,
+    /// 自身が作成した日程調整。投票は自身のものだけを含みます。
+    pub meeting_polls: Vec<MeetingPoll>,
+    /// 自身が作成したイベント
+    pub group_events: Vec<GroupEvent>,
+    /// 自身が書いたお知らせ
+    pub announcements: Vec<Announcement>,
+    pub announcement_reads: Vec<UserAnnouncementRead>,
+    /// 自身が操作したか, 対象となったアクティビティ
+    pub group_activities: Vec<GroupActivity>,
 }
 
 pub trait UserService<Context, E: Error>: Send + Sync {
@@ -174,6 +253,22 @@ pub trait UserService<Context, E: Error>: Send + Sync {
         id: UserId,
         params: UpdateUserParams,
     ) -> impl Future<Output = Result<User, E>> + Send;
+
+    /// アカウントを削除予定にします。猶予期間中は [`UserService::restore_user`] で取り消せます。
+    fn delete_user(&self, ctx: Context, id: UserId)
+    -> impl Future<Output = Result<User, E>> + Send;
+
+    fn restore_user(
+        &self,
+        ctx: Context,
+        id: UserId,
+    ) -> impl Future<Output = Result<User, E>> + Send;
+
+    /// 自身について保存されているデータを書き出します。
+    fn export_user_data(
+        &self,
+        ctx: Context,
+    ) -> impl Future<Output = Result<UserDataExport, E>> + Send;
 }
 
 pub trait ProvideUserService: Send + Sync {
@@ -214,6 +309,21 @@ pub trait ProvideUserService: Send + Sync {
         let ctx = self.context();
         self.user_service().update_user(ctx, id, params)
     }
+
+    fn delete_user(&self, id: UserId) -> impl Future<Output = Result<User, Self::Error>> + Send {
+        let ctx = self.context();
+        self.user_service().delete_user(ctx, id)
+    }
+
+    fn restore_user(&self, id: UserId) -> impl Future<Output = Result<User, Self::Error>> + Send {
+        let ctx = self.context();
+        self.user_service().restore_user(ctx, id)
+    }
+
+    fn export_user_data(&self) -> impl Future<Output = Result<UserDataExport, Self::Error>> + Send {
+        let ctx = self.context();
+        self.user_service().export_user_data(ctx)
+    }
 }
 
 newtype! {
@@ -228,13 +338,42 @@ impl std::fmt::Display for GroupId {
     }
 }
 
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupRole {
+    Owner,
+    Admin,
+    #[default]
+    Member,
+}
+
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupMember {
+    pub user_id: UserId,
+    pub role: GroupRole,
+}
+
 #[must_use]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct GroupCore {
     pub id: GroupId,
     pub name: String,
+    /// 公開グループは参加リクエストを受け付けます。
+    pub public: bool,
+    /// グループの説明文
+    pub description: String,
+    /// アイコン画像の URL
+    pub icon_url: Option<String>,
+    /// 外部サイト (サークルのホームページなど) へのリンク
+    pub website_url: Option<String>,
+    /// 親グループ (研究室に対する学科など)
+    pub parent_id: Option<GroupId>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    pub archived_at: Option<Timestamp>,
 }
 
 #[must_use]
@@ -242,29 +381,146 @@ pub struct GroupCore {
 pub struct Group {
     pub id: GroupId,
     pub name: String,
+    /// 公開グループは参加リクエストを受け付けます。
+    pub public: bool,
+    /// グループの説明文
+    pub description: String,
+    /// アイコン画像の URL
+    pub icon_url: Option<String>,
+    /// 外部サイト (サークルのホームページなど) へのリンク
+    pub website_url: Option<String>,
+    /// 親グループ (研究室に対する学科など)
+    pub parent_id: Option<GroupId>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
-    pub members: Vec<UserId>,
+    pub archived_at: Option<Timestamp>,
+    pub members: Vec<GroupMember>,
+}
+
+impl Group {
+    #[must_use]
+    pub fn role_of(&self, user_id: UserId) -> Option<GroupRole> {
+        self.members
+            .iter()
+            .find(|m| m.user_id == user_id)
+            .map(|m| m.role)
+    }
 }
 
 #[must_use]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct CreateGroupParams {
     pub name: String,
-    pub members: Vec<UserId>,
+    pub public: bool,
+    pub description: String,
+    pub website_url: Option<String>,
+    /// 作成後に親グループは変更できません。
+    pub parent_id: Option<GroupId>,
+    pub members: Vec<GroupMember>,
 }
 
+/// 部分更新のパラメータ。`None` のフィールドは変更しません。
+///
+/// `Option<Option<T>>` のフィールドは `Some(None)` で値を消します。
+/// アイコンは画像のアップロードでのみ変更します。
 #[must_use]
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct UpdateGroupParams {
-    pub name: String,
+    pub name: Option<String>,
+    pub public: Option<bool>,
+    pub description: Option<String>,
+    pub website_url: Option<Option<String>>,
+}
+
+newtype! {
+    #[must_use]
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct GroupInviteCode(String);
+}
+
+impl std::fmt::Display for GroupInviteCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// グループへの招待コード。
+///
+/// `expires_at` を過ぎるか, `single_use` なコードが一度使われると無効になります。
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupInvite {
+    pub code: GroupInviteCode,
+    pub group_id: GroupId,
+    pub created_by: UserId,
+    pub single_use: bool,
+    pub use_count: i32,
+    pub expires_at: Timestamp,
+    pub created_at: Timestamp,
+}
+
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct CreateGroupInviteParams {
+    pub single_use: bool,
+    pub expires_at: Timestamp,
+}
+
+newtype! {
+    #[must_use]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+    pub struct GroupJoinRequestId(uuid::Uuid);
+}
+
+impl std::fmt::Display for GroupJoinRequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupJoinRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// 公開グループへの参加リクエスト。
+///
+/// オーナーか管理者が承認するとメンバーとして追加されます。
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupJoinRequest {
+    pub id: GroupJoinRequestId,
+    pub group_id: GroupId,
+    pub user_id: UserId,
+    pub status: GroupJoinRequestStatus,
+    pub decided_by: Option<UserId>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+/// グループ一覧の絞り込み条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListGroupsFilter {
+    /// 自身が所属するグループ (アーカイブ済みを含む)
+    Joined,
+    /// アーカイブされていない公開グループ
+    Public,
 }
 
 pub trait GroupService<Context, E: Error>: Send + Sync {
     fn get_group(&self, ctx: Context, id: GroupId)
     -> impl Future<Output = Result<Group, E>> + Send;
 
-    fn list_groups(&self, ctx: Context) -> impl Future<Output = Result<Vec<GroupCore>, E>> + Send;
+    fn list_groups(
+        &self,
+        ctx: Context,
+        filter: ListGroupsFilter,
+    ) -> impl Future<Output = Result<Vec<GroupCore>, E>> + Send;
 
     fn create_group(
         &self,
@@ -283,8 +539,81 @@ pub trait GroupService<Context, E: Error>: Send + Sync {
         &self,
         ctx: Context,
         id: GroupId,
-        members: &[UserId],
+        members: &[GroupMember],
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    /// 既存のメンバーはそのままにして `members` を追加します。
+    fn add_group_members(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        members: &[GroupMember],
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    fn remove_group_member(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    fn leave_group(&self, ctx: Context, id: GroupId) -> impl Future<Output = Result<(), E>> + Send;
+
+    /// 呼び出したオーナーは管理者になり, `to` が新しいオーナーになります。
+    fn transfer_group_ownership(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        to: UserId,
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    fn archive_group(
+        &self,
+        ctx: Context,
+        id: GroupId,
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    fn delete_group(&self, ctx: Context, id: GroupId)
+    -> impl Future<Output = Result<(), E>> + Send;
+
+    fn create_group_invite(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        params: CreateGroupInviteParams,
+    ) -> impl Future<Output = Result<GroupInvite, E>> + Send;
+
+    fn accept_group_invite(
+        &self,
+        ctx: Context,
+        code: &GroupInviteCode,
     ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    fn list_group_join_requests(
+        &self,
+        ctx: Context,
+        id: GroupId,
+    ) -> impl Future<Output = Result<Vec<GroupJoinRequest>, E>> + Send;
+
+    fn create_group_join_request(
+        &self,
+        ctx: Context,
+        id: GroupId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, E>> + Send;
+
+    fn approve_group_join_request(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, E>> + Send;
+
+    fn reject_group_join_request(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, E>> + Send;
 }
 
 pub trait ProvideGroupService: Send + Sync {
@@ -304,9 +633,12 @@ pub trait ProvideGroupService: Send + Sync {
         self.group_service().get_group(ctx, id)
     }
 
-    fn list_groups(&self) -> impl Future<Output = Result<Vec<GroupCore>, Self::Error>> + Send {
+    fn list_groups(
+        &self,
+        filter: ListGroupsFilter,
+    ) -> impl Future<Output = Result<Vec<GroupCore>, Self::Error>> + Send {
         let ctx = self.context();
-        self.group_service().list_groups(ctx)
+        self.group_service().list_groups(ctx, filter)
     }
 
     fn create_group(
@@ -329,9 +661,899 @@ pub trait ProvideGroupService: Send + Sync {
     fn update_group_members(
         &self,
         id: GroupId,
-        members: &[UserId],
+        members: &[GroupMember],
     ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
         let ctx = self.context();
         self.group_service().update_group_members(ctx, id, members)
     }
+
+    fn add_group_members(
+        &self,
+        id: GroupId,
+        members: &[GroupMember],
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service().add_group_members(ctx, id, members)
+    }
+
+    fn remove_group_member(
+        &self,
+        id: GroupId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service().remove_group_member(ctx, id, user_id)
+    }
+
+    fn leave_group(&self, id: GroupId) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service().leave_group(ctx, id)
+    }
+
+    fn transfer_group_ownership(
+        &self,
+        id: GroupId,
+        to: UserId,
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service().transfer_group_ownership(ctx, id, to)
+    }
+
+    fn archive_group(
+        &self,
+        id: GroupId,
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service().archive_group(ctx, id)
+    }
+
+    fn delete_group(&self, id: GroupId) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service().delete_group(ctx, id)
+    }
+
+    fn create_group_invite(
+        &self,
+        id: GroupId,
+        params: CreateGroupInviteParams,
+    ) -> impl Future<Output = Result<GroupInvite, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service().create_group_invite(ctx, id, params)
+    }
+
+    fn accept_group_invite(
+        &self,
+        code: &GroupInviteCode,
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service().accept_group_invite(ctx, code)
+    }
+
+    fn list_group_join_requests(
+        &self,
+        id: GroupId,
+    ) -> impl Future<Output = Result<Vec<GroupJoinRequest>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service().list_group_join_requests(ctx, id)
+    }
+
+    fn create_group_join_request(
+        &self,
+        id: GroupId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service().create_group_join_request(ctx, id)
+    }
+
+    fn approve_group_join_request(
+        &self,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service()
+            .approve_group_join_request(ctx, id, request_id)
+    }
+
+    fn reject_group_join_request(
+        &self,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_service()
+            .reject_group_join_request(ctx, id, request_id)
+    }
+}
+
+newtype! {
+    #[must_use]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+    pub struct MeetingPollId(uuid::Uuid);
+}
+
+impl std::fmt::Display for MeetingPollId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+newtype! {
+    #[must_use]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+    pub struct MeetingPollCandidateId(uuid::Uuid);
+}
+
+impl std::fmt::Display for MeetingPollCandidateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingAvailability {
+    Available,
+    Maybe,
+    Unavailable,
+}
+
+/// 日程調整の候補となる時間帯
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct MeetingSlot {
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+}
+
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct MeetingPollCandidate {
+    pub id: MeetingPollCandidateId,
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+}
+
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct MeetingPollVote {
+    pub candidate_id: MeetingPollCandidateId,
+    pub user_id: UserId,
+    pub availability: MeetingAvailability,
+}
+
+/// グループ内の日程調整。
+///
+/// メンバーが各候補に都合を投票し, 作成者かグループのオーナー・管理者が 1 つに確定します。
+/// 確定した候補の時間帯でグループのイベントが作られます。
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct MeetingPoll {
+    pub id: MeetingPollId,
+    pub group_id: GroupId,
+    pub title: String,
+    /// 作成者。アカウントが消去されていれば `None`
+    pub organizer_id: Option<UserId>,
+    /// 開始時刻の早い順
+    pub candidates: Vec<MeetingPollCandidate>,
+    pub votes: Vec<MeetingPollVote>,
+    /// 確定した候補。確定後は投票できません。
+    pub finalized_candidate_id: Option<MeetingPollCandidateId>,
+    /// 確定時に作られたグループのイベント
+    pub event_id: Option<GroupEventId>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct CreateMeetingPollParams {
+    pub title: String,
+    pub candidates: Vec<MeetingSlot>,
+}
+
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct CastMeetingPollVote {
+    pub candidate_id: MeetingPollCandidateId,
+    pub availability: MeetingAvailability,
+}
+
+pub trait MeetingPollService<Context, E: Error>: Send + Sync {
+    fn list_meeting_polls(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<MeetingPoll>, E>> + Send;
+
+    fn get_meeting_poll(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send;
+
+    /// 呼び出したユーザーが作成者になります。
+    fn create_meeting_poll(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        params: CreateMeetingPollParams,
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send;
+
+    /// 呼び出したユーザーの投票を `votes` で置き換えます。
+    fn vote_meeting_poll(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        votes: &[CastMeetingPollVote],
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send;
+
+    /// `candidate_id` の時間帯で日程調整と同名のグループのイベントを作ります。
+    fn finalize_meeting_poll(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        candidate_id: MeetingPollCandidateId,
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send;
+}
+
+pub trait ProvideMeetingPollService: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type Error: Error;
+    type MeetingPollService<'a>: MeetingPollService<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn meeting_poll_service(&self) -> &Self::MeetingPollService<'_>;
+
+    fn list_meeting_polls(
+        &self,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<MeetingPoll>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_service()
+            .list_meeting_polls(ctx, group_id)
+    }
+
+    fn get_meeting_poll(
+        &self,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+    ) -> impl Future<Output = Result<MeetingPoll, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_service()
+            .get_meeting_poll(ctx, group_id, poll_id)
+    }
+
+    fn create_meeting_poll(
+        &self,
+        group_id: GroupId,
+        params: CreateMeetingPollParams,
+    ) -> impl Future<Output = Result<MeetingPoll, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_service()
+            .create_meeting_poll(ctx, group_id, params)
+    }
+
+    fn vote_meeting_poll(
+        &self,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        votes: &[CastMeetingPollVote],
+    ) -> impl Future<Output = Result<MeetingPoll, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_service()
+            .vote_meeting_poll(ctx, group_id, poll_id, votes)
+    }
+
+    fn finalize_meeting_poll(
+        &self,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        candidate_id: MeetingPollCandidateId,
+    ) -> impl Future<Output = Result<MeetingPoll, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_service()
+            .finalize_meeting_poll(ctx, group_id, poll_id, candidate_id)
+    }
+}
+
+newtype! {
+    #[must_use]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+    pub struct GroupEventId(uuid::Uuid);
+}
+
+impl std::fmt::Display for GroupEventId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// イベントの繰り返し規則
+///
+/// `frequency` の `interval` 回ごとに, `until` まで (省略時は無期限) 繰り返します。
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupEventRecurrence {
+    pub frequency: RecurrenceFrequency,
+    pub interval: i32,
+    pub until: Option<Timestamp>,
+}
+
+/// 練習やミーティングなど, 授業以外のグループの予定
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupEvent {
+    pub id: GroupEventId,
+    pub group_id: GroupId,
+    pub title: String,
+    pub location: Option<String>,
+    /// 繰り返すイベントでは初回の時間帯
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+    pub recurrence: Option<GroupEventRecurrence>,
+    /// 作成者。アカウントが消去されていれば `None`
+    pub created_by: Option<UserId>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct CreateGroupEventParams {
+    pub title: String,
+    pub location: Option<String>,
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+    pub recurrence: Option<GroupEventRecurrence>,
+}
+
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UpdateGroupEventParams {
+    pub title: String,
+    pub location: Option<String>,
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+    pub recurrence: Option<GroupEventRecurrence>,
+}
+
+pub trait GroupEventService<Context, E: Error>: Send + Sync {
+    fn list_group_events(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<GroupEvent>, E>> + Send;
+
+    fn get_group_event(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> impl Future<Output = Result<GroupEvent, E>> + Send;
+
+    fn create_group_event(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        params: CreateGroupEventParams,
+    ) -> impl Future<Output = Result<GroupEvent, E>> + Send;
+
+    fn update_group_event(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        event_id: GroupEventId,
+        params: UpdateGroupEventParams,
+    ) -> impl Future<Output = Result<GroupEvent, E>> + Send;
+
+    fn delete_group_event(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> impl Future<Output = Result<(), E>> + Send;
+}
+
+pub trait ProvideGroupEventService: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type Error: Error;
+    type GroupEventService<'a>: GroupEventService<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn group_event_service(&self) -> &Self::GroupEventService<'_>;
+
+    fn list_group_events(
+        &self,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<GroupEvent>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_service().list_group_events(ctx, group_id)
+    }
+
+    fn get_group_event(
+        &self,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> impl Future<Output = Result<GroupEvent, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_service()
+            .get_group_event(ctx, group_id, event_id)
+    }
+
+    fn create_group_event(
+        &self,
+        group_id: GroupId,
+        params: CreateGroupEventParams,
+    ) -> impl Future<Output = Result<GroupEvent, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_service()
+            .create_group_event(ctx, group_id, params)
+    }
+
+    fn update_group_event(
+        &self,
+        group_id: GroupId,
+        event_id: GroupEventId,
+        params: UpdateGroupEventParams,
+    ) -> impl Future<Output = Result<GroupEvent, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_service()
+            .update_group_event(ctx, group_id, event_id, params)
+    }
+
+    fn delete_group_event(
+        &self,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_service()
+            .delete_group_event(ctx, group_id, event_id)
+    }
+}
+
+newtype! {
+    #[must_use]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+    pub struct AnnouncementId(uuid::Uuid);
+}
+
+impl std::fmt::Display for AnnouncementId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// 「今週の練習は休み」のような, グループ内の重要なお知らせ
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct Announcement {
+    pub id: AnnouncementId,
+    pub group_id: GroupId,
+    /// 投稿者。アカウントが消去されていれば `None`
+    pub author_id: Option<UserId>,
+    pub title: String,
+    /// Markdown
+    pub body: String,
+    /// ピン留めされたお知らせは一覧の先頭に並びます。
+    pub pinned: bool,
+    /// 既読にしたメンバー
+    pub read_by: Vec<UserId>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct CreateAnnouncementParams {
+    pub title: String,
+    pub body: String,
+    pub pinned: bool,
+}
+
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UpdateAnnouncementParams {
+    pub title: String,
+    pub body: String,
+    pub pinned: bool,
+}
+
+pub trait AnnouncementService<Context, E: Error>: Send + Sync {
+    fn list_announcements(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<Announcement>, E>> + Send;
+
+    fn get_announcement(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send;
+
+    fn create_announcement(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        params: CreateAnnouncementParams,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send;
+
+    fn update_announcement(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+        params: UpdateAnnouncementParams,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send;
+
+    fn delete_announcement(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<(), E>> + Send;
+
+    /// 呼び出したユーザーの既読を記録します。既に既読なら何もしません。
+    fn mark_announcement_read(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send;
+}
+
+pub trait ProvideAnnouncementService: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type Error: Error;
+    type AnnouncementService<'a>: AnnouncementService<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn announcement_service(&self) -> &Self::AnnouncementService<'_>;
+
+    fn list_announcements(
+        &self,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<Announcement>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_service()
+            .list_announcements(ctx, group_id)
+    }
+
+    fn get_announcement(
+        &self,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<Announcement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_service()
+            .get_announcement(ctx, group_id, announcement_id)
+    }
+
+    fn create_announcement(
+        &self,
+        group_id: GroupId,
+        params: CreateAnnouncementParams,
+    ) -> impl Future<Output = Result<Announcement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_service()
+            .create_announcement(ctx, group_id, params)
+    }
+
+    fn update_announcement(
+        &self,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+        params: UpdateAnnouncementParams,
+    ) -> impl Future<Output = Result<Announcement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_service()
+            .update_announcement(ctx, group_id, announcement_id, params)
+    }
+
+    fn delete_announcement(
+        &self,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_service()
+            .delete_announcement(ctx, group_id, announcement_id)
+    }
+
+    fn mark_announcement_read(
+        &self,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<Announcement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_service()
+            .mark_announcement_read(ctx, group_id, announcement_id)
+    }
+}
+
+newtype! {
+    #[must_use]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+    pub struct GroupActivityId(uuid::Uuid);
+}
+
+impl std::fmt::Display for GroupActivityId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// グループで起きた出来事の種類
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GroupActivityKind {
+    Created,
+    /// アカウントが消去されていれば `user_id` は `None`
+    MemberJoined {
+        user_id: Option<UserId>,
+    },
+    /// 自分で抜けた場合と, 外された場合の両方
+    MemberLeft {
+        user_id: Option<UserId>,
+    },
+    Renamed {
+        from: String,
+        to: String,
+    },
+    /// `user_id` が新しいオーナー。前のオーナーのアカウントの消去で引き継いだ場合は
+    /// `actor_id` が `None` になります。
+    OwnershipTransferred {
+        user_id: Option<UserId>,
+    },
+    IconChanged,
+    DescriptionChanged,
+    MadePublic,
+    MadePrivate,
+    WebsiteChanged,
+    Archived,
+    /// イベントが削除されていれば `event_id` は `None`
+    EventCreated {
+        event_id: Option<GroupEventId>,
+        title: String,
+    },
+    /// イベントが削除されていれば `event_id` は `None`
+    EventUpdated {
+        event_id: Option<GroupEventId>,
+        title: String,
+    },
+    /// 削除時点のタイトルを残します。
+    EventDeleted {
+        title: String,
+    },
+}
+
+/// グループのアクティビティフィードの 1 件
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupActivity {
+    pub id: GroupActivityId,
+    pub group_id: GroupId,
+    /// 操作したユーザー。アカウントが削除されていれば `None`
+    pub actor_id: Option<UserId>,
+    pub kind: GroupActivityKind,
+    pub created_at: Timestamp,
+}
+
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct ListGroupActivitiesParams {
+    /// 前のページの `next_cursor`。省略すると最新から
+    pub cursor: Option<GroupActivityId>,
+    pub limit: u32,
+}
+
+impl ListGroupActivitiesParams {
+    pub const DEFAULT_LIMIT: u32 = 50;
+    pub const MAX_LIMIT: u32 = 100;
+}
+
+/// 新しい順に並んだアクティビティの 1 ページ
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupActivityPage {
+    pub activities: Vec<GroupActivity>,
+    /// 続きがなければ `None`
+    pub next_cursor: Option<GroupActivityId>,
+}
+
+pub trait GroupActivityService<Context, E: Error>: Send + Sync {
+    fn list_group_activities(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        params: ListGroupActivitiesParams,
+    ) -> impl Future<Output = Result<GroupActivityPage, E>> + Send;
+}
+
+pub trait ProvideGroupActivityService: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type Error: Error;
+    type GroupActivityService<'a>: GroupActivityService<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn group_activity_service(&self) -> &Self::GroupActivityService<'_>;
+
+    fn list_group_activities(
+        &self,
+        group_id: GroupId,
+        params: ListGroupActivitiesParams,
+    ) -> impl Future<Output = Result<GroupActivityPage, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_activity_service()
+            .list_group_activities(ctx, group_id, params)
+    }
+}
+
+newtype! {
+    /// メディアの内容の SHA-256 ハッシュ (小文字の 16 進数表記)
+    #[must_use]
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct MediaHash(String);
+}
+
+impl MediaHash {
+    /// `GET /media/{hash}` で配信される URL のパス
+    #[must_use]
+    pub fn url_path(&self) -> String {
+        format!("/media/{}", self.0)
+    }
+
+    /// [`MediaHash::url_path`] の形の URL からハッシュを取り出します。
+    ///
+    /// 外部の URL など, 保存した画像を指さない URL には `None` を返します。
+    #[must_use]
+    pub fn from_url_path(url: &str) -> Option<Self> {
+        url.strip_prefix("/media/")
+            .filter(|hash| !hash.is_empty() && !hash.contains('/'))
+            .map(|hash| Self(hash.to_string()))
+    }
+}
+
+impl std::fmt::Display for MediaHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// アップロードされた画像。内容のハッシュで識別され, 一度保存されると変化しません。
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct Media {
+    pub hash: MediaHash,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+impl Media {
+    /// アップロードできる画像ファイルの最大サイズ (バイト)
+    pub const MAX_UPLOAD_SIZE: usize = 5 * 1024 * 1024;
+}
+
+pub trait MediaService<Context, E: Error>: Send + Sync {
+    fn get_media(
+        &self,
+        ctx: Context,
+        hash: MediaHash,
+    ) -> impl Future<Output = Result<Media, E>> + Send;
+
+    /// 画像を正規化して保存し, ユーザーのアバターに設定します。
+    fn upload_user_avatar(
+        &self,
+        ctx: Context,
+        user_id: UserId,
+        image: Vec<u8>,
+    ) -> impl Future<Output = Result<User, E>> + Send;
+
+    /// ユーザーのアバターを外します。
+    fn delete_user_avatar(
+        &self,
+        ctx: Context,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<User, E>> + Send;
+
+    /// 画像を正規化して保存し, グループのアイコンに設定します。
+    fn upload_group_icon(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        image: Vec<u8>,
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    /// グループのアイコンを外します。
+    fn delete_group_icon(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+}
+
+pub trait ProvideMediaService: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type Error: Error;
+    type MediaService<'a>: MediaService<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn media_service(&self) -> &Self::MediaService<'_>;
+
+    fn get_media(
+        &self,
+        hash: MediaHash,
+    ) -> impl Future<Output = Result<Media, Self::Error>> + Send {
+        let ctx = self.context();
+        self.media_service().get_media(ctx, hash)
+    }
+
+    fn upload_user_avatar(
+        &self,
+        user_id: UserId,
+        image: Vec<u8>,
+    ) -> impl Future<Output = Result<User, Self::Error>> + Send {
+        let ctx = self.context();
+        self.media_service().upload_user_avatar(ctx, user_id, image)
+    }
+
+    fn delete_user_avatar(
+        &self,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<User, Self::Error>> + Send {
+        let ctx = self.context();
+        self.media_service().delete_user_avatar(ctx, user_id)
+    }
+
+    fn upload_group_icon(
+        &self,
+        group_id: GroupId,
+        image: Vec<u8>,
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.media_service().upload_group_icon(ctx, group_id, image)
+    }
+
+    fn delete_group_icon(
+        &self,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.media_service().delete_group_icon(ctx, group_id)
+    }
 }
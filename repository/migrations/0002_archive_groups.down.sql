@@ -0,0 +1,3 @@
+-- Add down migration script here
+
+ALTER TABLE "groups" DROP COLUMN IF EXISTS "archived_at";
@@ -0,0 +1,3 @@
+-- Add up migration script here
+
+ALTER TABLE "groups" ADD COLUMN IF NOT EXISTS "archived_at" TIMESTAMPTZ;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+ALTER TABLE "group_members" DROP COLUMN IF EXISTS "role";
+
+DROP TYPE IF EXISTS "group_role";
@@ -0,0 +1,8 @@
+-- Add up migration script here
+
+CREATE TYPE "group_role" AS ENUM ('owner', 'admin', 'member');
+
+-- existing members could already edit everything, so they keep that as owners
+ALTER TABLE "group_members" ADD COLUMN IF NOT EXISTS "role" "group_role" NOT NULL DEFAULT 'owner';
+
+ALTER TABLE "group_members" ALTER COLUMN "role" SET DEFAULT 'member';
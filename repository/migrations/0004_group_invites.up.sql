@@ -0,0 +1,11 @@
+-- Add up migration script here
+
+CREATE TABLE IF NOT EXISTS group_invites (
+    "code" VARCHAR PRIMARY KEY,
+    "group_id" uuid NOT NULL REFERENCES groups(id) ON DELETE CASCADE,
+    "created_by" uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    "single_use" BOOLEAN NOT NULL,
+    "use_count" INTEGER NOT NULL DEFAULT 0,
+    "expires_at" TIMESTAMPTZ NOT NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,7 @@
+-- Add down migration script here
+
+DROP TABLE IF EXISTS group_join_requests;
+
+DROP TYPE IF EXISTS "join_request_status";
+
+ALTER TABLE "groups" DROP COLUMN IF EXISTS "public";
@@ -0,0 +1,20 @@
+-- Add up migration script here
+
+ALTER TABLE "groups" ADD COLUMN IF NOT EXISTS "public" BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TYPE "join_request_status" AS ENUM ('pending', 'approved', 'rejected');
+
+CREATE TABLE IF NOT EXISTS group_join_requests (
+    "id" uuid PRIMARY KEY,
+    "group_id" uuid NOT NULL REFERENCES groups(id) ON DELETE CASCADE,
+    "user_id" uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    "status" "join_request_status" NOT NULL DEFAULT 'pending',
+    "decided_by" uuid REFERENCES users(id) ON DELETE SET NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    "updated_at" TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- a user can have at most one pending request per group
+CREATE UNIQUE INDEX IF NOT EXISTS group_join_requests_pending_idx
+    ON group_join_requests ("group_id", "user_id")
+    WHERE "status" = 'pending';
@@ -0,0 +1,6 @@
+-- Add down migration script here
+
+ALTER TABLE "groups"
+    DROP COLUMN IF EXISTS "description",
+    DROP COLUMN IF EXISTS "icon_url",
+    DROP COLUMN IF EXISTS "website_url";
@@ -0,0 +1,6 @@
+-- Add up migration script here
+
+ALTER TABLE "groups"
+    ADD COLUMN IF NOT EXISTS "description" TEXT NOT NULL DEFAULT '',
+    ADD COLUMN IF NOT EXISTS "icon_url" VARCHAR,
+    ADD COLUMN IF NOT EXISTS "website_url" VARCHAR;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+DROP INDEX IF EXISTS groups_parent_id_idx;
+
+ALTER TABLE "groups" DROP COLUMN IF EXISTS "parent_id";
@@ -0,0 +1,6 @@
+-- Add up migration script here
+
+-- subgroups become top-level groups when their parent is deleted
+ALTER TABLE "groups" ADD COLUMN IF NOT EXISTS "parent_id" uuid REFERENCES groups(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS groups_parent_id_idx ON groups ("parent_id");
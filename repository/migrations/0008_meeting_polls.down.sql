@@ -0,0 +1,11 @@
+-- Add down migration script here
+
+DROP TABLE IF EXISTS meeting_poll_votes;
+
+ALTER TABLE meeting_polls DROP CONSTRAINT IF EXISTS meeting_polls_finalized_candidate_fkey;
+
+DROP TABLE IF EXISTS meeting_poll_candidates;
+
+DROP TABLE IF EXISTS meeting_polls;
+
+DROP TYPE IF EXISTS "meeting_availability";
@@ -0,0 +1,41 @@
+-- Add up migration script here
+
+CREATE TYPE "meeting_availability" AS ENUM ('available', 'maybe', 'unavailable');
+
+CREATE TABLE IF NOT EXISTS meeting_polls (
+    "id" uuid PRIMARY KEY,
+    "group_id" uuid NOT NULL REFERENCES groups(id) ON DELETE CASCADE,
+    "title" TEXT NOT NULL,
+    "organizer_id" uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    "finalized_candidate_id" uuid,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    "updated_at" TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS meeting_polls_group_id_idx ON meeting_polls ("group_id");
+
+CREATE TABLE IF NOT EXISTS meeting_poll_candidates (
+    "id" uuid PRIMARY KEY,
+    "poll_id" uuid NOT NULL REFERENCES meeting_polls(id) ON DELETE CASCADE,
+    "starts_at" TIMESTAMPTZ NOT NULL,
+    "ends_at" TIMESTAMPTZ NOT NULL,
+    CHECK ("starts_at" < "ends_at"),
+    UNIQUE ("poll_id", "id")
+);
+
+-- the finalized candidate must belong to the same poll
+ALTER TABLE meeting_polls
+    ADD CONSTRAINT meeting_polls_finalized_candidate_fkey
+    FOREIGN KEY ("id", "finalized_candidate_id")
+    REFERENCES meeting_poll_candidates ("poll_id", "id");
+
+CREATE TABLE IF NOT EXISTS meeting_poll_votes (
+    "poll_id" uuid NOT NULL,
+    "candidate_id" uuid NOT NULL,
+    "user_id" uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    "availability" "meeting_availability" NOT NULL,
+    "updated_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY ("candidate_id", "user_id"),
+    FOREIGN KEY ("poll_id", "candidate_id")
+        REFERENCES meeting_poll_candidates ("poll_id", "id") ON DELETE CASCADE
+);
@@ -0,0 +1,7 @@
+-- Add down migration script here
+
+ALTER TABLE meeting_polls DROP COLUMN IF EXISTS "event_id";
+
+DROP TABLE IF EXISTS group_events;
+
+DROP TYPE IF EXISTS "recurrence_frequency";
@@ -0,0 +1,29 @@
+-- Add up migration script here
+
+CREATE TYPE "recurrence_frequency" AS ENUM ('daily', 'weekly', 'monthly');
+
+CREATE TABLE IF NOT EXISTS group_events (
+    "id" uuid PRIMARY KEY,
+    "group_id" uuid NOT NULL REFERENCES groups(id) ON DELETE CASCADE,
+    "title" TEXT NOT NULL,
+    "location" TEXT,
+    "starts_at" TIMESTAMPTZ NOT NULL,
+    "ends_at" TIMESTAMPTZ NOT NULL,
+    "recurrence_frequency" "recurrence_frequency",
+    "recurrence_interval" INTEGER,
+    "recurrence_until" TIMESTAMPTZ,
+    "created_by" uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    "updated_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ("starts_at" < "ends_at"),
+    -- frequency and interval are set together, and until only with them
+    CHECK (("recurrence_frequency" IS NULL) = ("recurrence_interval" IS NULL)),
+    CHECK ("recurrence_frequency" IS NOT NULL OR "recurrence_until" IS NULL),
+    CHECK ("recurrence_interval" >= 1)
+);
+
+CREATE INDEX IF NOT EXISTS group_events_group_id_idx ON group_events ("group_id");
+
+-- the event created when the poll was finalized
+ALTER TABLE meeting_polls
+    ADD COLUMN IF NOT EXISTS "event_id" uuid REFERENCES group_events(id) ON DELETE SET NULL;
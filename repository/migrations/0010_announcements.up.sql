@@ -0,0 +1,21 @@
+-- Add up migration script here
+
+CREATE TABLE IF NOT EXISTS announcements (
+    "id" uuid PRIMARY KEY,
+    "group_id" uuid NOT NULL REFERENCES groups(id) ON DELETE CASCADE,
+    "author_id" uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    "title" TEXT NOT NULL,
+    "body" TEXT NOT NULL,
+    "pinned" BOOLEAN NOT NULL DEFAULT FALSE,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    "updated_at" TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS announcements_group_id_idx ON announcements ("group_id");
+
+CREATE TABLE IF NOT EXISTS announcement_reads (
+    "announcement_id" uuid NOT NULL REFERENCES announcements(id) ON DELETE CASCADE,
+    "user_id" uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    "read_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY ("announcement_id", "user_id")
+);
@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+DROP TABLE IF EXISTS group_activities;
+
+DROP TYPE IF EXISTS "group_activity_kind";
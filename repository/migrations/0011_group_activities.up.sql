@@ -0,0 +1,28 @@
+-- Add up migration script here
+
+CREATE TYPE "group_activity_kind" AS ENUM (
+    'member_joined', 'member_left', 'renamed', 'event_created'
+);
+
+CREATE TABLE IF NOT EXISTS group_activities (
+    "id" uuid PRIMARY KEY,
+    "group_id" uuid NOT NULL REFERENCES groups(id) ON DELETE CASCADE,
+    "actor_id" uuid REFERENCES users(id) ON DELETE SET NULL,
+    "kind" "group_activity_kind" NOT NULL,
+    -- member_joined, member_left
+    "subject_user_id" uuid REFERENCES users(id) ON DELETE CASCADE,
+    -- renamed
+    "old_name" TEXT,
+    "new_name" TEXT,
+    -- event_created; the title is kept even after the event is deleted
+    "event_id" uuid REFERENCES group_events(id) ON DELETE SET NULL,
+    "event_title" TEXT,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ("kind" NOT IN ('member_joined', 'member_left') OR "subject_user_id" IS NOT NULL),
+    CHECK ("kind" <> 'renamed' OR ("old_name" IS NOT NULL AND "new_name" IS NOT NULL)),
+    CHECK ("kind" <> 'event_created' OR "event_title" IS NOT NULL)
+);
+
+-- ids are UUIDv7, so ordering by id is ordering by time
+CREATE INDEX IF NOT EXISTS group_activities_group_id_id_idx
+    ON group_activities ("group_id", "id" DESC);
@@ -0,0 +1,7 @@
+-- Add up migration script here
+
+-- users with "deleted_at" set are purged once the grace period has passed
+ALTER TABLE "users" ADD COLUMN IF NOT EXISTS "deleted_at" TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS users_deleted_at_idx ON users ("deleted_at")
+    WHERE "deleted_at" IS NOT NULL;
@@ -0,0 +1,12 @@
+-- Add down migration script here
+
+DROP INDEX IF EXISTS users_handle_idx;
+
+ALTER TABLE "users"
+    DROP COLUMN IF EXISTS "handle",
+    DROP COLUMN IF EXISTS "university",
+    DROP COLUMN IF EXISTS "faculty",
+    DROP COLUMN IF EXISTS "department",
+    DROP COLUMN IF EXISTS "enrollment_year",
+    DROP COLUMN IF EXISTS "bio",
+    DROP COLUMN IF EXISTS "avatar_url";
@@ -0,0 +1,14 @@
+-- Add up migration script here
+
+ALTER TABLE "users"
+    ADD COLUMN IF NOT EXISTS "handle" VARCHAR
+        CHECK ("handle" ~ '^[a-z0-9_]{3,30}$'),
+    ADD COLUMN IF NOT EXISTS "university" VARCHAR,
+    ADD COLUMN IF NOT EXISTS "faculty" VARCHAR,
+    ADD COLUMN IF NOT EXISTS "department" VARCHAR,
+    ADD COLUMN IF NOT EXISTS "enrollment_year" INTEGER,
+    ADD COLUMN IF NOT EXISTS "bio" TEXT NOT NULL DEFAULT '',
+    ADD COLUMN IF NOT EXISTS "avatar_url" VARCHAR;
+
+-- handles are stored in lowercase, so this is also case-insensitive
+CREATE UNIQUE INDEX IF NOT EXISTS users_handle_idx ON users ("handle");
@@ -0,0 +1,35 @@
+-- Add down migration script here
+
+DELETE FROM "group_activities"
+WHERE "kind" IN ('member_joined', 'member_left') AND "subject_user_id" IS NULL;
+
+ALTER TABLE "group_activities"
+    DROP CONSTRAINT IF EXISTS group_activities_subject_user_id_fkey,
+    ADD CONSTRAINT group_activities_subject_user_id_fkey
+        FOREIGN KEY ("subject_user_id") REFERENCES users(id) ON DELETE CASCADE,
+    ADD CONSTRAINT group_activities_check
+        CHECK ("kind" NOT IN ('member_joined', 'member_left') OR "subject_user_id" IS NOT NULL);
+
+DELETE FROM "announcements" WHERE "author_id" IS NULL;
+
+ALTER TABLE "announcements"
+    DROP CONSTRAINT IF EXISTS announcements_author_id_fkey,
+    ADD CONSTRAINT announcements_author_id_fkey
+        FOREIGN KEY ("author_id") REFERENCES users(id) ON DELETE CASCADE,
+    ALTER COLUMN "author_id" SET NOT NULL;
+
+DELETE FROM "group_events" WHERE "created_by" IS NULL;
+
+ALTER TABLE "group_events"
+    DROP CONSTRAINT IF EXISTS group_events_created_by_fkey,
+    ADD CONSTRAINT group_events_created_by_fkey
+        FOREIGN KEY ("created_by") REFERENCES users(id) ON DELETE CASCADE,
+    ALTER COLUMN "created_by" SET NOT NULL;
+
+DELETE FROM "meeting_polls" WHERE "organizer_id" IS NULL;
+
+ALTER TABLE "meeting_polls"
+    DROP CONSTRAINT IF EXISTS meeting_polls_organizer_id_fkey,
+    ADD CONSTRAINT meeting_polls_organizer_id_fkey
+        FOREIGN KEY ("organizer_id") REFERENCES users(id) ON DELETE CASCADE,
+    ALTER COLUMN "organizer_id" SET NOT NULL;
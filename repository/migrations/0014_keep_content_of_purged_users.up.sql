@@ -0,0 +1,27 @@
+-- Add up migration script here
+
+-- polls, events and announcements belong to the group, so they outlive their creator
+ALTER TABLE "meeting_polls"
+    ALTER COLUMN "organizer_id" DROP NOT NULL,
+    DROP CONSTRAINT IF EXISTS meeting_polls_organizer_id_fkey,
+    ADD CONSTRAINT meeting_polls_organizer_id_fkey
+        FOREIGN KEY ("organizer_id") REFERENCES users(id) ON DELETE SET NULL;
+
+ALTER TABLE "group_events"
+    ALTER COLUMN "created_by" DROP NOT NULL,
+    DROP CONSTRAINT IF EXISTS group_events_created_by_fkey,
+    ADD CONSTRAINT group_events_created_by_fkey
+        FOREIGN KEY ("created_by") REFERENCES users(id) ON DELETE SET NULL;
+
+ALTER TABLE "announcements"
+    ALTER COLUMN "author_id" DROP NOT NULL,
+    DROP CONSTRAINT IF EXISTS announcements_author_id_fkey,
+    ADD CONSTRAINT announcements_author_id_fkey
+        FOREIGN KEY ("author_id") REFERENCES users(id) ON DELETE SET NULL;
+
+-- member_joined / member_left entries stay in the feed without the purged user
+ALTER TABLE "group_activities"
+    DROP CONSTRAINT IF EXISTS group_activities_check,
+    DROP CONSTRAINT IF EXISTS group_activities_subject_user_id_fkey,
+    ADD CONSTRAINT group_activities_subject_user_id_fkey
+        FOREIGN KEY ("subject_user_id") REFERENCES users(id) ON DELETE SET NULL;
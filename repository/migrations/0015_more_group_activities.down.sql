@@ -0,0 +1,26 @@
+-- Add down migration script here
+
+DELETE FROM "group_activities"
+WHERE "kind"::text IN (
+    'created', 'ownership_transferred', 'icon_changed', 'archived', 'event_updated', 'event_deleted'
+);
+
+ALTER TABLE "group_activities"
+    DROP CONSTRAINT IF EXISTS group_activities_event_title_check;
+
+-- enum values cannot be dropped, so the type is recreated
+ALTER TYPE "group_activity_kind" RENAME TO "group_activity_kind_old";
+
+CREATE TYPE "group_activity_kind" AS ENUM (
+    'member_joined', 'member_left', 'renamed', 'event_created'
+);
+
+ALTER TABLE "group_activities"
+    DROP CONSTRAINT IF EXISTS group_activities_check1,
+    ALTER COLUMN "kind" TYPE "group_activity_kind" USING "kind"::text::"group_activity_kind",
+    ADD CONSTRAINT group_activities_check1
+        CHECK ("kind" <> 'renamed' OR ("old_name" IS NOT NULL AND "new_name" IS NOT NULL)),
+    ADD CONSTRAINT group_activities_check2
+        CHECK ("kind" <> 'event_created' OR "event_title" IS NOT NULL);
+
+DROP TYPE "group_activity_kind_old";
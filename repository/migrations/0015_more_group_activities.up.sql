@@ -0,0 +1,16 @@
+-- Add up migration script here
+
+ALTER TYPE "group_activity_kind" ADD VALUE IF NOT EXISTS 'created';
+ALTER TYPE "group_activity_kind" ADD VALUE IF NOT EXISTS 'ownership_transferred';
+ALTER TYPE "group_activity_kind" ADD VALUE IF NOT EXISTS 'icon_changed';
+ALTER TYPE "group_activity_kind" ADD VALUE IF NOT EXISTS 'archived';
+ALTER TYPE "group_activity_kind" ADD VALUE IF NOT EXISTS 'event_updated';
+ALTER TYPE "group_activity_kind" ADD VALUE IF NOT EXISTS 'event_deleted';
+
+-- new enum values cannot be used in the transaction that adds them, so compare as text
+ALTER TABLE "group_activities"
+    DROP CONSTRAINT IF EXISTS group_activities_check2,
+    ADD CONSTRAINT group_activities_event_title_check CHECK (
+        "kind"::text NOT IN ('event_created', 'event_updated', 'event_deleted')
+        OR "event_title" IS NOT NULL
+    );
@@ -0,0 +1,20 @@
+-- Add up migration script here
+
+-- how many avatars and icons point at each stored image;
+-- a blob is deleted only while its row is locked with "ref_count" = 0
+CREATE TABLE IF NOT EXISTS media (
+    "hash" VARCHAR PRIMARY KEY,
+    "ref_count" INTEGER NOT NULL DEFAULT 0 CHECK ("ref_count" >= 0),
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO "media" ("hash", "ref_count")
+SELECT substring(r."url" FROM 8), COUNT(*)
+FROM (
+    SELECT "avatar_url" AS "url" FROM "users"
+    UNION ALL
+    SELECT "icon_url" AS "url" FROM "groups"
+) AS r
+WHERE r."url" ~ '^/media/[^/]+$'
+GROUP BY substring(r."url" FROM 8)
+ON CONFLICT ("hash") DO NOTHING;
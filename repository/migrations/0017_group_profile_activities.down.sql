@@ -0,0 +1,20 @@
+-- Add down migration script here
+
+DELETE FROM "group_activities"
+WHERE "kind"::text IN ('description_changed', 'made_public', 'made_private', 'website_changed');
+
+-- enum values cannot be dropped, so the type is recreated
+ALTER TYPE "group_activity_kind" RENAME TO "group_activity_kind_old";
+
+CREATE TYPE "group_activity_kind" AS ENUM (
+    'member_joined', 'member_left', 'renamed', 'event_created',
+    'created', 'ownership_transferred', 'icon_changed', 'archived', 'event_updated', 'event_deleted'
+);
+
+ALTER TABLE "group_activities"
+    DROP CONSTRAINT IF EXISTS group_activities_check1,
+    ALTER COLUMN "kind" TYPE "group_activity_kind" USING "kind"::text::"group_activity_kind",
+    ADD CONSTRAINT group_activities_check1
+        CHECK ("kind" <> 'renamed' OR ("old_name" IS NOT NULL AND "new_name" IS NOT NULL));
+
+DROP TYPE "group_activity_kind_old";
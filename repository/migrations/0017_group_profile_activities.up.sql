@@ -0,0 +1,6 @@
+-- Add up migration script here
+
+ALTER TYPE "group_activity_kind" ADD VALUE IF NOT EXISTS 'description_changed';
+ALTER TYPE "group_activity_kind" ADD VALUE IF NOT EXISTS 'made_public';
+ALTER TYPE "group_activity_kind" ADD VALUE IF NOT EXISTS 'made_private';
+ALTER TYPE "group_activity_kind" ADD VALUE IF NOT EXISTS 'website_changed';
@@ -0,0 +1,8 @@
+-- consume the invite only while it is still valid
+UPDATE ONLY "group_invites"
+SET "use_count" = "use_count" + 1
+WHERE
+    "code" = $1
+    AND "expires_at" > NOW()
+    AND (NOT "single_use" OR "use_count" = 0)
+RETURNING "group_id"
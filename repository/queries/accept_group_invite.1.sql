@@ -0,0 +1,3 @@
+INSERT INTO "group_members" ("group_id", "user_id", "role")
+VALUES ($1, $2, 'member')
+ON CONFLICT ("group_id", "user_id") DO NOTHING
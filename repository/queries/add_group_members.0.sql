@@ -0,0 +1,4 @@
+-- check group and user existence
+SELECT
+    EXISTS (SELECT 1 FROM "groups" WHERE "id" = $1) AS "group_exists!",
+    (SELECT COUNT(*) FROM "users" WHERE "id" = ANY ($2::uuid[])) AS "user_count!"
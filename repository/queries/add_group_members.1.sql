@@ -0,0 +1,8 @@
+-- existing members keep their current role
+INSERT INTO "group_members" ("group_id", "user_id", "role")
+(
+    SELECT $1 AS "group_id", m."user_id", m."role"
+    FROM unnest($2::uuid[], $3::group_role[]) AS m("user_id", "role")
+)
+ON CONFLICT ("group_id", "user_id") DO NOTHING
+RETURNING "user_id"
@@ -0,0 +1,2 @@
+INSERT INTO "announcements" ("id", "group_id", "author_id", "title", "body", "pinned")
+VALUES ($1, $2, $3, $4, $5, $6)
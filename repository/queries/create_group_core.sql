@@ -1,4 +1,9 @@
-INSERT INTO "groups" ("id", "name", "created_at", "updated_at")
+INSERT INTO "groups" (
+    "id", "name", "public", "description", "website_url", "parent_id",
+    "created_at", "updated_at"
+)
 VALUES
-    ($1, $2, NOW(), NOW())
-RETURNING "id", "name", "created_at", "updated_at"
+    ($1, $2, $3, $4, $5, $6, NOW(), NOW())
+RETURNING
+    "id", "name", "public", "description", "icon_url", "website_url", "parent_id",
+    "created_at", "updated_at", "archived_at"
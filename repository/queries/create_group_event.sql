@@ -0,0 +1,10 @@
+INSERT INTO "group_events" (
+    "id", "group_id", "title", "location", "starts_at", "ends_at",
+    "recurrence_frequency", "recurrence_interval", "recurrence_until", "created_by"
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+RETURNING
+    "id", "group_id", "title", "location", "starts_at", "ends_at",
+    "recurrence_frequency" AS "recurrence_frequency: RecurrenceFrequencyRow",
+    "recurrence_interval", "recurrence_until",
+    "created_by", "created_at", "updated_at"
@@ -0,0 +1,5 @@
+INSERT INTO "group_invites" (
+    "code", "group_id", "created_by", "single_use", "use_count", "expires_at", "created_at"
+)
+VALUES ($1, $2, $3, $4, 0, $5, NOW())
+RETURNING "code", "group_id", "created_by", "single_use", "use_count", "expires_at", "created_at"
@@ -0,0 +1,9 @@
+-- re-submitting while a request is still pending returns the existing one
+INSERT INTO "group_join_requests" ("id", "group_id", "user_id", "created_at", "updated_at")
+VALUES
+    ($1, $2, $3, NOW(), NOW())
+ON CONFLICT ("group_id", "user_id") WHERE "status" = 'pending'
+DO UPDATE SET "updated_at" = NOW()
+RETURNING
+    "id", "group_id", "user_id", "status" AS "status: JoinRequestStatusRow",
+    "decided_by", "created_at", "updated_at"
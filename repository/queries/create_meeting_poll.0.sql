@@ -0,0 +1,3 @@
+INSERT INTO "meeting_polls" ("id", "group_id", "title", "organizer_id", "created_at", "updated_at")
+VALUES
+    ($1, $2, $3, $4, NOW(), NOW())
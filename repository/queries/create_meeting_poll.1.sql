@@ -0,0 +1,5 @@
+INSERT INTO "meeting_poll_candidates" ("id", "poll_id", "starts_at", "ends_at")
+(
+    SELECT c."id", $1 AS "poll_id", c."starts_at", c."ends_at"
+    FROM unnest($2::uuid[], $3::timestamptz[], $4::timestamptz[]) AS c("id", "starts_at", "ends_at")
+)
@@ -1,3 +1,5 @@
 INSERT INTO "users" ("id", "name", "created_at", "updated_at")
 VALUES ($1, $2, NOW(), NOW())
-RETURNING "id", "name", "created_at", "updated_at"
+RETURNING
+    "id", "name", "handle", "university", "faculty", "department", "enrollment_year", "bio",
+    "avatar_url", "created_at", "updated_at", "deleted_at"
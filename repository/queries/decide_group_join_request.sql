@@ -0,0 +1,12 @@
+-- only pending requests can be decided
+UPDATE ONLY "group_join_requests"
+SET "status" = $3,
+    "decided_by" = $4,
+    "updated_at" = NOW()
+WHERE
+    "id" = $2
+    AND "group_id" = $1
+    AND "status" = 'pending'
+RETURNING
+    "id", "group_id", "user_id", "status" AS "status: JoinRequestStatusRow",
+    "decided_by", "created_at", "updated_at"
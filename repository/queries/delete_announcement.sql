@@ -0,0 +1,4 @@
+DELETE FROM "announcements"
+WHERE
+    "id" = $2
+    AND "group_id" = $1
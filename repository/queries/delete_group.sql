@@ -0,0 +1,4 @@
+-- rows in "group_members" are removed by ON DELETE CASCADE
+DELETE FROM "groups"
+WHERE "id" = $1
+RETURNING "icon_url"
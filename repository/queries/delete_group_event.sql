@@ -0,0 +1,5 @@
+DELETE FROM "group_events"
+WHERE
+    "id" = $2
+    AND "group_id" = $1
+RETURNING "title"
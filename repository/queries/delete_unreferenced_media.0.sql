@@ -0,0 +1,4 @@
+-- create the row if missing so that there is always something to lock
+INSERT INTO "media" ("hash", "ref_count")
+VALUES ($1, 0)
+ON CONFLICT ("hash") DO NOTHING
@@ -0,0 +1,5 @@
+-- concurrent retains wait on this lock until the blob and the row are gone
+SELECT "ref_count"
+FROM "media"
+WHERE "hash" = $1
+FOR UPDATE
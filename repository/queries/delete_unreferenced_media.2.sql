@@ -0,0 +1,2 @@
+DELETE FROM "media"
+WHERE "hash" = $1
@@ -0,0 +1,6 @@
+-- lock the memberships of every group the user belongs to, in a fixed order to avoid deadlocks
+SELECT m."group_id", m."user_id"
+FROM "group_members" AS m
+WHERE m."group_id" IN (SELECT "group_id" FROM "group_members" WHERE "user_id" = $1)
+ORDER BY m."group_id", m."user_id"
+FOR UPDATE
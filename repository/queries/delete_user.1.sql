@@ -0,0 +1,13 @@
+-- groups that would be left with members but without an owner
+SELECT COUNT(*) AS "count!"
+FROM "group_members" AS m
+WHERE
+    m."user_id" = $1
+    AND EXISTS (
+        SELECT 1 FROM "group_members" AS o
+        WHERE o."group_id" = m."group_id" AND o."user_id" <> $1
+    )
+    AND NOT EXISTS (
+        SELECT 1 FROM "group_members" AS o
+        WHERE o."group_id" = m."group_id" AND o."user_id" <> $1 AND o."role" = 'owner'
+    )
@@ -0,0 +1,8 @@
+-- keep the original timestamp so that repeating the request does not extend the grace period
+UPDATE ONLY "users"
+SET "deleted_at" = COALESCE("deleted_at", NOW()),
+    "updated_at" = NOW()
+WHERE "id" = $1
+RETURNING
+    "id", "name", "handle", "university", "faculty", "department", "enrollment_year", "bio",
+    "avatar_url", "created_at", "updated_at", "deleted_at"
@@ -0,0 +1,4 @@
+SELECT "announcement_id", "read_at"
+FROM "announcement_reads"
+WHERE "user_id" = $1
+ORDER BY "read_at", "announcement_id"
@@ -0,0 +1,12 @@
+SELECT
+    a."id", a."group_id", a."author_id", a."title", a."body", a."pinned",
+    a."created_at", a."updated_at",
+    r."read_by" AS "read_by!"
+FROM "announcements" AS a
+CROSS JOIN LATERAL (
+    SELECT COALESCE(array_agg("user_id" ORDER BY "read_at", "user_id"), '{}') AS "read_by"
+    FROM "announcement_reads"
+    WHERE "announcement_id" = a."id"
+) AS r
+WHERE a."author_id" = $1
+ORDER BY a."created_at", a."id"
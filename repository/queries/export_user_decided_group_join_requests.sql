@@ -0,0 +1,6 @@
+SELECT
+    "id", "group_id", "user_id", "status" AS "status: JoinRequestStatusRow",
+    "decided_by", "created_at", "updated_at"
+FROM "group_join_requests"
+WHERE "decided_by" = $1
+ORDER BY "created_at", "id"
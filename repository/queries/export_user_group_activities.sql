@@ -0,0 +1,6 @@
+SELECT
+    "id", "group_id", "actor_id", "kind" AS "kind: GroupActivityKindRow",
+    "subject_user_id", "old_name", "new_name", "event_id", "event_title", "created_at"
+FROM "group_activities"
+WHERE "actor_id" = $1 OR "subject_user_id" = $1
+ORDER BY "id"
@@ -0,0 +1,5 @@
+SELECT g."id" AS "group_id", g."name" AS "group_name", m."role" AS "role: GroupRoleRow"
+FROM "group_members" AS m
+JOIN "groups" AS g ON g."id" = m."group_id"
+WHERE m."user_id" = $1
+ORDER BY g."created_at", g."id"
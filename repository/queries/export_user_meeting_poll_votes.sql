@@ -0,0 +1,6 @@
+SELECT
+    "poll_id", "candidate_id", "availability" AS "availability: MeetingAvailabilityRow",
+    "updated_at"
+FROM "meeting_poll_votes"
+WHERE "user_id" = $1
+ORDER BY "updated_at", "candidate_id"
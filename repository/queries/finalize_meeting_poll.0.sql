@@ -0,0 +1,12 @@
+-- the event takes the title of the poll and the time range of the chosen candidate;
+-- the poll row stays locked until commit, so a concurrent finalize waits and then sees it finalized
+INSERT INTO "group_events" ("id", "group_id", "title", "starts_at", "ends_at", "created_by")
+SELECT $4, p."group_id", p."title", c."starts_at", c."ends_at", $5
+FROM "meeting_polls" AS p
+INNER JOIN "meeting_poll_candidates" AS c ON c."poll_id" = p."id"
+WHERE
+    p."id" = $2
+    AND p."group_id" = $1
+    AND c."id" = $3
+    AND p."finalized_candidate_id" IS NULL
+FOR UPDATE OF p
@@ -0,0 +1,9 @@
+-- a poll can be finalized only once
+UPDATE ONLY "meeting_polls"
+SET "finalized_candidate_id" = $3,
+    "event_id" = $4,
+    "updated_at" = NOW()
+WHERE
+    "id" = $2
+    AND "group_id" = $1
+    AND "finalized_candidate_id" IS NULL
@@ -1,6 +1,14 @@
 SELECT
-    "id", "name", "created_at", "updated_at",
-    (SELECT array_agg("user_id") FROM group_members WHERE "group_id" = groups.id) AS "members!"
-FROM "groups"
+    g."id", g."name", g."public", g."description", g."icon_url", g."website_url", g."parent_id",
+    g."created_at", g."updated_at", g."archived_at",
+    m."members" AS "members!", m."roles" AS "roles!: Vec<GroupRoleRow>"
+FROM "groups" AS g
+CROSS JOIN LATERAL (
+    SELECT
+        COALESCE(array_agg("user_id" ORDER BY "user_id"), '{}') AS "members",
+        COALESCE(array_agg("role" ORDER BY "user_id"), '{}') AS "roles"
+    FROM "group_members"
+    WHERE "group_id" = g."id"
+) AS m
 WHERE
-    "id" = $1
+    g."id" = $1
@@ -0,0 +1,8 @@
+SELECT
+    "id", "group_id", "title", "location", "starts_at", "ends_at",
+    "recurrence_frequency" AS "recurrence_frequency: RecurrenceFrequencyRow",
+    "recurrence_interval", "recurrence_until",
+    "created_by", "created_at", "updated_at"
+FROM "group_events"
+WHERE
+    "id" = $1
@@ -0,0 +1,4 @@
+SELECT "code", "group_id", "created_by", "single_use", "use_count", "expires_at", "created_at"
+FROM "group_invites"
+WHERE "code" = $1
+LIMIT 1
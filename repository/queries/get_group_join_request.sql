@@ -0,0 +1,6 @@
+SELECT
+    "id", "group_id", "user_id", "status" AS "status: JoinRequestStatusRow",
+    "decided_by", "created_at", "updated_at"
+FROM "group_join_requests"
+WHERE "id" = $1
+LIMIT 1
@@ -1,3 +0,0 @@
-SELECT "user_id"
-FROM "group_members"
-WHERE "group_id" = $1
@@ -0,0 +1,29 @@
+SELECT
+    p."id", p."group_id", p."title", p."organizer_id", p."finalized_candidate_id", p."event_id",
+    p."created_at", p."updated_at",
+    c."candidate_ids" AS "candidate_ids!", c."starts_at" AS "starts_at!",
+    c."ends_at" AS "ends_at!",
+    v."vote_candidate_ids" AS "vote_candidate_ids!", v."vote_user_ids" AS "vote_user_ids!",
+    v."availabilities" AS "availabilities!: Vec<MeetingAvailabilityRow>"
+FROM "meeting_polls" AS p
+CROSS JOIN LATERAL (
+    SELECT
+        COALESCE(array_agg("id" ORDER BY "starts_at", "id"), '{}') AS "candidate_ids",
+        COALESCE(array_agg("starts_at" ORDER BY "starts_at", "id"), '{}') AS "starts_at",
+        COALESCE(array_agg("ends_at" ORDER BY "starts_at", "id"), '{}') AS "ends_at"
+    FROM "meeting_poll_candidates"
+    WHERE "poll_id" = p."id"
+) AS c
+CROSS JOIN LATERAL (
+    SELECT
+        COALESCE(array_agg("candidate_id" ORDER BY "candidate_id", "user_id"), '{}')
+            AS "vote_candidate_ids",
+        COALESCE(array_agg("user_id" ORDER BY "candidate_id", "user_id"), '{}')
+            AS "vote_user_ids",
+        COALESCE(array_agg("availability" ORDER BY "candidate_id", "user_id"), '{}')
+            AS "availabilities"
+    FROM "meeting_poll_votes"
+    WHERE "poll_id" = p."id"
+) AS v
+WHERE
+    p."id" = $1
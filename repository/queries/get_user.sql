@@ -1,4 +1,6 @@
-SELECT "id", "name", "created_at", "updated_at"
+SELECT
+    "id", "name", "handle", "university", "faculty", "department", "enrollment_year", "bio",
+    "avatar_url", "created_at", "updated_at", "deleted_at"
 FROM "users"
 WHERE "id" = $1
 LIMIT 1
@@ -0,0 +1,9 @@
+SELECT
+    "id", "group_id", "actor_id", "kind" AS "kind: GroupActivityKindRow",
+    "subject_user_id", "old_name", "new_name", "event_id", "event_title", "created_at"
+FROM "group_activities"
+WHERE
+    "group_id" = $1
+    AND ($2::uuid IS NULL OR "id" < $2)
+ORDER BY "id" DESC
+LIMIT $3
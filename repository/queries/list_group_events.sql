@@ -0,0 +1,9 @@
+SELECT
+    "id", "group_id", "title", "location", "starts_at", "ends_at",
+    "recurrence_frequency" AS "recurrence_frequency: RecurrenceFrequencyRow",
+    "recurrence_interval", "recurrence_until",
+    "created_by", "created_at", "updated_at"
+FROM "group_events"
+WHERE
+    "group_id" = $1
+ORDER BY "starts_at", "id"
@@ -1,3 +0,0 @@
-SELECT
-    "id", "name", "created_at", "updated_at"
-FROM "groups"
@@ -0,0 +1,7 @@
+SELECT
+    g."id", g."name", g."public", g."description", g."icon_url", g."website_url", g."parent_id",
+    g."created_at", g."updated_at", g."archived_at"
+FROM "groups" AS g
+JOIN "group_members" AS m ON m."group_id" = g."id"
+WHERE m."user_id" = $1
+ORDER BY g."created_at" DESC
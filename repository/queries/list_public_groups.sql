@@ -0,0 +1,8 @@
+SELECT
+    "id", "name", "public", "description", "icon_url", "website_url", "parent_id",
+    "created_at", "updated_at", "archived_at"
+FROM "groups"
+WHERE
+    "public"
+    AND "archived_at" IS NULL
+ORDER BY "created_at" DESC
@@ -0,0 +1,4 @@
+SELECT "hash"
+FROM "media"
+WHERE "ref_count" = 0
+ORDER BY "hash"
@@ -1,2 +1,6 @@
-SELECT "id", "name", "created_at", "updated_at"
+-- users scheduled for deletion are hidden from the directory
+SELECT
+    "id", "name", "handle", "university", "faculty", "department", "enrollment_year", "bio",
+    "avatar_url", "created_at", "updated_at", "deleted_at"
 FROM "users"
+WHERE "deleted_at" IS NULL
@@ -0,0 +1,5 @@
+-- lock the group and read the values that the activity feed compares against
+SELECT "name", "public", "description", "icon_url", "website_url"
+FROM "groups"
+WHERE "id" = $1
+FOR UPDATE
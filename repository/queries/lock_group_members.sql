@@ -0,0 +1,6 @@
+-- lock the memberships so that concurrent changes see each other's result
+SELECT "group_id", "user_id", "role" AS "role: GroupRoleRow"
+FROM "group_members"
+WHERE "group_id" = $1
+ORDER BY "user_id"
+FOR UPDATE
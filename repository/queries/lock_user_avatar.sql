@@ -0,0 +1,5 @@
+-- lock the user so that the avatar reference counts follow the stored URL
+SELECT "avatar_url"
+FROM "users"
+WHERE "id" = $1
+FOR UPDATE
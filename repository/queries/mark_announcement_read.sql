@@ -0,0 +1,8 @@
+-- marking twice keeps the first read time
+INSERT INTO "announcement_reads" ("announcement_id", "user_id")
+SELECT "id", $3
+FROM "announcements"
+WHERE
+    "id" = $2
+    AND "group_id" = $1
+ON CONFLICT ("announcement_id", "user_id") DO NOTHING
@@ -0,0 +1,4 @@
+SELECT "id"
+FROM "users"
+WHERE "deleted_at" < $1
+FOR UPDATE
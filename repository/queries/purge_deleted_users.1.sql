@@ -0,0 +1,12 @@
+-- groups that only the purged users belong to are removed with them
+DELETE FROM "groups" AS g
+WHERE
+    EXISTS (
+        SELECT 1 FROM "group_members" AS m
+        WHERE m."group_id" = g."id" AND m."user_id" = ANY($1)
+    )
+    AND NOT EXISTS (
+        SELECT 1 FROM "group_members" AS m
+        WHERE m."group_id" = g."id" AND m."user_id" <> ALL($1)
+    )
+RETURNING g."icon_url"
@@ -0,0 +1,5 @@
+-- memberships in the groups that remain, recorded as member_left before they cascade away
+SELECT "group_id", "user_id"
+FROM "group_members"
+WHERE "user_id" = ANY($1)
+ORDER BY "group_id", "user_id"
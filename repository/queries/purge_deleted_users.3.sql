@@ -0,0 +1,20 @@
+-- groups left without an owner get one heir, preferring admins over members
+UPDATE ONLY "group_members" AS m
+SET "role" = 'owner'
+FROM (
+    SELECT DISTINCT ON (r."group_id") r."group_id", r."user_id"
+    FROM "group_members" AS r
+    WHERE
+        r."user_id" <> ALL($1)
+        AND EXISTS (
+            SELECT 1 FROM "group_members" AS p
+            WHERE p."group_id" = r."group_id" AND p."user_id" = ANY($1) AND p."role" = 'owner'
+        )
+        AND NOT EXISTS (
+            SELECT 1 FROM "group_members" AS o
+            WHERE o."group_id" = r."group_id" AND o."user_id" <> ALL($1) AND o."role" = 'owner'
+        )
+    ORDER BY r."group_id", r."role" = 'admin' DESC, r."user_id"
+) AS heir
+WHERE m."group_id" = heir."group_id" AND m."user_id" = heir."user_id"
+RETURNING m."group_id", m."user_id"
@@ -0,0 +1,5 @@
+-- memberships, votes, invites and the like are removed by ON DELETE CASCADE;
+-- polls, events, announcements and activities only lose the reference (ON DELETE SET NULL)
+DELETE FROM "users"
+WHERE "id" = ANY($1)
+RETURNING "avatar_url"
@@ -0,0 +1,5 @@
+INSERT INTO "group_activities" (
+    "id", "group_id", "actor_id", "kind",
+    "subject_user_id", "old_name", "new_name", "event_id", "event_title"
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
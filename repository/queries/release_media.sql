@@ -0,0 +1,9 @@
+-- one reference is dropped per occurrence of a hash; rows missing from the table are ignored
+UPDATE ONLY "media" AS m
+SET "ref_count" = GREATEST(m."ref_count" - r."count", 0)
+FROM (
+    SELECT "hash", COUNT(*)::INTEGER AS "count"
+    FROM UNNEST($1::VARCHAR[]) AS "hash"
+    GROUP BY "hash"
+) AS r
+WHERE m."hash" = r."hash"
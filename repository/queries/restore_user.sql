@@ -0,0 +1,7 @@
+UPDATE ONLY "users"
+SET "deleted_at" = NULL,
+    "updated_at" = NOW()
+WHERE "id" = $1
+RETURNING
+    "id", "name", "handle", "university", "faculty", "department", "enrollment_year", "bio",
+    "avatar_url", "created_at", "updated_at", "deleted_at"
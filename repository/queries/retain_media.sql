@@ -0,0 +1,4 @@
+INSERT INTO "media" ("hash", "ref_count")
+VALUES ($1, 1)
+ON CONFLICT ("hash") DO UPDATE
+SET "ref_count" = "media"."ref_count" + 1
@@ -0,0 +1,22 @@
+WITH g AS (
+    UPDATE ONLY "groups"
+    SET "icon_url" = $2,
+        "updated_at" = NOW()
+    WHERE
+        "id" = $1
+    RETURNING
+        "id", "name", "public", "description", "icon_url", "website_url", "parent_id",
+        "created_at", "updated_at", "archived_at"
+)
+SELECT
+    g."id", g."name", g."public", g."description", g."icon_url", g."website_url", g."parent_id",
+    g."created_at", g."updated_at", g."archived_at",
+    m."members" AS "members!", m."roles" AS "roles!: Vec<GroupRoleRow>"
+FROM g
+CROSS JOIN LATERAL (
+    SELECT
+        COALESCE(array_agg("user_id" ORDER BY "user_id"), '{}') AS "members",
+        COALESCE(array_agg("role" ORDER BY "user_id"), '{}') AS "roles"
+    FROM "group_members"
+    WHERE "group_id" = g."id"
+) AS m
@@ -0,0 +1,6 @@
+-- the previous owner stays in the group as an admin
+UPDATE ONLY "group_members"
+SET "role" = CASE WHEN "user_id" = $3 THEN 'owner'::group_role ELSE 'admin'::group_role END
+WHERE
+    "group_id" = $1
+    AND "user_id" IN ($2, $3)
@@ -0,0 +1,8 @@
+UPDATE ONLY "announcements"
+SET "title" = $3,
+    "body" = $4,
+    "pinned" = $5,
+    "updated_at" = NOW()
+WHERE
+    "id" = $2
+    AND "group_id" = $1
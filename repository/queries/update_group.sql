@@ -1,12 +1,26 @@
+-- fields whose flag is false are left unchanged
 WITH g AS (
     UPDATE ONLY "groups"
-    SET "name" = $2,
+    SET "name" = COALESCE($2, "name"),
+        "public" = COALESCE($3, "public"),
+        "description" = COALESCE($4, "description"),
+        "website_url" = CASE WHEN $5 THEN $6 ELSE "website_url" END,
         "updated_at" = NOW()
     WHERE
         "id" = $1
-    RETURNING "id", "name", "created_at", "updated_at"
+    RETURNING
+        "id", "name", "public", "description", "icon_url", "website_url", "parent_id",
+        "created_at", "updated_at", "archived_at"
 )
 SELECT
-    g."id", g."name", g."created_at", g."updated_at",
-    (SELECT array_agg("user_id") FROM group_members WHERE "group_id" = g.id) AS "members!"
+    g."id", g."name", g."public", g."description", g."icon_url", g."website_url", g."parent_id",
+    g."created_at", g."updated_at", g."archived_at",
+    m."members" AS "members!", m."roles" AS "roles!: Vec<GroupRoleRow>"
 FROM g
+CROSS JOIN LATERAL (
+    SELECT
+        COALESCE(array_agg("user_id" ORDER BY "user_id"), '{}') AS "members",
+        COALESCE(array_agg("role" ORDER BY "user_id"), '{}') AS "roles"
+    FROM "group_members"
+    WHERE "group_id" = g."id"
+) AS m
@@ -0,0 +1,17 @@
+UPDATE ONLY "group_events"
+SET "title" = $3,
+    "location" = $4,
+    "starts_at" = $5,
+    "ends_at" = $6,
+    "recurrence_frequency" = $7,
+    "recurrence_interval" = $8,
+    "recurrence_until" = $9,
+    "updated_at" = NOW()
+WHERE
+    "id" = $2
+    AND "group_id" = $1
+RETURNING
+    "id", "group_id", "title", "location", "starts_at", "ends_at",
+    "recurrence_frequency" AS "recurrence_frequency: RecurrenceFrequencyRow",
+    "recurrence_interval", "recurrence_until",
+    "created_by", "created_at", "updated_at"
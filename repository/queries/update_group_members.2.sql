@@ -1,6 +1,6 @@
-INSERT INTO "group_members" ("group_id", "user_id")
+INSERT INTO "group_members" ("group_id", "user_id", "role")
 (
-    SELECT $1 AS "group_id", "user_id"
-    FROM unnest($2::uuid[]) AS "user_id"
+    SELECT $1 AS "group_id", m."user_id", m."role"
+    FROM unnest($2::uuid[], $3::group_role[]) AS m("user_id", "role")
 )
-RETURNING "group_id", "user_id"
+RETURNING "group_id", "user_id", "role" AS "role: GroupRoleRow"
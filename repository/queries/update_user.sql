@@ -1,5 +1,15 @@
+-- fields whose flag is false are left unchanged
 UPDATE ONLY "users"
-SET "name" = $2,
+SET "name" = COALESCE($2, "name"),
+    "handle" = CASE WHEN $3 THEN $4 ELSE "handle" END,
+    "university" = CASE WHEN $5 THEN $6 ELSE "university" END,
+    "faculty" = CASE WHEN $5 THEN $7 ELSE "faculty" END,
+    "department" = CASE WHEN $5 THEN $8 ELSE "department" END,
+    "enrollment_year" = CASE WHEN $9 THEN $10 ELSE "enrollment_year" END,
+    "bio" = COALESCE($11, "bio"),
+    "avatar_url" = CASE WHEN $12 THEN $13 ELSE "avatar_url" END,
     "updated_at" = NOW()
 WHERE "id" = $1
-RETURNING "id", "name", "created_at", "updated_at"
+RETURNING
+    "id", "name", "handle", "university", "faculty", "department", "enrollment_year", "bio",
+    "avatar_url", "created_at", "updated_at", "deleted_at"
@@ -0,0 +1,5 @@
+-- votes are replaced as a whole
+DELETE FROM "meeting_poll_votes"
+WHERE
+    "poll_id" = $1
+    AND "user_id" = $2
@@ -0,0 +1,5 @@
+INSERT INTO "meeting_poll_votes" ("poll_id", "candidate_id", "user_id", "availability", "updated_at")
+(
+    SELECT $1 AS "poll_id", v."candidate_id", $2 AS "user_id", v."availability", NOW()
+    FROM unnest($3::uuid[], $4::meeting_availability[]) AS v("candidate_id", "availability")
+)
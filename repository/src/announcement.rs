@@ -0,0 +1,275 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
+)]
+pub struct AnnouncementRow {
+    pub id: uuid::Uuid,
+    pub group_id: uuid::Uuid,
+    pub author_id: Option<uuid::Uuid>,
+    pub title: String,
+    pub body: String,
+    pub pinned: bool,
+    pub created_at: domain::Timestamp,
+    pub updated_at: domain::Timestamp,
+    pub read_by: Vec<uuid::Uuid>,
+}
+
+impl From<AnnouncementRow> for domain::Announcement {
+    fn from(row: AnnouncementRow) -> Self {
+        let AnnouncementRow {
+            id,
+            group_id,
+            author_id,
+            title,
+            body,
+            pinned,
+            created_at,
+            updated_at,
+            read_by,
+        } = row;
+        Self {
+            id: domain::AnnouncementId::new(id),
+            group_id: domain::GroupId::new(group_id),
+            author_id: author_id.map(domain::UserId::new),
+            title,
+            body,
+            pinned,
+            read_by: read_by.into_iter().map(domain::UserId::new).collect(),
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+// MARK: impl AnnouncementRepository
+
+impl<C, E> service::AnnouncementRepository<C, E> for crate::Repository
+where
+    C: crate::AsPgPool,
+    E: crate::Error,
+{
+    async fn list_announcements(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+    ) -> Result<Vec<domain::Announcement>, E> {
+        let announcements = sqlx::query_file_as!(
+            AnnouncementRow,
+            "queries/list_announcements.sql",
+            group_id.into_inner()
+        )
+        .fetch_all(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while listing announcements");
+        })
+        .context("Failed to fetch announcements")?;
+        Ok(announcements.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_announcement(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> Result<domain::Announcement, E> {
+        let announcement = sqlx::query_file_as!(
+            AnnouncementRow,
+            "queries/get_announcement.sql",
+            announcement_id.into_inner()
+        )
+        .fetch_optional(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while fetching announcement");
+        })
+        .context("Failed to fetch announcement")?
+        .filter(|a| a.group_id == group_id.into_inner())
+        .ok_or_else(|| E::not_found("Announcement not found"))?;
+        Ok(announcement.into())
+    }
+
+    async fn create_announcement(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        author_id: domain::UserId,
+        params: domain::CreateAnnouncementParams,
+    ) -> Result<domain::Announcement, E> {
+        let id = uuid::Uuid::now_v7();
+        let domain::CreateAnnouncementParams {
+            title,
+            body,
+            pinned,
+        } = params;
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            sqlx::query_file!(
+                "queries/create_announcement.sql",
+                id,
+                group_id.into_inner(),
+                author_id.into_inner(),
+                title,
+                body,
+                pinned
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while creating announcement");
+            })
+            .context("Failed to create announcement")?;
+
+            let announcement = sqlx::query_file_as!(
+                AnnouncementRow,
+                "queries/get_announcement.sql",
+                id
+            )
+            .fetch_one(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while fetching created announcement");
+            })
+            .context("Failed to fetch created announcement")?;
+            Ok(announcement.into())
+        })
+        .await
+    }
+
+    async fn update_announcement(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+        params: domain::UpdateAnnouncementParams,
+    ) -> Result<domain::Announcement, E> {
+        let domain::UpdateAnnouncementParams {
+            title,
+            body,
+            pinned,
+        } = params;
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let result = sqlx::query_file!(
+                "queries/update_announcement.sql",
+                group_id.into_inner(),
+                announcement_id.into_inner(),
+                title,
+                body,
+                pinned
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while updating announcement");
+            })
+            .context("Failed to update announcement")?;
+            if result.rows_affected() == 0 {
+                return Err(E::not_found("Announcement not found"));
+            }
+
+            let announcement = sqlx::query_file_as!(
+                AnnouncementRow,
+                "queries/get_announcement.sql",
+                announcement_id.into_inner()
+            )
+            .fetch_one(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while fetching updated announcement");
+            })
+            .context("Failed to fetch updated announcement")?;
+            Ok(announcement.into())
+        })
+        .await
+    }
+
+    async fn delete_announcement(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> Result<(), E> {
+        let result = sqlx::query_file!(
+            "queries/delete_announcement.sql",
+            group_id.into_inner(),
+            announcement_id.into_inner()
+        )
+        .execute(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while deleting announcement");
+        })
+        .context("Failed to delete announcement")?;
+        if result.rows_affected() == 0 {
+            return Err(E::not_found("Announcement not found"));
+        }
+        Ok(())
+    }
+
+    async fn mark_announcement_read(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+        user_id: domain::UserId,
+    ) -> Result<domain::Announcement, E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            sqlx::query_file!(
+                "queries/mark_announcement_read.sql",
+                group_id.into_inner(),
+                announcement_id.into_inner(),
+                user_id.into_inner()
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while marking announcement as read");
+            })
+            .context("Failed to mark announcement as read")?;
+
+            let announcement = sqlx::query_file_as!(
+                AnnouncementRow,
+                "queries/get_announcement.sql",
+                announcement_id.into_inner()
+            )
+            .fetch_optional(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while fetching read announcement");
+            })
+            .context("Failed to fetch read announcement")?
+            .filter(|a| a.group_id == group_id.into_inner())
+            .ok_or_else(|| E::not_found("Announcement not found"))?;
+            Ok(announcement.into())
+        })
+        .await
+    }
+}
+
+// MARK: impl AnnouncementEntityRepository
+
+impl<C, E> authz::AnnouncementEntityRepository<C, E> for crate::Repository
+where
+    C: crate::AsPgPool,
+    E: crate::Error,
+{
+    async fn get_announcement_entity(
+        &self,
+        ctx: C,
+        id: domain::AnnouncementId,
+    ) -> Result<Option<domain::Announcement>, E> {
+        let announcement = sqlx::query_file_as!(
+            AnnouncementRow,
+            "queries/get_announcement.sql",
+            id.into_inner()
+        )
+        .fetch_optional(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while fetching announcement entity");
+        })
+        .context("Failed to fetch announcement entity")?;
+        Ok(announcement.map(Into::into))
+    }
+}
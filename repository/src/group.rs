@@ -1,15 +1,55 @@
 use anyhow::Context;
 use serde::{Deserialize, Serialize};
 
+use crate::group_activity::{member_changes, record_group_activities};
+use crate::media::{release_media, retain_media};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::Type,
+)]
+#[sqlx(type_name = "group_role", rename_all = "snake_case")]
+pub enum GroupRoleRow {
+    Owner,
+    Admin,
+    Member,
+}
+
+impl From<GroupRoleRow> for domain::GroupRole {
+    fn from(row: GroupRoleRow) -> Self {
+        match row {
+            GroupRoleRow::Owner => Self::Owner,
+            GroupRoleRow::Admin => Self::Admin,
+            GroupRoleRow::Member => Self::Member,
+        }
+    }
+}
+
+impl From<domain::GroupRole> for GroupRoleRow {
+    fn from(role: domain::GroupRole) -> Self {
+        match role {
+            domain::GroupRole::Owner => Self::Owner,
+            domain::GroupRole::Admin => Self::Admin,
+            domain::GroupRole::Member => Self::Member,
+        }
+    }
+}
+
 #[derive(
     Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
 )]
 pub struct GroupRow {
     pub id: uuid::Uuid,
     pub name: String,
+    pub public: bool,
+    pub description: String,
+    pub icon_url: Option<String>,
+    pub website_url: Option<String>,
+    pub parent_id: Option<uuid::Uuid>,
     pub created_at: domain::Timestamp,
     pub updated_at: domain::Timestamp,
+    pub archived_at: Option<domain::Timestamp>,
     pub members: Vec<uuid::Uuid>,
+    pub roles: Vec<GroupRoleRow>,
 }
 
 impl From<GroupRow> for domain::Group {
@@ -17,16 +57,36 @@ impl From<GroupRow> for domain::Group {
         let GroupRow {
             id,
             name,
+            public,
+            description,
+            icon_url,
+            website_url,
+            parent_id,
             created_at,
             updated_at,
+            archived_at,
             members,
+            roles,
         } = row;
-        let members = members.into_iter().map(domain::UserId::new).collect();
+        let members = members
+            .into_iter()
+            .zip(roles)
+            .map(|(user_id, role)| domain::GroupMember {
+                user_id: domain::UserId::new(user_id),
+                role: role.into(),
+            })
+            .collect();
         Self {
             id: domain::GroupId::new(id),
             name,
+            public,
+            description,
+            icon_url,
+            website_url,
+            parent_id: parent_id.map(domain::GroupId::new),
             created_at,
             updated_at,
+            archived_at,
             members,
         }
     }
@@ -38,8 +98,14 @@ impl From<GroupRow> for domain::Group {
 pub struct GroupCoreRow {
     pub id: uuid::Uuid,
     pub name: String,
+    pub public: bool,
+    pub description: String,
+    pub icon_url: Option<String>,
+    pub website_url: Option<String>,
+    pub parent_id: Option<uuid::Uuid>,
     pub created_at: domain::Timestamp,
     pub updated_at: domain::Timestamp,
+    pub archived_at: Option<domain::Timestamp>,
 }
 
 impl From<GroupCoreRow> for domain::GroupCore {
@@ -47,14 +113,26 @@ impl From<GroupCoreRow> for domain::GroupCore {
         let GroupCoreRow {
             id,
             name,
+            public,
+            description,
+            icon_url,
+            website_url,
+            parent_id,
             created_at,
             updated_at,
+            archived_at,
         } = row;
         Self {
             id: domain::GroupId::new(id),
             name,
+            public,
+            description,
+            icon_url,
+            website_url,
+            parent_id: parent_id.map(domain::GroupId::new),
             created_at,
             updated_at,
+            archived_at,
         }
     }
 }
@@ -65,6 +143,149 @@ impl From<GroupCoreRow> for domain::GroupCore {
 pub struct GroupMemberRow {
     pub group_id: uuid::Uuid,
     pub user_id: uuid::Uuid,
+    pub role: GroupRoleRow,
+}
+
+impl From<GroupMemberRow> for domain::GroupMember {
+    fn from(row: GroupMemberRow) -> Self {
+        let GroupMemberRow { user_id, role, .. } = row;
+        Self {
+            user_id: domain::UserId::new(user_id),
+            role: role.into(),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
+)]
+pub struct GroupInviteRow {
+    pub code: String,
+    pub group_id: uuid::Uuid,
+    pub created_by: uuid::Uuid,
+    pub single_use: bool,
+    pub use_count: i32,
+    pub expires_at: domain::Timestamp,
+    pub created_at: domain::Timestamp,
+}
+
+impl From<GroupInviteRow> for domain::GroupInvite {
+    fn from(row: GroupInviteRow) -> Self {
+        let GroupInviteRow {
+            code,
+            group_id,
+            created_by,
+            single_use,
+            use_count,
+            expires_at,
+            created_at,
+        } = row;
+        Self {
+            code: domain::GroupInviteCode::new(code),
+            group_id: domain::GroupId::new(group_id),
+            created_by: domain::UserId::new(created_by),
+            single_use,
+            use_count,
+            expires_at,
+            created_at,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::Type,
+)]
+#[sqlx(type_name = "join_request_status", rename_all = "snake_case")]
+pub enum JoinRequestStatusRow {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl From<JoinRequestStatusRow> for domain::GroupJoinRequestStatus {
+    fn from(row: JoinRequestStatusRow) -> Self {
+        match row {
+            JoinRequestStatusRow::Pending => Self::Pending,
+            JoinRequestStatusRow::Approved => Self::Approved,
+            JoinRequestStatusRow::Rejected => Self::Rejected,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
+)]
+pub struct GroupJoinRequestRow {
+    pub id: uuid::Uuid,
+    pub group_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub status: JoinRequestStatusRow,
+    pub decided_by: Option<uuid::Uuid>,
+    pub created_at: domain::Timestamp,
+    pub updated_at: domain::Timestamp,
+}
+
+impl From<GroupJoinRequestRow> for domain::GroupJoinRequest {
+    fn from(row: GroupJoinRequestRow) -> Self {
+        let GroupJoinRequestRow {
+            id,
+            group_id,
+            user_id,
+            status,
+            decided_by,
+            created_at,
+            updated_at,
+        } = row;
+        Self {
+            id: domain::GroupJoinRequestId::new(id),
+            group_id: domain::GroupId::new(group_id),
+            user_id: domain::UserId::new(user_id),
+            status: status.into(),
+            decided_by: decided_by.map(domain::UserId::new),
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+/// 人が入力しやすいように紛らわしい文字 (0, O, 1, I) を除いた 10 文字の招待コードを作ります。
+fn generate_invite_code() -> String {
+    use rand::Rng;
+
+    const CHARSET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+    const LEN: usize = 10;
+
+    let mut rng = rand::thread_rng();
+    (0..LEN)
+        .map(|_| char::from(CHARSET[rng.gen_range(0..CHARSET.len())]))
+        .collect()
+}
+
+/// `unnest` に渡すためにメンバーを ID とロールの列に分けます。
+fn split_members(members: &[domain::GroupMember]) -> (Vec<uuid::Uuid>, Vec<GroupRoleRow>) {
+    members
+        .iter()
+        .map(|m| (m.user_id.into_inner(), GroupRoleRow::from(m.role)))
+        .unzip()
+}
+
+/// メンバーの行をロックし, 変更前のメンバーとして返します。
+async fn lock_group_members(
+    conn: &mut sqlx::PgConnection,
+    id: domain::GroupId,
+) -> anyhow::Result<Vec<domain::GroupMember>> {
+    let members = sqlx::query_file_as!(
+        GroupMemberRow,
+        "queries/lock_group_members.sql",
+        id.into_inner()
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .inspect_err(|e| {
+        tracing::error!(error = %e, "Postgres error while locking group members");
+    })
+    .context("Failed to lock group members")?;
+    Ok(members.into_iter().map(Into::into).collect())
 }
 
 // MARK: impl GroupRepository
@@ -76,102 +297,248 @@ where
 {
     async fn get_group(&self, ctx: C, id: domain::GroupId) -> Result<domain::Group, E> {
         let group = sqlx::query_file_as!(GroupRow, "queries/get_group.sql", id.into_inner())
-            .fetch_one(ctx.as_pg_pool())
+            .fetch_optional(ctx.as_pg_pool())
             .await
             .inspect_err(|e| {
                 tracing::error!(error = %e, "Postgres error while fetching group");
             })
-            .context("Failed to fetch group")?;
+            .context("Failed to fetch group")?
+            .ok_or_else(|| E::not_found("Group not found"))?;
         Ok(group.into())
     }
 
-    async fn list_groups(&self, ctx: C) -> Result<Vec<domain::GroupCore>, E> {
-        let groups = sqlx::query_file_as!(GroupCoreRow, "queries/list_groups.sql")
+    async fn list_joined_groups(
+        &self,
+        ctx: C,
+        user_id: domain::UserId,
+    ) -> Result<Vec<domain::GroupCore>, E> {
+        let groups = sqlx::query_file_as!(
+            GroupCoreRow,
+            "queries/list_joined_groups.sql",
+            user_id.into_inner()
+        )
+        .fetch_all(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while listing joined groups");
+        })
+        .context("Failed to fetch joined groups")?;
+        Ok(groups.into_iter().map(Into::into).collect())
+    }
+
+    async fn list_public_groups(&self, ctx: C) -> Result<Vec<domain::GroupCore>, E> {
+        let groups = sqlx::query_file_as!(GroupCoreRow, "queries/list_public_groups.sql")
             .fetch_all(ctx.as_pg_pool())
             .await
             .inspect_err(|e| {
-                tracing::error!(error = %e, "Postgres error while listing groups");
+                tracing::error!(error = %e, "Postgres error while listing public groups");
             })
-            .context("Failed to fetch groups")?;
+            .context("Failed to fetch public groups")?;
         Ok(groups.into_iter().map(Into::into).collect())
     }
 
     async fn create_group(
         &self,
         ctx: C,
+        actor_id: domain::UserId,
         params: domain::CreateGroupParams,
     ) -> Result<domain::Group, E> {
-        // TODO: transaction
-        let mut conn = ctx
-            .as_pg_pool()
-            .acquire()
-            .await
-            .context("Failed to acquire connection")?;
         let id = uuid::Uuid::now_v7();
-        let domain::CreateGroupParams { name, members } = params;
-        let members: Vec<_> = members.into_iter().map(|id| id.into_inner()).collect();
-        let group_core =
-            sqlx::query_file_as!(GroupCoreRow, "queries/create_group_core.sql", id, name)
-                .fetch_one(&mut *conn)
-                .await
-                .inspect_err(|e| {
-                    tracing::error!(error = %e, "Postgres error while creating group core");
-                })
-                .context("Failed to create group core")?;
-        let group_members = sqlx::query_file_as!(
-            GroupMemberRow,
-            "queries/create_group_members.sql",
-            id,
-            &members
-        )
-        .fetch_all(&mut *conn)
-        .await
-        .inspect_err(|e| {
-            tracing::error!(error = %e, "Postgres error while creating group members");
-        })
-        .context("Failed to create group members")?;
-        let GroupCoreRow {
-            id,
-            name,
-            created_at,
-            updated_at,
-        } = group_core;
-        let members = group_members
-            .into_iter()
-            .map(|m| domain::UserId::new(m.user_id))
-            .collect();
-        Ok(domain::Group {
-            id: domain::GroupId::new(id),
+        let domain::CreateGroupParams {
             name,
-            created_at,
-            updated_at,
+            public,
+            description,
+            website_url,
+            parent_id,
             members,
+        } = params;
+        let (members, roles) = split_members(&members);
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let group_core = sqlx::query_file_as!(
+                GroupCoreRow,
+                "queries/create_group_core.sql",
+                id,
+                name,
+                public,
+                description,
+                website_url,
+                parent_id.map(domain::GroupId::into_inner)
+            )
+            .fetch_one(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while creating group core");
+            })
+            .context("Failed to create group core")?;
+            let group_members = sqlx::query_file_as!(
+                GroupMemberRow,
+                "queries/create_group_members.sql",
+                id,
+                &members,
+                &roles as &[GroupRoleRow]
+            )
+            .fetch_all(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while creating group members");
+            })
+            .context("Failed to create group members")?;
+            let GroupCoreRow {
+                id,
+                name,
+                public,
+                description,
+                icon_url,
+                website_url,
+                parent_id,
+                created_at,
+                updated_at,
+                archived_at,
+            } = group_core;
+            let members: Vec<domain::GroupMember> =
+                group_members.into_iter().map(Into::into).collect();
+
+            // 作成者の参加は作成に含める
+            let activities: Vec<_> = std::iter::once(domain::GroupActivityKind::Created)
+                .chain(members.iter().filter(|m| m.user_id != actor_id).map(|m| {
+                    domain::GroupActivityKind::MemberJoined {
+                        user_id: Some(m.user_id),
+                    }
+                }))
+                .collect();
+            let group_id = domain::GroupId::new(id);
+            record_group_activities(conn, group_id, Some(actor_id), &activities).await?;
+
+            Ok(domain::Group {
+                id: group_id,
+                name,
+                public,
+                description,
+                icon_url,
+                website_url,
+                parent_id: parent_id.map(domain::GroupId::new),
+                created_at,
+                updated_at,
+                archived_at,
+                members,
+            })
         })
+        .await
     }
 
     async fn update_group(
         &self,
         ctx: C,
         id: domain::GroupId,
+        actor_id: domain::UserId,
         params: domain::UpdateGroupParams,
     ) -> Result<domain::Group, E> {
-        let domain::UpdateGroupParams { name } = params;
-        let group =
-            sqlx::query_file_as!(GroupRow, "queries/update_group.sql", id.into_inner(), name)
-                .fetch_one(ctx.as_pg_pool())
+        let domain::UpdateGroupParams {
+            name,
+            public,
+            description,
+            website_url,
+        } = params;
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let before = sqlx::query_file!("queries/lock_group.sql", id.into_inner())
+                .fetch_optional(&mut *conn)
                 .await
                 .inspect_err(|e| {
-                    tracing::error!(error = %e, "Postgres error while updating group");
+                    tracing::error!(error = %e, "Postgres error while locking group");
                 })
-                .context("Failed to update group")?;
-        Ok(group.into())
+                .context("Failed to lock group")?
+                .ok_or_else(|| E::not_found("Group not found"))?;
+
+            let group = sqlx::query_file_as!(
+                GroupRow,
+                "queries/update_group.sql",
+                id.into_inner(),
+                name,
+                public,
+                description,
+                website_url.is_some(),
+                website_url.flatten()
+            )
+            .fetch_one(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while updating group");
+            })
+            .context("Failed to update group")?;
+
+            let mut activities = Vec::new();
+            if before.name != group.name {
+                activities.push(domain::GroupActivityKind::Renamed {
+                    from: before.name,
+                    to: group.name.clone(),
+                });
+            }
+            if before.description != group.description {
+                activities.push(domain::GroupActivityKind::DescriptionChanged);
+            }
+            if before.public != group.public {
+                activities.push(if group.public {
+                    domain::GroupActivityKind::MadePublic
+                } else {
+                    domain::GroupActivityKind::MadePrivate
+                });
+            }
+            if before.website_url != group.website_url {
+                activities.push(domain::GroupActivityKind::WebsiteChanged);
+            }
+            record_group_activities(conn, id, Some(actor_id), &activities).await?;
+            Ok(group.into())
+        })
+        .await
+    }
+
+    async fn set_group_icon(
+        &self,
+        ctx: C,
+        id: domain::GroupId,
+        actor_id: domain::UserId,
+        icon_url: Option<String>,
+    ) -> Result<domain::Group, E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let before = sqlx::query_file!("queries/lock_group.sql", id.into_inner())
+                .fetch_optional(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while locking group");
+                })
+                .context("Failed to lock group")?
+                .ok_or_else(|| E::not_found("Group not found"))?;
+
+            let group = sqlx::query_file_as!(
+                GroupRow,
+                "queries/set_group_icon.sql",
+                id.into_inner(),
+                icon_url
+            )
+            .fetch_one(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while setting group icon");
+            })
+            .context("Failed to set group icon")?;
+
+            if before.icon_url != group.icon_url {
+                retain_media(conn, group.icon_url.as_deref()).await?;
+                release_media(conn, [before.icon_url.as_deref()]).await?;
+                let changed = domain::GroupActivityKind::IconChanged;
+                record_group_activities(conn, id, Some(actor_id), &[changed]).await?;
+            }
+            Ok(group.into())
+        })
+        .await
     }
 
     async fn update_group_members(
         &self,
         ctx: C,
         id: domain::GroupId,
-        members: &[domain::UserId],
+        actor_id: domain::UserId,
+        members: &[domain::GroupMember],
     ) -> Result<domain::Group, E> {
         #[derive(sqlx::FromRow)]
         struct Check {
@@ -180,7 +547,7 @@ where
         }
 
         self.within_tx(ctx.as_pg_pool(), async |conn| {
-            let members: Vec<_> = members.iter().map(|id| id.into_inner()).collect();
+            let (members, roles) = split_members(members);
             let check = sqlx::query_file_as!(
                 Check,
                 "queries/update_group_members.0.sql",
@@ -200,6 +567,7 @@ where
                 return Err(E::not_found("Some members not found"));
             }
 
+            let before = lock_group_members(conn, id).await?;
             sqlx::query_file!("queries/update_group_members.1.sql", id.into_inner())
                 .execute(&mut *conn)
                 .await
@@ -211,11 +579,12 @@ where
                 })
                 .context("Failed to delete existing group members")?;
 
-            let _ = sqlx::query_file_as!(
+            let after = sqlx::query_file_as!(
                 GroupMemberRow,
                 "queries/update_group_members.2.sql",
                 id.into_inner(),
-                &members
+                &members,
+                &roles as &[GroupRoleRow]
             )
             .fetch_all(&mut *conn)
             .await
@@ -223,6 +592,112 @@ where
                 tracing::error!(error = %e, "Postgres error while inserting new group members");
             })
             .context("Failed to insert new group members")?;
+            let after: Vec<_> = after.into_iter().map(Into::into).collect();
+            let activities = member_changes(&before, &after);
+            record_group_activities(conn, id, Some(actor_id), &activities).await?;
+
+            let group = sqlx::query_file_as!(GroupRow, "queries/get_group.sql", id.into_inner())
+                .fetch_one(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while fetching updated group");
+                })
+                .context("Failed to fetch updated group")?;
+            Ok(group.into())
+        })
+        .await
+    }
+
+    async fn add_group_members(
+        &self,
+        ctx: C,
+        id: domain::GroupId,
+        actor_id: domain::UserId,
+        members: &[domain::GroupMember],
+    ) -> Result<domain::Group, E> {
+        #[derive(sqlx::FromRow)]
+        struct Check {
+            group_exists: bool,
+            user_count: i64,
+        }
+
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let (members, roles) = split_members(members);
+            let check = sqlx::query_file_as!(
+                Check,
+                "queries/add_group_members.0.sql",
+                id.into_inner(),
+                &members
+            )
+            .fetch_one(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while checking added members");
+            })
+            .context("Failed to check added members")?;
+            if !check.group_exists {
+                return Err(E::not_found("Group not found"));
+            }
+            if check.user_count != members.len() as i64 {
+                return Err(E::not_found("Some members not found"));
+            }
+
+            // 既にメンバーだったユーザーは返らないので, 実際に加わった分だけ記録できる
+            let added = sqlx::query_file_scalar!(
+                "queries/add_group_members.1.sql",
+                id.into_inner(),
+                &members,
+                &roles as &[GroupRoleRow]
+            )
+            .fetch_all(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while adding group members");
+            })
+            .context("Failed to add group members")?;
+            let activities: Vec<_> = added
+                .into_iter()
+                .map(|user_id| domain::GroupActivityKind::MemberJoined {
+                    user_id: Some(domain::UserId::new(user_id)),
+                })
+                .collect();
+            record_group_activities(conn, id, Some(actor_id), &activities).await?;
+
+            let group = sqlx::query_file_as!(GroupRow, "queries/get_group.sql", id.into_inner())
+                .fetch_one(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while fetching updated group");
+                })
+                .context("Failed to fetch updated group")?;
+            Ok(group.into())
+        })
+        .await
+    }
+
+    async fn remove_group_member(
+        &self,
+        ctx: C,
+        id: domain::GroupId,
+        user_id: domain::UserId,
+        actor_id: domain::UserId,
+    ) -> Result<domain::Group, E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let _ = lock_group_members(conn, id).await?;
+            let result = sqlx::query_file!(
+                "queries/remove_group_member.sql",
+                id.into_inner(),
+                user_id.into_inner()
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while removing group member");
+            })
+            .context("Failed to remove group member")?;
+            if result.rows_affected() == 0 {
+                return Err(E::not_found("Member not found"));
+            }
 
             let group = sqlx::query_file_as!(GroupRow, "queries/get_group.sql", id.into_inner())
                 .fetch_one(&mut *conn)
@@ -231,10 +706,293 @@ where
                     tracing::error!(error = %e, "Postgres error while fetching updated group");
                 })
                 .context("Failed to fetch updated group")?;
+            let group: domain::Group = group.into();
+            // コミット前に確かめるので, 同時に抜けたオーナー同士の一方だけが失敗する
+            if !group.members.iter().any(|m| m.role == domain::GroupRole::Owner) {
+                return Err(E::conflict(
+                    "A group must have at least one owner; transfer ownership or delete the group instead",
+                ));
+            }
+            let left = domain::GroupActivityKind::MemberLeft {
+                user_id: Some(user_id),
+            };
+            record_group_activities(conn, id, Some(actor_id), &[left]).await?;
+            Ok(group)
+        })
+        .await
+    }
+
+    async fn transfer_group_ownership(
+        &self,
+        ctx: C,
+        id: domain::GroupId,
+        from: domain::UserId,
+        to: domain::UserId,
+    ) -> Result<domain::Group, E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let result = sqlx::query_file!(
+                "queries/transfer_group_ownership.sql",
+                id.into_inner(),
+                from.into_inner(),
+                to.into_inner()
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while transferring group ownership");
+            })
+            .context("Failed to transfer group ownership")?;
+            if result.rows_affected() != 2 {
+                return Err(E::not_found("Member not found"));
+            }
+            let transferred = domain::GroupActivityKind::OwnershipTransferred { user_id: Some(to) };
+            record_group_activities(conn, id, Some(from), &[transferred]).await?;
+
+            let group = sqlx::query_file_as!(GroupRow, "queries/get_group.sql", id.into_inner())
+                .fetch_one(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while fetching updated group");
+                })
+                .context("Failed to fetch updated group")?;
+            Ok(group.into())
+        })
+        .await
+    }
+
+    async fn archive_group(
+        &self,
+        ctx: C,
+        id: domain::GroupId,
+        actor_id: domain::UserId,
+    ) -> Result<domain::Group, E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let group =
+                sqlx::query_file_as!(GroupRow, "queries/archive_group.sql", id.into_inner())
+                    .fetch_optional(&mut *conn)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::error!(error = %e, "Postgres error while archiving group");
+                    })
+                    .context("Failed to archive group")?
+                    .ok_or_else(|| E::not_found("Group not found"))?;
+            let archived = domain::GroupActivityKind::Archived;
+            record_group_activities(conn, id, Some(actor_id), &[archived]).await?;
+            Ok(group.into())
+        })
+        .await
+    }
+
+    async fn delete_group(&self, ctx: C, id: domain::GroupId) -> Result<(), E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let icon_url = sqlx::query_file_scalar!("queries/delete_group.sql", id.into_inner())
+                .fetch_optional(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while deleting group");
+                })
+                .context("Failed to delete group")?
+                .ok_or_else(|| E::not_found("Group not found"))?;
+            release_media(conn, [icon_url.as_deref()]).await?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn create_group_invite(
+        &self,
+        ctx: C,
+        id: domain::GroupId,
+        created_by: domain::UserId,
+        params: domain::CreateGroupInviteParams,
+    ) -> Result<domain::GroupInvite, E> {
+        let code = generate_invite_code();
+        let domain::CreateGroupInviteParams {
+            single_use,
+            expires_at,
+        } = params;
+        let invite = sqlx::query_file_as!(
+            GroupInviteRow,
+            "queries/create_group_invite.sql",
+            code,
+            id.into_inner(),
+            created_by.into_inner(),
+            single_use,
+            expires_at
+        )
+        .fetch_one(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while creating group invite");
+        })
+        .context("Failed to create group invite")?;
+        Ok(invite.into())
+    }
+
+    async fn accept_group_invite(
+        &self,
+        ctx: C,
+        code: &domain::GroupInviteCode,
+        user_id: domain::UserId,
+    ) -> Result<domain::Group, E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let group_id =
+                sqlx::query_file_scalar!("queries/accept_group_invite.0.sql", code.as_inner())
+                    .fetch_optional(&mut *conn)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::error!(error = %e, "Postgres error while consuming group invite");
+                    })
+                    .context("Failed to consume group invite")?
+                    .ok_or_else(|| E::not_found("Invite not found or no longer valid"))?;
+
+            // 既にメンバーなら招待の消費ごとロールバックさせる
+            let inserted = sqlx::query_file!(
+                "queries/accept_group_invite.1.sql",
+                group_id,
+                user_id.into_inner()
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while adding invited member");
+            })
+            .context("Failed to add invited member")?
+            .rows_affected();
+            if inserted == 0 {
+                return Err(E::conflict("Already a member of the group"));
+            }
+            let joined = domain::GroupActivityKind::MemberJoined {
+                user_id: Some(user_id),
+            };
+            let group_id = domain::GroupId::new(group_id);
+            record_group_activities(conn, group_id, Some(user_id), &[joined]).await?;
+
+            let group =
+                sqlx::query_file_as!(GroupRow, "queries/get_group.sql", group_id.into_inner())
+                    .fetch_one(&mut *conn)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::error!(error = %e, "Postgres error while fetching joined group");
+                    })
+                    .context("Failed to fetch joined group")?;
             Ok(group.into())
         })
         .await
     }
+
+    async fn list_group_join_requests(
+        &self,
+        ctx: C,
+        id: domain::GroupId,
+    ) -> Result<Vec<domain::GroupJoinRequest>, E> {
+        let requests = sqlx::query_file_as!(
+            GroupJoinRequestRow,
+            "queries/list_group_join_requests.sql",
+            id.into_inner()
+        )
+        .fetch_all(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while listing group join requests");
+        })
+        .context("Failed to fetch group join requests")?;
+        Ok(requests.into_iter().map(Into::into).collect())
+    }
+
+    async fn create_group_join_request(
+        &self,
+        ctx: C,
+        id: domain::GroupId,
+        user_id: domain::UserId,
+    ) -> Result<domain::GroupJoinRequest, E> {
+        let request = sqlx::query_file_as!(
+            GroupJoinRequestRow,
+            "queries/create_group_join_request.sql",
+            uuid::Uuid::now_v7(),
+            id.into_inner(),
+            user_id.into_inner()
+        )
+        .fetch_one(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while creating group join request");
+        })
+        .context("Failed to create group join request")?;
+        Ok(request.into())
+    }
+
+    async fn approve_group_join_request(
+        &self,
+        ctx: C,
+        id: domain::GroupId,
+        request_id: domain::GroupJoinRequestId,
+        decided_by: domain::UserId,
+    ) -> Result<domain::GroupJoinRequest, E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let request = sqlx::query_file_as!(
+                GroupJoinRequestRow,
+                "queries/decide_group_join_request.sql",
+                id.into_inner(),
+                request_id.into_inner(),
+                JoinRequestStatusRow::Approved as JoinRequestStatusRow,
+                decided_by.into_inner()
+            )
+            .fetch_optional(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while approving group join request");
+            })
+            .context("Failed to approve group join request")?
+            .ok_or_else(|| E::not_found("Join request not found or already decided"))?;
+
+            let result = sqlx::query_file!(
+                "queries/approve_group_join_request.sql",
+                request.group_id,
+                request.user_id
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while adding requesting member");
+            })
+            .context("Failed to add requesting member")?;
+            // 申請中に招待などで既に加わっていれば記録しない
+            if result.rows_affected() > 0 {
+                let joined = domain::GroupActivityKind::MemberJoined {
+                    user_id: Some(domain::UserId::new(request.user_id)),
+                };
+                record_group_activities(conn, id, Some(decided_by), &[joined]).await?;
+            }
+            Ok(request.into())
+        })
+        .await
+    }
+
+    async fn reject_group_join_request(
+        &self,
+        ctx: C,
+        id: domain::GroupId,
+        request_id: domain::GroupJoinRequestId,
+        decided_by: domain::UserId,
+    ) -> Result<domain::GroupJoinRequest, E> {
+        let request = sqlx::query_file_as!(
+            GroupJoinRequestRow,
+            "queries/decide_group_join_request.sql",
+            id.into_inner(),
+            request_id.into_inner(),
+            JoinRequestStatusRow::Rejected as JoinRequestStatusRow,
+            decided_by.into_inner()
+        )
+        .fetch_optional(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while rejecting group join request");
+        })
+        .context("Failed to reject group join request")?
+        .ok_or_else(|| E::not_found("Join request not found or already decided"))?;
+        Ok(request.into())
+    }
 }
 
 // MARK: impl GroupEntityRepository
@@ -244,27 +1002,56 @@ where
     C: crate::AsPgPool,
     E: crate::Error,
 {
-    async fn get_group_members(
+    async fn get_group_entity(
         &self,
         ctx: C,
         id: domain::GroupId,
-    ) -> Result<Vec<domain::UserId>, E> {
-        #[derive(Deserialize, Serialize, sqlx::FromRow)]
-        struct Row {
-            user_id: uuid::Uuid,
-        }
-
-        let members = sqlx::query_file_as!(Row, "queries/get_group_members.sql", id.into_inner())
-            .fetch_all(ctx.as_pg_pool())
+    ) -> Result<Option<domain::Group>, E> {
+        let group = sqlx::query_file_as!(GroupRow, "queries/get_group.sql", id.into_inner())
+            .fetch_optional(ctx.as_pg_pool())
             .await
-            .inspect_err(
-                |e| tracing::error!(error = %e, "Postgres error while fetching group members"),
-            )
-            .context("Failed to fetch group members")?;
-        let members: Vec<_> = members
-            .into_iter()
-            .map(|r| domain::UserId::new(r.user_id))
-            .collect();
-        Ok(members)
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while fetching group entity");
+            })
+            .context("Failed to fetch group entity")?;
+        Ok(group.map(Into::into))
+    }
+
+    async fn get_group_invite_entity(
+        &self,
+        ctx: C,
+        code: &domain::GroupInviteCode,
+    ) -> Result<Option<domain::GroupInvite>, E> {
+        let invite = sqlx::query_file_as!(
+            GroupInviteRow,
+            "queries/get_group_invite.sql",
+            code.as_inner()
+        )
+        .fetch_optional(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while fetching group invite entity");
+        })
+        .context("Failed to fetch group invite entity")?;
+        Ok(invite.map(Into::into))
+    }
+
+    async fn get_group_join_request_entity(
+        &self,
+        ctx: C,
+        id: domain::GroupJoinRequestId,
+    ) -> Result<Option<domain::GroupJoinRequest>, E> {
+        let request = sqlx::query_file_as!(
+            GroupJoinRequestRow,
+            "queries/get_group_join_request.sql",
+            id.into_inner()
+        )
+        .fetch_optional(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while fetching group join request entity");
+        })
+        .context("Failed to fetch group join request entity")?;
+        Ok(request.map(Into::into))
     }
 }
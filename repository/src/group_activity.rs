@@ -0,0 +1,248 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::Type,
+)]
+#[sqlx(type_name = "group_activity_kind", rename_all = "snake_case")]
+pub enum GroupActivityKindRow {
+    MemberJoined,
+    MemberLeft,
+    Renamed,
+    EventCreated,
+    Created,
+    OwnershipTransferred,
+    IconChanged,
+    Archived,
+    EventUpdated,
+    EventDeleted,
+    DescriptionChanged,
+    MadePublic,
+    MadePrivate,
+    WebsiteChanged,
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
+)]
+pub struct GroupActivityRow {
+    pub id: uuid::Uuid,
+    pub group_id: uuid::Uuid,
+    pub actor_id: Option<uuid::Uuid>,
+    pub kind: GroupActivityKindRow,
+    pub subject_user_id: Option<uuid::Uuid>,
+    pub old_name: Option<String>,
+    pub new_name: Option<String>,
+    pub event_id: Option<uuid::Uuid>,
+    pub event_title: Option<String>,
+    pub created_at: domain::Timestamp,
+}
+
+impl TryFrom<GroupActivityRow> for domain::GroupActivity {
+    type Error = anyhow::Error;
+
+    fn try_from(row: GroupActivityRow) -> Result<Self, Self::Error> {
+        let GroupActivityRow {
+            id,
+            group_id,
+            actor_id,
+            kind,
+            subject_user_id,
+            old_name,
+            new_name,
+            event_id,
+            event_title,
+            created_at,
+        } = row;
+        // 種類ごとに必要な列は CHECK 制約で揃っている
+        // subject_user_id はアカウントの消去で NULL になりうる
+        let kind = match kind {
+            GroupActivityKindRow::Created => domain::GroupActivityKind::Created,
+            GroupActivityKindRow::MemberJoined => domain::GroupActivityKind::MemberJoined {
+                user_id: subject_user_id.map(domain::UserId::new),
+            },
+            GroupActivityKindRow::MemberLeft => domain::GroupActivityKind::MemberLeft {
+                user_id: subject_user_id.map(domain::UserId::new),
+            },
+            GroupActivityKindRow::Renamed => domain::GroupActivityKind::Renamed {
+                from: old_name.context("renamed without old_name")?,
+                to: new_name.context("renamed without new_name")?,
+            },
+            GroupActivityKindRow::OwnershipTransferred => {
+                domain::GroupActivityKind::OwnershipTransferred {
+                    user_id: subject_user_id.map(domain::UserId::new),
+                }
+            }
+            GroupActivityKindRow::IconChanged => domain::GroupActivityKind::IconChanged,
+            GroupActivityKindRow::DescriptionChanged => {
+                domain::GroupActivityKind::DescriptionChanged
+            }
+            GroupActivityKindRow::MadePublic => domain::GroupActivityKind::MadePublic,
+            GroupActivityKindRow::MadePrivate => domain::GroupActivityKind::MadePrivate,
+            GroupActivityKindRow::WebsiteChanged => domain::GroupActivityKind::WebsiteChanged,
+            GroupActivityKindRow::Archived => domain::GroupActivityKind::Archived,
+            GroupActivityKindRow::EventCreated => domain::GroupActivityKind::EventCreated {
+                event_id: event_id.map(domain::GroupEventId::new),
+                title: event_title.context("event_created without event_title")?,
+            },
+            GroupActivityKindRow::EventUpdated => domain::GroupActivityKind::EventUpdated {
+                event_id: event_id.map(domain::GroupEventId::new),
+                title: event_title.context("event_updated without event_title")?,
+            },
+            GroupActivityKindRow::EventDeleted => domain::GroupActivityKind::EventDeleted {
+                title: event_title.context("event_deleted without event_title")?,
+            },
+        };
+        Ok(Self {
+            id: domain::GroupActivityId::new(id),
+            group_id: domain::GroupId::new(group_id),
+            actor_id: actor_id.map(domain::UserId::new),
+            kind,
+            created_at,
+        })
+    }
+}
+
+/// メンバー一覧の変化を参加・脱退のアクティビティにします。
+pub(crate) fn member_changes(
+    before: &[domain::GroupMember],
+    after: &[domain::GroupMember],
+) -> Vec<domain::GroupActivityKind> {
+    let contains = |ms: &[domain::GroupMember], user_id| ms.iter().any(|m| m.user_id == user_id);
+    let joined = after
+        .iter()
+        .filter(|m| !contains(before, m.user_id))
+        .map(|m| domain::GroupActivityKind::MemberJoined {
+            user_id: Some(m.user_id),
+        });
+    let left = before
+        .iter()
+        .filter(|m| !contains(after, m.user_id))
+        .map(|m| domain::GroupActivityKind::MemberLeft {
+            user_id: Some(m.user_id),
+        });
+    joined.chain(left).collect()
+}
+
+/// `kinds` の順にアクティビティを記録します。
+///
+/// フィードが実際の変更とずれないよう, 変更と同じトランザクションの中で呼びます。
+/// `actor_id` が `None` なのは, 利用者の操作ではない変更です。
+pub(crate) async fn record_group_activities(
+    conn: &mut sqlx::PgConnection,
+    group_id: domain::GroupId,
+    actor_id: Option<domain::UserId>,
+    kinds: &[domain::GroupActivityKind],
+) -> anyhow::Result<()> {
+    for kind in kinds {
+        let mut subject_user_id = None;
+        let mut old_name = None;
+        let mut new_name = None;
+        let mut event_id = None;
+        let mut event_title = None;
+        let kind_row = match kind {
+            domain::GroupActivityKind::Created => GroupActivityKindRow::Created,
+            domain::GroupActivityKind::MemberJoined { user_id } => {
+                subject_user_id = user_id.map(domain::UserId::into_inner);
+                GroupActivityKindRow::MemberJoined
+            }
+            domain::GroupActivityKind::MemberLeft { user_id } => {
+                subject_user_id = user_id.map(domain::UserId::into_inner);
+                GroupActivityKindRow::MemberLeft
+            }
+            domain::GroupActivityKind::Renamed { from, to } => {
+                old_name = Some(from.as_str());
+                new_name = Some(to.as_str());
+                GroupActivityKindRow::Renamed
+            }
+            domain::GroupActivityKind::OwnershipTransferred { user_id } => {
+                subject_user_id = user_id.map(domain::UserId::into_inner);
+                GroupActivityKindRow::OwnershipTransferred
+            }
+            domain::GroupActivityKind::IconChanged => GroupActivityKindRow::IconChanged,
+            domain::GroupActivityKind::DescriptionChanged => {
+                GroupActivityKindRow::DescriptionChanged
+            }
+            domain::GroupActivityKind::MadePublic => GroupActivityKindRow::MadePublic,
+            domain::GroupActivityKind::MadePrivate => GroupActivityKindRow::MadePrivate,
+            domain::GroupActivityKind::WebsiteChanged => GroupActivityKindRow::WebsiteChanged,
+            domain::GroupActivityKind::Archived => GroupActivityKindRow::Archived,
+            domain::GroupActivityKind::EventCreated {
+                event_id: id,
+                title,
+            } => {
+                event_id = id.map(domain::GroupEventId::into_inner);
+                event_title = Some(title.as_str());
+                GroupActivityKindRow::EventCreated
+            }
+            domain::GroupActivityKind::EventUpdated {
+                event_id: id,
+                title,
+            } => {
+                event_id = id.map(domain::GroupEventId::into_inner);
+                event_title = Some(title.as_str());
+                GroupActivityKindRow::EventUpdated
+            }
+            domain::GroupActivityKind::EventDeleted { title } => {
+                event_title = Some(title.as_str());
+                GroupActivityKindRow::EventDeleted
+            }
+        };
+        // UUIDv7 は生成順に並ぶので、記録順がそのまま表示順になる
+        sqlx::query_file!(
+            "queries/record_group_activity.sql",
+            uuid::Uuid::now_v7(),
+            group_id.into_inner(),
+            actor_id.map(domain::UserId::into_inner),
+            kind_row as GroupActivityKindRow,
+            subject_user_id,
+            old_name,
+            new_name,
+            event_id,
+            event_title
+        )
+        .execute(&mut *conn)
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while recording group activity");
+        })
+        .context("Failed to record group activity")?;
+    }
+    Ok(())
+}
+
+// MARK: impl GroupActivityRepository
+
+impl<C, E> service::GroupActivityRepository<C, E> for crate::Repository
+where
+    C: crate::AsPgPool,
+    E: crate::Error,
+{
+    async fn list_group_activities(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        cursor: Option<domain::GroupActivityId>,
+        limit: u32,
+    ) -> Result<Vec<domain::GroupActivity>, E> {
+        let activities = sqlx::query_file_as!(
+            GroupActivityRow,
+            "queries/list_group_activities.sql",
+            group_id.into_inner(),
+            cursor.map(domain::GroupActivityId::into_inner),
+            i64::from(limit)
+        )
+        .fetch_all(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while listing group activities");
+        })
+        .context("Failed to fetch group activities")?;
+        let activities = activities
+            .into_iter()
+            .map(domain::GroupActivity::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .context("Inconsistent group activity row")?;
+        Ok(activities)
+    }
+}
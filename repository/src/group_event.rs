@@ -0,0 +1,303 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::group_activity::record_group_activities;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::Type,
+)]
+#[sqlx(type_name = "recurrence_frequency", rename_all = "snake_case")]
+pub enum RecurrenceFrequencyRow {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl From<RecurrenceFrequencyRow> for domain::RecurrenceFrequency {
+    fn from(row: RecurrenceFrequencyRow) -> Self {
+        match row {
+            RecurrenceFrequencyRow::Daily => Self::Daily,
+            RecurrenceFrequencyRow::Weekly => Self::Weekly,
+            RecurrenceFrequencyRow::Monthly => Self::Monthly,
+        }
+    }
+}
+
+impl From<domain::RecurrenceFrequency> for RecurrenceFrequencyRow {
+    fn from(frequency: domain::RecurrenceFrequency) -> Self {
+        match frequency {
+            domain::RecurrenceFrequency::Daily => Self::Daily,
+            domain::RecurrenceFrequency::Weekly => Self::Weekly,
+            domain::RecurrenceFrequency::Monthly => Self::Monthly,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
+)]
+pub struct GroupEventRow {
+    pub id: uuid::Uuid,
+    pub group_id: uuid::Uuid,
+    pub title: String,
+    pub location: Option<String>,
+    pub starts_at: domain::Timestamp,
+    pub ends_at: domain::Timestamp,
+    pub recurrence_frequency: Option<RecurrenceFrequencyRow>,
+    pub recurrence_interval: Option<i32>,
+    pub recurrence_until: Option<domain::Timestamp>,
+    pub created_by: Option<uuid::Uuid>,
+    pub created_at: domain::Timestamp,
+    pub updated_at: domain::Timestamp,
+}
+
+impl From<GroupEventRow> for domain::GroupEvent {
+    fn from(row: GroupEventRow) -> Self {
+        let GroupEventRow {
+            id,
+            group_id,
+            title,
+            location,
+            starts_at,
+            ends_at,
+            recurrence_frequency,
+            recurrence_interval,
+            recurrence_until,
+            created_by,
+            created_at,
+            updated_at,
+        } = row;
+        // frequency と interval は CHECK 制約で必ず揃っている
+        let recurrence =
+            recurrence_frequency
+                .zip(recurrence_interval)
+                .map(|(frequency, interval)| domain::GroupEventRecurrence {
+                    frequency: frequency.into(),
+                    interval,
+                    until: recurrence_until,
+                });
+        Self {
+            id: domain::GroupEventId::new(id),
+            group_id: domain::GroupId::new(group_id),
+            title,
+            location,
+            starts_at,
+            ends_at,
+            recurrence,
+            created_by: created_by.map(domain::UserId::new),
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+/// recurrence -> (frequency, interval, until)
+fn split_recurrence(
+    recurrence: Option<domain::GroupEventRecurrence>,
+) -> (
+    Option<RecurrenceFrequencyRow>,
+    Option<i32>,
+    Option<domain::Timestamp>,
+) {
+    match recurrence {
+        Some(r) => (Some(r.frequency.into()), Some(r.interval), r.until),
+        None => (None, None, None),
+    }
+}
+
+// MARK: impl GroupEventRepository
+
+impl<C, E> service::GroupEventRepository<C, E> for crate::Repository
+where
+    C: crate::AsPgPool,
+    E: crate::Error,
+{
+    async fn list_group_events(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+    ) -> Result<Vec<domain::GroupEvent>, E> {
+        let events = sqlx::query_file_as!(
+            GroupEventRow,
+            "queries/list_group_events.sql",
+            group_id.into_inner()
+        )
+        .fetch_all(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while listing group events");
+        })
+        .context("Failed to fetch group events")?;
+        Ok(events.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_group_event(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    ) -> Result<domain::GroupEvent, E> {
+        let event = sqlx::query_file_as!(
+            GroupEventRow,
+            "queries/get_group_event.sql",
+            event_id.into_inner()
+        )
+        .fetch_optional(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while fetching group event");
+        })
+        .context("Failed to fetch group event")?
+        .filter(|e| e.group_id == group_id.into_inner())
+        .ok_or_else(|| E::not_found("Group event not found"))?;
+        Ok(event.into())
+    }
+
+    async fn create_group_event(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        created_by: domain::UserId,
+        params: domain::CreateGroupEventParams,
+    ) -> Result<domain::GroupEvent, E> {
+        let domain::CreateGroupEventParams {
+            title,
+            location,
+            starts_at,
+            ends_at,
+            recurrence,
+        } = params;
+        let (frequency, interval, until) = split_recurrence(recurrence);
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let event = sqlx::query_file_as!(
+                GroupEventRow,
+                "queries/create_group_event.sql",
+                uuid::Uuid::now_v7(),
+                group_id.into_inner(),
+                title,
+                location,
+                starts_at,
+                ends_at,
+                frequency as Option<RecurrenceFrequencyRow>,
+                interval,
+                until,
+                created_by.into_inner()
+            )
+            .fetch_one(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while creating group event");
+            })
+            .context("Failed to create group event")?;
+            let created = domain::GroupActivityKind::EventCreated {
+                event_id: Some(domain::GroupEventId::new(event.id)),
+                title: event.title.clone(),
+            };
+            record_group_activities(conn, group_id, Some(created_by), &[created]).await?;
+            Ok(event.into())
+        })
+        .await
+    }
+
+    async fn update_group_event(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+        actor_id: domain::UserId,
+        params: domain::UpdateGroupEventParams,
+    ) -> Result<domain::GroupEvent, E> {
+        let domain::UpdateGroupEventParams {
+            title,
+            location,
+            starts_at,
+            ends_at,
+            recurrence,
+        } = params;
+        let (frequency, interval, until) = split_recurrence(recurrence);
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let event = sqlx::query_file_as!(
+                GroupEventRow,
+                "queries/update_group_event.sql",
+                group_id.into_inner(),
+                event_id.into_inner(),
+                title,
+                location,
+                starts_at,
+                ends_at,
+                frequency as Option<RecurrenceFrequencyRow>,
+                interval,
+                until
+            )
+            .fetch_optional(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while updating group event");
+            })
+            .context("Failed to update group event")?
+            .ok_or_else(|| E::not_found("Group event not found"))?;
+            let updated = domain::GroupActivityKind::EventUpdated {
+                event_id: Some(event_id),
+                title: event.title.clone(),
+            };
+            record_group_activities(conn, group_id, Some(actor_id), &[updated]).await?;
+            Ok(event.into())
+        })
+        .await
+    }
+
+    async fn delete_group_event(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+        actor_id: domain::UserId,
+    ) -> Result<(), E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let title = sqlx::query_file_scalar!(
+                "queries/delete_group_event.sql",
+                group_id.into_inner(),
+                event_id.into_inner()
+            )
+            .fetch_optional(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while deleting group event");
+            })
+            .context("Failed to delete group event")?
+            .ok_or_else(|| E::not_found("Group event not found"))?;
+            // イベントはもうないので, タイトルだけを残す
+            let deleted = domain::GroupActivityKind::EventDeleted { title };
+            record_group_activities(conn, group_id, Some(actor_id), &[deleted]).await?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+// MARK: impl GroupEventEntityRepository
+
+impl<C, E> authz::GroupEventEntityRepository<C, E> for crate::Repository
+where
+    C: crate::AsPgPool,
+    E: crate::Error,
+{
+    async fn get_group_event_entity(
+        &self,
+        ctx: C,
+        id: domain::GroupEventId,
+    ) -> Result<Option<domain::GroupEvent>, E> {
+        let event = sqlx::query_file_as!(
+            GroupEventRow,
+            "queries/get_group_event.sql",
+            id.into_inner()
+        )
+        .fetch_optional(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while fetching group event entity");
+        })
+        .context("Failed to fetch group event entity")?;
+        Ok(event.map(Into::into))
+    }
+}
@@ -1,4 +1,9 @@
+mod announcement;
 mod group;
+mod group_activity;
+mod group_event;
+mod media;
+mod meeting_poll;
 mod user;
 
 #[derive(Debug, Clone)]
@@ -48,6 +53,8 @@ impl Repository {
 
 pub trait Error: domain::Error + From<anyhow::Error> {
     fn not_found(message: &str) -> Self;
+    /// 一意制約に違反した場合のエラー
+    fn conflict(message: &str) -> Self;
 }
 
 pub trait AsPgPool: Send + Sync {
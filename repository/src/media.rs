@@ -0,0 +1,107 @@
+use anyhow::Context;
+
+/// `url` が保存した画像を指していれば, その参照数を 1 増やします。
+pub(crate) async fn retain_media(
+    conn: &mut sqlx::PgConnection,
+    url: Option<&str>,
+) -> anyhow::Result<()> {
+    let Some(hash) = url.and_then(domain::MediaHash::from_url_path) else {
+        return Ok(());
+    };
+    sqlx::query_file!("queries/retain_media.sql", hash.as_inner())
+        .execute(&mut *conn)
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while retaining media");
+        })
+        .context("Failed to retain media")?;
+    Ok(())
+}
+
+/// `urls` のうち保存した画像を指すものの参照数を 1 つずつ減らします。
+pub(crate) async fn release_media(
+    conn: &mut sqlx::PgConnection,
+    urls: impl IntoIterator<Item = Option<&str>>,
+) -> anyhow::Result<()> {
+    let hashes: Vec<String> = urls
+        .into_iter()
+        .flatten()
+        .filter_map(domain::MediaHash::from_url_path)
+        .map(domain::MediaHash::into_inner)
+        .collect();
+    if hashes.is_empty() {
+        return Ok(());
+    }
+    sqlx::query_file!("queries/release_media.sql", &hashes)
+        .execute(&mut *conn)
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while releasing media");
+        })
+        .context("Failed to release media")?;
+    Ok(())
+}
+
+// MARK: impl MediaRepository
+
+impl<C, E> service::MediaRepository<C, E> for crate::Repository
+where
+    C: crate::AsPgPool,
+    E: crate::Error,
+{
+    async fn delete_unreferenced_media<B>(
+        &self,
+        ctx: C,
+        hash: &domain::MediaHash,
+        blob_store: &B,
+    ) -> Result<bool, E>
+    where
+        B: service::ProvideBlobStore<Error = E>,
+    {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            sqlx::query_file!("queries/delete_unreferenced_media.0.sql", hash.as_inner())
+                .execute(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while preparing media lock");
+                })
+                .context("Failed to prepare media lock")?;
+            let ref_count = sqlx::query_file_scalar!(
+                "queries/delete_unreferenced_media.1.sql",
+                hash.as_inner()
+            )
+            .fetch_one(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while locking media");
+            })
+            .context("Failed to lock media")?;
+            if ref_count > 0 {
+                return Ok(false);
+            }
+
+            // 失敗すれば行ごと残し, 次の掃除で消し直す
+            blob_store.delete_blob(hash).await?;
+            sqlx::query_file!("queries/delete_unreferenced_media.2.sql", hash.as_inner())
+                .execute(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while deleting media");
+                })
+                .context("Failed to delete media")?;
+            Ok(true)
+        })
+        .await
+    }
+
+    async fn list_unreferenced_media(&self, ctx: C) -> Result<Vec<domain::MediaHash>, E> {
+        let hashes = sqlx::query_file_scalar!("queries/list_unreferenced_media.sql")
+            .fetch_all(ctx.as_pg_pool())
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while listing unreferenced media");
+            })
+            .context("Failed to fetch unreferenced media")?;
+        Ok(hashes.into_iter().map(domain::MediaHash::new).collect())
+    }
+}
@@ -0,0 +1,368 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::group_activity::record_group_activities;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::Type,
+)]
+#[sqlx(type_name = "meeting_availability", rename_all = "snake_case")]
+pub enum MeetingAvailabilityRow {
+    Available,
+    Maybe,
+    Unavailable,
+}
+
+impl From<MeetingAvailabilityRow> for domain::MeetingAvailability {
+    fn from(row: MeetingAvailabilityRow) -> Self {
+        match row {
+            MeetingAvailabilityRow::Available => Self::Available,
+            MeetingAvailabilityRow::Maybe => Self::Maybe,
+            MeetingAvailabilityRow::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
+impl From<domain::MeetingAvailability> for MeetingAvailabilityRow {
+    fn from(availability: domain::MeetingAvailability) -> Self {
+        match availability {
+            domain::MeetingAvailability::Available => Self::Available,
+            domain::MeetingAvailability::Maybe => Self::Maybe,
+            domain::MeetingAvailability::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
+)]
+pub struct MeetingPollRow {
+    pub id: uuid::Uuid,
+    pub group_id: uuid::Uuid,
+    pub title: String,
+    pub organizer_id: Option<uuid::Uuid>,
+    pub finalized_candidate_id: Option<uuid::Uuid>,
+    pub event_id: Option<uuid::Uuid>,
+    pub created_at: domain::Timestamp,
+    pub updated_at: domain::Timestamp,
+    pub candidate_ids: Vec<uuid::Uuid>,
+    pub starts_at: Vec<domain::Timestamp>,
+    pub ends_at: Vec<domain::Timestamp>,
+    pub vote_candidate_ids: Vec<uuid::Uuid>,
+    pub vote_user_ids: Vec<uuid::Uuid>,
+    pub availabilities: Vec<MeetingAvailabilityRow>,
+}
+
+impl From<MeetingPollRow> for domain::MeetingPoll {
+    fn from(row: MeetingPollRow) -> Self {
+        use domain::{MeetingPollCandidate, MeetingPollCandidateId, MeetingPollVote, UserId};
+
+        let MeetingPollRow {
+            id,
+            group_id,
+            title,
+            organizer_id,
+            finalized_candidate_id,
+            event_id,
+            created_at,
+            updated_at,
+            candidate_ids,
+            starts_at,
+            ends_at,
+            vote_candidate_ids,
+            vote_user_ids,
+            availabilities,
+        } = row;
+        let candidates = candidate_ids
+            .into_iter()
+            .zip(starts_at)
+            .zip(ends_at)
+            .map(|((id, starts_at), ends_at)| MeetingPollCandidate {
+                id: MeetingPollCandidateId::new(id),
+                starts_at,
+                ends_at,
+            })
+            .collect();
+        let votes = vote_candidate_ids
+            .into_iter()
+            .zip(vote_user_ids)
+            .zip(availabilities)
+            .map(|((candidate_id, user_id), availability)| MeetingPollVote {
+                candidate_id: MeetingPollCandidateId::new(candidate_id),
+                user_id: UserId::new(user_id),
+                availability: availability.into(),
+            })
+            .collect();
+        Self {
+            id: domain::MeetingPollId::new(id),
+            group_id: domain::GroupId::new(group_id),
+            title,
+            organizer_id: organizer_id.map(UserId::new),
+            candidates,
+            votes,
+            finalized_candidate_id: finalized_candidate_id.map(MeetingPollCandidateId::new),
+            event_id: event_id.map(domain::GroupEventId::new),
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+// MARK: impl MeetingPollRepository
+
+impl<C, E> service::MeetingPollRepository<C, E> for crate::Repository
+where
+    C: crate::AsPgPool,
+    E: crate::Error,
+{
+    async fn list_meeting_polls(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+    ) -> Result<Vec<domain::MeetingPoll>, E> {
+        let polls = sqlx::query_file_as!(
+            MeetingPollRow,
+            "queries/list_meeting_polls.sql",
+            group_id.into_inner()
+        )
+        .fetch_all(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while listing meeting polls");
+        })
+        .context("Failed to fetch meeting polls")?;
+        Ok(polls.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> Result<domain::MeetingPoll, E> {
+        let poll = sqlx::query_file_as!(
+            MeetingPollRow,
+            "queries/get_meeting_poll.sql",
+            poll_id.into_inner()
+        )
+        .fetch_optional(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while fetching meeting poll");
+        })
+        .context("Failed to fetch meeting poll")?
+        .filter(|p| p.group_id == group_id.into_inner())
+        .ok_or_else(|| E::not_found("Meeting poll not found"))?;
+        Ok(poll.into())
+    }
+
+    async fn create_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        organizer_id: domain::UserId,
+        params: domain::CreateMeetingPollParams,
+    ) -> Result<domain::MeetingPoll, E> {
+        let id = uuid::Uuid::now_v7();
+        let domain::CreateMeetingPollParams { title, candidates } = params;
+        let candidate_ids: Vec<_> = candidates.iter().map(|_| uuid::Uuid::now_v7()).collect();
+        let (starts_at, ends_at): (Vec<_>, Vec<_>) =
+            candidates.iter().map(|c| (c.starts_at, c.ends_at)).unzip();
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            sqlx::query_file!(
+                "queries/create_meeting_poll.0.sql",
+                id,
+                group_id.into_inner(),
+                title,
+                organizer_id.into_inner()
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while creating meeting poll");
+            })
+            .context("Failed to create meeting poll")?;
+
+            sqlx::query_file!(
+                "queries/create_meeting_poll.1.sql",
+                id,
+                &candidate_ids,
+                &starts_at,
+                &ends_at
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while creating meeting poll candidates");
+            })
+            .context("Failed to create meeting poll candidates")?;
+
+            let poll = sqlx::query_file_as!(MeetingPollRow, "queries/get_meeting_poll.sql", id)
+                .fetch_one(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while fetching created meeting poll");
+                })
+                .context("Failed to fetch created meeting poll")?;
+            Ok(poll.into())
+        })
+        .await
+    }
+
+    async fn vote_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+        user_id: domain::UserId,
+        votes: &[domain::CastMeetingPollVote],
+    ) -> Result<domain::MeetingPoll, E> {
+        let (candidate_ids, availabilities): (Vec<_>, Vec<_>) = votes
+            .iter()
+            .map(|v| {
+                (
+                    v.candidate_id.into_inner(),
+                    MeetingAvailabilityRow::from(v.availability),
+                )
+            })
+            .unzip();
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            sqlx::query_file!(
+                "queries/vote_meeting_poll.0.sql",
+                poll_id.into_inner(),
+                user_id.into_inner()
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while deleting previous votes");
+            })
+            .context("Failed to delete previous votes")?;
+
+            sqlx::query_file!(
+                "queries/vote_meeting_poll.1.sql",
+                poll_id.into_inner(),
+                user_id.into_inner(),
+                &candidate_ids,
+                &availabilities as &[MeetingAvailabilityRow]
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while inserting votes");
+            })
+            .context("Failed to insert votes")?;
+
+            let poll = sqlx::query_file_as!(
+                MeetingPollRow,
+                "queries/get_meeting_poll.sql",
+                poll_id.into_inner()
+            )
+            .fetch_optional(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while fetching voted meeting poll");
+            })
+            .context("Failed to fetch voted meeting poll")?
+            .filter(|p| p.group_id == group_id.into_inner())
+            .ok_or_else(|| E::not_found("Meeting poll not found"))?;
+            Ok(poll.into())
+        })
+        .await
+    }
+
+    async fn finalize_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+        candidate_id: domain::MeetingPollCandidateId,
+        finalized_by: domain::UserId,
+    ) -> Result<domain::MeetingPoll, E> {
+        let event_id = uuid::Uuid::now_v7();
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let result = sqlx::query_file!(
+                "queries/finalize_meeting_poll.0.sql",
+                group_id.into_inner(),
+                poll_id.into_inner(),
+                candidate_id.into_inner(),
+                event_id,
+                finalized_by.into_inner()
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while creating event of meeting poll");
+            })
+            .context("Failed to create event of meeting poll")?;
+            if result.rows_affected() == 0 {
+                return Err(E::not_found("Meeting poll not found or already finalized"));
+            }
+
+            let result = sqlx::query_file!(
+                "queries/finalize_meeting_poll.1.sql",
+                group_id.into_inner(),
+                poll_id.into_inner(),
+                candidate_id.into_inner(),
+                event_id
+            )
+            .execute(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while finalizing meeting poll");
+            })
+            .context("Failed to finalize meeting poll")?;
+            // 作ったイベントが宙に浮かないよう, 確定できなければ巻き戻す
+            if result.rows_affected() == 0 {
+                return Err(E::conflict("Meeting poll is already finalized"));
+            }
+
+            let poll = sqlx::query_file_as!(
+                MeetingPollRow,
+                "queries/get_meeting_poll.sql",
+                poll_id.into_inner()
+            )
+            .fetch_one(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while fetching finalized meeting poll");
+            })
+            .context("Failed to fetch finalized meeting poll")?;
+            // 確定で作られたイベントもフィードに載せる
+            let created = domain::GroupActivityKind::EventCreated {
+                event_id: Some(domain::GroupEventId::new(event_id)),
+                title: poll.title.clone(),
+            };
+            record_group_activities(conn, group_id, Some(finalized_by), &[created]).await?;
+            Ok(poll.into())
+        })
+        .await
+    }
+}
+
+// MARK: impl MeetingPollEntityRepository
+
+impl<C, E> authz::MeetingPollEntityRepository<C, E> for crate::Repository
+where
+    C: crate::AsPgPool,
+    E: crate::Error,
+{
+    async fn get_meeting_poll_entity(
+        &self,
+        ctx: C,
+        id: domain::MeetingPollId,
+    ) -> Result<Option<domain::MeetingPoll>, E> {
+        let poll = sqlx::query_file_as!(
+            MeetingPollRow,
+            "queries/get_meeting_poll.sql",
+            id.into_inner()
+        )
+        .fetch_optional(ctx.as_pg_pool())
+        .await
+        .inspect_err(|e| {
+            tracing::error!(error = %e, "Postgres error while fetching meeting poll entity");
+        })
+        .context("Failed to fetch meeting poll entity")?;
+        Ok(poll.map(Into::into))
+    }
+}
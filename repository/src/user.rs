@@ -1,14 +1,29 @@
 use anyhow::Context;
 use serde::{Deserialize, Serialize};
 
+use crate::announcement::AnnouncementRow;
+use crate::group::{GroupInviteRow, GroupJoinRequestRow, GroupRoleRow, JoinRequestStatusRow};
+use crate::group_activity::{GroupActivityKindRow, GroupActivityRow, record_group_activities};
+use crate::group_event::{GroupEventRow, RecurrenceFrequencyRow};
+use crate::media::{release_media, retain_media};
+use crate::meeting_poll::{MeetingAvailabilityRow, MeetingPollRow};
+
 #[derive(
     Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
 )]
 pub struct UserRow {
     pub id: uuid::Uuid,
     pub name: String,
+    pub handle: Option<String>,
+    pub university: Option<String>,
+    pub faculty: Option<String>,
+    pub department: Option<String>,
+    pub enrollment_year: Option<i32>,
+    pub bio: String,
+    pub avatar_url: Option<String>,
     pub created_at: domain::Timestamp,
     pub updated_at: domain::Timestamp,
+    pub deleted_at: Option<domain::Timestamp>,
 }
 
 impl From<UserRow> for domain::User {
@@ -16,14 +31,104 @@ impl From<UserRow> for domain::User {
         let UserRow {
             id,
             name,
+            handle,
+            university,
+            faculty,
+            department,
+            enrollment_year,
+            bio,
+            avatar_url,
             created_at,
             updated_at,
+            deleted_at,
         } = row;
         domain::User {
             id: domain::UserId::new(id),
             name,
+            handle,
+            affiliation: domain::Affiliation {
+                university,
+                faculty,
+                department,
+            },
+            enrollment_year,
+            bio,
+            avatar_url,
             created_at,
             updated_at,
+            deleted_at,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
+)]
+pub struct UserGroupMembershipRow {
+    pub group_id: uuid::Uuid,
+    pub group_name: String,
+    pub role: GroupRoleRow,
+}
+
+impl From<UserGroupMembershipRow> for domain::UserGroupMembership {
+    fn from(row: UserGroupMembershipRow) -> Self {
+        let UserGroupMembershipRow {
+            group_id,
+            group_name,
+            role,
+        } = row;
+        Self {
+            group_id: domain::GroupId::new(group_id),
+            group_name,
+            role: role.into(),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
+)]
+pub struct UserMeetingPollVoteRow {
+    pub poll_id: uuid::Uuid,
+    pub candidate_id: uuid::Uuid,
+    pub availability: MeetingAvailabilityRow,
+    pub updated_at: domain::Timestamp,
+}
+
+impl From<UserMeetingPollVoteRow> for domain::UserMeetingPollVote {
+    fn from(row: UserMeetingPollVoteRow) -> Self {
+        let UserMeetingPollVoteRow {
+            poll_id,
+            candidate_id,
+            availability,
+            updated_at,
+        } = row;
+        Self {
+            poll_id: domain::MeetingPollId::new(poll_id),
+            candidate_id: domain::MeetingPollCandidateId::new(candidate_id),
+            availability: availability.into(),
+            updated_at,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, sqlx::FromRow,
+)]
+pub struct UserAnnouncementReadRow {
+    pub announcement_id: uuid::Uuid,
+    pub read_at: domain::Timestamp,
+}
+
+impl From<UserAnnouncementReadRow> for domain::UserAnnouncementRead {
+    fn from(row: UserAnnouncementReadRow) -> Self {
+        let UserAnnouncementReadRow {
+            announcement_id,
+            read_at,
+        } = row;
+        Self {
+            announcement_id: domain::AnnouncementId::new(announcement_id),
+            read_at,
         }
     }
 }
@@ -79,14 +184,342 @@ where
         id: domain::UserId,
         params: domain::UpdateUserParams,
     ) -> Result<domain::User, E> {
-        let domain::UpdateUserParams { name } = params;
-        let user = sqlx::query_file_as!(UserRow, "queries/update_user.sql", id.into_inner(), name)
-            .fetch_one(ctx.as_pg_pool())
+        let domain::UpdateUserParams {
+            name,
+            handle,
+            affiliation,
+            enrollment_year,
+            bio,
+            avatar_url,
+        } = params;
+        let domain::Affiliation {
+            university,
+            faculty,
+            department,
+        } = affiliation.clone().unwrap_or_default();
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let previous_avatar_url = match avatar_url {
+                Some(_) => {
+                    sqlx::query_file_scalar!("queries/lock_user_avatar.sql", id.into_inner())
+                        .fetch_optional(&mut *conn)
+                        .await
+                        .inspect_err(|e| {
+                            tracing::error!(error = %e, "Postgres error while locking user avatar");
+                        })
+                        .context("Failed to lock user avatar")?
+                        .ok_or_else(|| E::not_found("User not found"))?
+                }
+                None => None,
+            };
+
+            let user = sqlx::query_file_as!(
+                UserRow,
+                "queries/update_user.sql",
+                id.into_inner(),
+                name,
+                handle.is_some(),
+                handle.flatten(),
+                affiliation.is_some(),
+                university,
+                faculty,
+                department,
+                enrollment_year.is_some(),
+                enrollment_year.flatten(),
+                bio,
+                avatar_url.is_some(),
+                avatar_url.flatten()
+            )
+            .fetch_optional(&mut *conn)
             .await
-            .inspect_err(|e| {
+            .map_err(|e| {
+                if e.as_database_error()
+                    .is_some_and(|e| e.is_unique_violation())
+                {
+                    return E::conflict("Handle is already taken");
+                }
                 tracing::error!(error = %e, "Postgres error while updating user");
+                anyhow::Error::new(e)
+                    .context("Failed to update user in database")
+                    .into()
+            })?
+            .ok_or_else(|| E::not_found("User not found"))?;
+
+            if previous_avatar_url != user.avatar_url {
+                retain_media(conn, user.avatar_url.as_deref()).await?;
+                release_media(conn, [previous_avatar_url.as_deref()]).await?;
+            }
+            Ok(user.into())
+        })
+        .await
+    }
+
+    async fn delete_user(&self, ctx: C, id: domain::UserId) -> Result<domain::User, E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let _ = sqlx::query_file!("queries/delete_user.0.sql", id.into_inner())
+                .fetch_all(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while locking group members");
+                })
+                .context("Failed to lock group members")?;
+
+            // 他のメンバーが残るグループは, オーナーを引き継いでからでないと抜けられない
+            let orphaned = sqlx::query_file_scalar!("queries/delete_user.1.sql", id.into_inner())
+                .fetch_one(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while checking group owners");
+                })
+                .context("Failed to check group owners")?;
+            if orphaned > 0 {
+                return Err(E::conflict(
+                    "A group must have at least one owner; transfer ownership or delete the group instead",
+                ));
+            }
+
+            let user = sqlx::query_file_as!(UserRow, "queries/delete_user.2.sql", id.into_inner())
+                .fetch_optional(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while deleting user");
+                })
+                .context("Failed to delete user in database")?
+                .ok_or_else(|| E::not_found("User not found"))?;
+            Ok(user.into())
+        })
+        .await
+    }
+
+    async fn restore_user(&self, ctx: C, id: domain::UserId) -> Result<domain::User, E> {
+        let user = sqlx::query_file_as!(UserRow, "queries/restore_user.sql", id.into_inner())
+            .fetch_optional(ctx.as_pg_pool())
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while restoring user");
             })
-            .context("Failed to update user in database")?;
+            .context("Failed to restore user in database")?
+            .ok_or_else(|| E::not_found("User not found"))?;
         Ok(user.into())
     }
+
+    async fn export_user_data(
+        &self,
+        ctx: C,
+        id: domain::UserId,
+    ) -> Result<domain::UserDataExport, E> {
+        let id = id.into_inner();
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let profile = sqlx::query_file_as!(UserRow, "queries/get_user.sql", id)
+                .fetch_optional(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while exporting user profile");
+                })
+                .context("Failed to export user profile")?
+                .ok_or_else(|| E::not_found("User not found"))?;
+
+            let group_memberships = sqlx::query_file_as!(
+                UserGroupMembershipRow,
+                "queries/export_user_group_memberships.sql",
+                id
+            )
+            .fetch_all(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while exporting group memberships");
+            })
+            .context("Failed to export group memberships")?;
+
+            let group_join_requests = sqlx::query_file_as!(
+                GroupJoinRequestRow,
+                "queries/export_user_group_join_requests.sql",
+                id
+            )
+            .fetch_all(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while exporting group join requests");
+            })
+            .context("Failed to export group join requests")?;
+
+            let decided_group_join_requests = sqlx::query_file_as!(
+                GroupJoinRequestRow,
+                "queries/export_user_decided_group_join_requests.sql",
+                id
+            )
+            .fetch_all(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while exporting decided group join requests");
+            })
+            .context("Failed to export decided group join requests")?;
+
+            let group_invites =
+                sqlx::query_file_as!(GroupInviteRow, "queries/export_user_group_invites.sql", id)
+                    .fetch_all(&mut *conn)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::error!(error = %e, "Postgres error while exporting group invites");
+                    })
+                    .context("Failed to export group invites")?;
+
+            let meeting_poll_votes = sqlx::query_file_as!(
+                UserMeetingPollVoteRow,
+                "queries/export_user_meeting_poll_votes.sql",
+                id
+            )
+            .fetch_all(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while exporting meeting poll votes");
+            })
+            .context("Failed to export meeting poll votes")?;
+
+            let meeting_polls =
+                sqlx::query_file_as!(MeetingPollRow, "queries/export_user_meeting_polls.sql", id)
+                    .fetch_all(&mut *conn)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::error!(error = %e, "Postgres error while exporting meeting polls");
+                    })
+                    .context("Failed to export meeting polls")?;
+
+            let group_events =
+                sqlx::query_file_as!(GroupEventRow, "queries/export_user_group_events.sql", id)
+                    .fetch_all(&mut *conn)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::error!(error = %e, "Postgres error while exporting group events");
+                    })
+                    .context("Failed to export group events")?;
+
+            let announcements =
+                sqlx::query_file_as!(AnnouncementRow, "queries/export_user_announcements.sql", id)
+                    .fetch_all(&mut *conn)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::error!(error = %e, "Postgres error while exporting announcements");
+                    })
+                    .context("Failed to export announcements")?;
+
+            let announcement_reads = sqlx::query_file_as!(
+                UserAnnouncementReadRow,
+                "queries/export_user_announcement_reads.sql",
+                id
+            )
+            .fetch_all(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while exporting announcement reads");
+            })
+            .context("Failed to export announcement reads")?;
+
+            let group_activities = sqlx::query_file_as!(
+                GroupActivityRow,
+                "queries/export_user_group_activities.sql",
+                id
+            )
+            .fetch_all(&mut *conn)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Postgres error while exporting group activities");
+            })
+            .context("Failed to export group activities")?
+            .into_iter()
+            .map(domain::GroupActivity::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .context("Inconsistent group activity row")?;
+
+            Ok(domain::UserDataExport {
+                exported_at: chrono::Utc::now(),
+                profile: profile.into(),
+                group_memberships: group_memberships.into_iter().map(Into::into).collect(),
+                group_join_requests: group_join_requests.into_iter().map(Into::into).collect(),
+                decided_group_join_requests: decided_group_join_requests
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                group_invites: group_invites.into_iter().map(Into::into).collect(),
+                meeting_poll_votes: meeting_poll_votes.into_iter().map(Into::into).collect(),
+                meeting_polls: meeting_polls.into_iter().map(Into::into).collect(),
+                group_events: group_events.into_iter().map(Into::into).collect(),
+                announcements: announcements.into_iter().map(Into::into).collect(),
+                announcement_reads: announcement_reads.into_iter().map(Into::into).collect(),
+                group_activities,
+            })
+        })
+        .await
+    }
+
+    async fn purge_deleted_users(
+        &self,
+        ctx: C,
+        deleted_before: domain::Timestamp,
+    ) -> Result<Vec<domain::UserId>, E> {
+        self.within_tx(ctx.as_pg_pool(), async |conn| {
+            let ids: Vec<uuid::Uuid> =
+                sqlx::query_file_scalar!("queries/purge_deleted_users.0.sql", deleted_before)
+                    .fetch_all(&mut *conn)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::error!(error = %e, "Postgres error while locking deleted users");
+                    })
+                    .context("Failed to fetch deleted users")?;
+            if ids.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let icon_urls = sqlx::query_file_scalar!("queries/purge_deleted_users.1.sql", &ids)
+                .fetch_all(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while purging orphaned groups");
+                })
+                .context("Failed to purge groups of deleted users")?;
+
+            let departures = sqlx::query_file!("queries/purge_deleted_users.2.sql", &ids)
+                .fetch_all(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while listing memberships of deleted users");
+                })
+                .context("Failed to fetch memberships of deleted users")?;
+
+            let heirs = sqlx::query_file!("queries/purge_deleted_users.3.sql", &ids)
+                .fetch_all(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while handing over group ownership");
+                })
+                .context("Failed to hand over groups of deleted users")?;
+
+            // 利用者の操作ではないので操作者は記録しない
+            for d in departures {
+                let left = domain::GroupActivityKind::MemberLeft {
+                    user_id: Some(domain::UserId::new(d.user_id)),
+                };
+                let group_id = domain::GroupId::new(d.group_id);
+                record_group_activities(conn, group_id, None, &[left]).await?;
+            }
+            for h in heirs {
+                let transferred = domain::GroupActivityKind::OwnershipTransferred {
+                    user_id: Some(domain::UserId::new(h.user_id)),
+                };
+                let group_id = domain::GroupId::new(h.group_id);
+                record_group_activities(conn, group_id, None, &[transferred]).await?;
+            }
+
+            let avatar_urls = sqlx::query_file_scalar!("queries/purge_deleted_users.4.sql", &ids)
+                .fetch_all(&mut *conn)
+                .await
+                .inspect_err(|e| {
+                    tracing::error!(error = %e, "Postgres error while purging deleted users");
+                })
+                .context("Failed to purge deleted users")?;
+            let media_urls = icon_urls.iter().chain(&avatar_urls).map(Option::as_deref);
+            release_media(conn, media_urls).await?;
+            Ok(ids.into_iter().map(domain::UserId::new).collect())
+        })
+        .await
+    }
 }
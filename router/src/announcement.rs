@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+
+use domain::{
+    Announcement, AnnouncementId, CreateAnnouncementParams, GroupId, UpdateAnnouncementParams,
+    UserId,
+};
+
+use crate::authn::AuthenticatedService;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct AnnouncementResponse {
+    pub id: uuid::Uuid,
+    pub group_id: uuid::Uuid,
+    pub author_id: Option<uuid::Uuid>,
+    pub title: String,
+    pub body: String,
+    pub pinned: bool,
+    pub read_by: Vec<uuid::Uuid>,
+    pub created_at: domain::Timestamp,
+    pub updated_at: domain::Timestamp,
+}
+
+impl From<Announcement> for AnnouncementResponse {
+    fn from(value: Announcement) -> Self {
+        let Announcement {
+            id,
+            group_id,
+            author_id,
+            title,
+            body,
+            pinned,
+            read_by,
+            created_at,
+            updated_at,
+        } = value;
+        Self {
+            id: id.into_inner(),
+            group_id: group_id.into_inner(),
+            author_id: author_id.map(UserId::into_inner),
+            title,
+            body,
+            pinned,
+            read_by: read_by.into_iter().map(UserId::into_inner).collect(),
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct CreateAnnouncementRequest {
+    pub title: String,
+    pub body: String,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+impl From<CreateAnnouncementRequest> for CreateAnnouncementParams {
+    fn from(value: CreateAnnouncementRequest) -> Self {
+        let CreateAnnouncementRequest {
+            title,
+            body,
+            pinned,
+        } = value;
+        Self {
+            title,
+            body,
+            pinned,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UpdateAnnouncementRequest {
+    pub title: String,
+    pub body: String,
+    pub pinned: bool,
+}
+
+impl From<UpdateAnnouncementRequest> for UpdateAnnouncementParams {
+    fn from(value: UpdateAnnouncementRequest) -> Self {
+        let UpdateAnnouncementRequest {
+            title,
+            body,
+            pinned,
+        } = value;
+        Self {
+            title,
+            body,
+            pinned,
+        }
+    }
+}
+
+impl<T, A> crate::Service<T>
+where
+    T: crate::StateRequirements<Authn = A>,
+    A: crate::AuthenticatedRequirements<Err = T::Err>,
+{
+    pub(crate) fn announcement_router(&self) -> axum::Router<Self> {
+        use axum::Json;
+        use axum::extract::Path;
+        use axum::routing::{get, post};
+
+        axum::Router::new()
+            .route(
+                "/groups/{id}/announcements",
+                get(async |a: AuthenticatedService<A>, Path(id)| {
+                    a.list_announcements(id).await.map(Json)
+                })
+                .post(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
+                    a.create_announcement(id, r).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/announcements/{announcement_id}",
+                get(
+                    async |a: AuthenticatedService<A>, Path((id, announcement_id))| {
+                        a.get_announcement(id, announcement_id).await.map(Json)
+                    },
+                )
+                .put(
+                    async |a: AuthenticatedService<A>, Path((id, announcement_id)), Json(r)| {
+                        a.update_announcement(id, announcement_id, r)
+                            .await
+                            .map(Json)
+                    },
+                )
+                .delete(
+                    async |a: AuthenticatedService<A>, Path((id, announcement_id))| {
+                        a.delete_announcement(id, announcement_id).await
+                    },
+                ),
+            )
+            .route(
+                "/groups/{id}/announcements/{announcement_id}/read",
+                post(
+                    async |a: AuthenticatedService<A>, Path((id, announcement_id))| {
+                        a.mark_announcement_read(id, announcement_id)
+                            .await
+                            .map(Json)
+                    },
+                ),
+            )
+    }
+}
+
+impl<A> AuthenticatedService<A>
+where
+    A: crate::AuthenticatedRequirements,
+{
+    pub(crate) async fn list_announcements(
+        &self,
+        group_id: uuid::Uuid,
+    ) -> Result<Vec<AnnouncementResponse>, crate::Error> {
+        let announcements = self
+            .service
+            .list_announcements(GroupId::new(group_id))
+            .await
+            .map_err(Into::into)?;
+        let announcements: Vec<_> = announcements
+            .into_iter()
+            .map(AnnouncementResponse::from)
+            .collect();
+        Ok(announcements)
+    }
+
+    pub(crate) async fn get_announcement(
+        &self,
+        group_id: uuid::Uuid,
+        announcement_id: uuid::Uuid,
+    ) -> Result<AnnouncementResponse, crate::Error> {
+        let announcement = self
+            .service
+            .get_announcement(GroupId::new(group_id), AnnouncementId::new(announcement_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(announcement.into())
+    }
+
+    pub(crate) async fn create_announcement(
+        &self,
+        group_id: uuid::Uuid,
+        request: CreateAnnouncementRequest,
+    ) -> Result<AnnouncementResponse, crate::Error> {
+        let announcement = self
+            .service
+            .create_announcement(GroupId::new(group_id), request.into())
+            .await
+            .map_err(Into::into)?;
+        Ok(announcement.into())
+    }
+
+    pub(crate) async fn update_announcement(
+        &self,
+        group_id: uuid::Uuid,
+        announcement_id: uuid::Uuid,
+        request: UpdateAnnouncementRequest,
+    ) -> Result<AnnouncementResponse, crate::Error> {
+        let announcement = self
+            .service
+            .update_announcement(
+                GroupId::new(group_id),
+                AnnouncementId::new(announcement_id),
+                request.into(),
+            )
+            .await
+            .map_err(Into::into)?;
+        Ok(announcement.into())
+    }
+
+    pub(crate) async fn delete_announcement(
+        &self,
+        group_id: uuid::Uuid,
+        announcement_id: uuid::Uuid,
+    ) -> Result<(), crate::Error> {
+        self.service
+            .delete_announcement(GroupId::new(group_id), AnnouncementId::new(announcement_id))
+            .await
+            .map_err(Into::into)
+    }
+
+    pub(crate) async fn mark_announcement_read(
+        &self,
+        group_id: uuid::Uuid,
+        announcement_id: uuid::Uuid,
+    ) -> Result<AnnouncementResponse, crate::Error> {
+        let announcement = self
+            .service
+            .mark_announcement_read(GroupId::new(group_id), AnnouncementId::new(announcement_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(announcement.into())
+    }
+}
@@ -1,16 +1,42 @@
 use serde::{Deserialize, Serialize};
 
-use domain::{CreateGroupParams, Group, GroupCore, GroupId, UpdateGroupParams, UserId};
+use domain::{
+    CreateGroupInviteParams, CreateGroupParams, Group, GroupCore, GroupId, GroupInvite,
+    GroupInviteCode, GroupJoinRequest, GroupJoinRequestId, GroupJoinRequestStatus, GroupMember,
+    GroupRole, ListGroupsFilter, UpdateGroupParams, UserId,
+};
 
 use crate::authn::AuthenticatedService;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupMemberResponse {
+    pub user_id: uuid::Uuid,
+    pub role: GroupRole,
+}
+
+impl From<GroupMember> for GroupMemberResponse {
+    fn from(value: GroupMember) -> Self {
+        let GroupMember { user_id, role } = value;
+        Self {
+            user_id: user_id.into_inner(),
+            role,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct GroupResponse {
     pub id: uuid::Uuid,
     pub name: String,
+    pub public: bool,
+    pub description: String,
+    pub icon_url: Option<String>,
+    pub website_url: Option<String>,
+    pub parent_id: Option<uuid::Uuid>,
     pub created_at: domain::Timestamp,
     pub updated_at: domain::Timestamp,
-    pub members: Vec<uuid::Uuid>,
+    pub archived_at: Option<domain::Timestamp>,
+    pub members: Vec<GroupMemberResponse>,
 }
 
 impl From<Group> for GroupResponse {
@@ -18,16 +44,28 @@ impl From<Group> for GroupResponse {
         let Group {
             id,
             name,
+            public,
+            description,
+            icon_url,
+            website_url,
+            parent_id,
             created_at,
             updated_at,
+            archived_at,
             members,
         } = value;
-        let members: Vec<_> = members.into_iter().map(UserId::into_inner).collect();
+        let members: Vec<_> = members.into_iter().map(GroupMemberResponse::from).collect();
         Self {
             id: id.into_inner(),
             name,
+            public,
+            description,
+            icon_url,
+            website_url,
+            parent_id: parent_id.map(GroupId::into_inner),
             created_at,
             updated_at,
+            archived_at,
             members,
         }
     }
@@ -37,8 +75,14 @@ impl From<Group> for GroupResponse {
 pub struct GroupCoreResponse {
     pub id: uuid::Uuid,
     pub name: String,
+    pub public: bool,
+    pub description: String,
+    pub icon_url: Option<String>,
+    pub website_url: Option<String>,
+    pub parent_id: Option<uuid::Uuid>,
     pub created_at: domain::Timestamp,
     pub updated_at: domain::Timestamp,
+    pub archived_at: Option<domain::Timestamp>,
 }
 
 impl From<GroupCore> for GroupCoreResponse {
@@ -46,41 +90,209 @@ impl From<GroupCore> for GroupCoreResponse {
         let GroupCore {
             id,
             name,
+            public,
+            description,
+            icon_url,
+            website_url,
+            parent_id,
             created_at,
             updated_at,
+            archived_at,
         } = value;
         Self {
             id: id.into_inner(),
             name,
+            public,
+            description,
+            icon_url,
+            website_url,
+            parent_id: parent_id.map(GroupId::into_inner),
             created_at,
             updated_at,
+            archived_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupMemberRequest {
+    pub user_id: uuid::Uuid,
+    #[serde(default)]
+    pub role: GroupRole,
+}
+
+impl From<GroupMemberRequest> for GroupMember {
+    fn from(value: GroupMemberRequest) -> Self {
+        let GroupMemberRequest { user_id, role } = value;
+        Self {
+            user_id: UserId::new(user_id),
+            role,
         }
     }
 }
 
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+)]
+pub struct AddGroupMemberRequest {
+    #[serde(default)]
+    pub role: GroupRole,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct TransferGroupOwnershipRequest {
+    pub user_id: uuid::Uuid,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct CreateGroupRequest {
     pub name: String,
-    pub members: Vec<uuid::Uuid>,
+    #[serde(default)]
+    pub public: bool,
+    #[serde(default)]
+    pub description: String,
+    pub website_url: Option<String>,
+    pub parent_id: Option<uuid::Uuid>,
+    pub members: Vec<GroupMemberRequest>,
 }
 
 impl From<CreateGroupRequest> for CreateGroupParams {
     fn from(value: CreateGroupRequest) -> Self {
-        let CreateGroupRequest { name, members } = value;
-        let members: Vec<_> = members.into_iter().map(UserId::new).collect();
-        Self { name, members }
+        let CreateGroupRequest {
+            name,
+            public,
+            description,
+            website_url,
+            parent_id,
+            members,
+        } = value;
+        let members: Vec<_> = members.into_iter().map(GroupMember::from).collect();
+        Self {
+            name,
+            public,
+            description,
+            website_url,
+            parent_id: parent_id.map(GroupId::new),
+            members,
+        }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+/// 省略したフィールドは変更されず, `null` を指定したフィールドは値が消えます。
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct UpdateGroupRequest {
-    pub name: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub public: Option<bool>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "crate::user::deserialize_nullable")]
+    pub website_url: Option<Option<String>>,
 }
 
 impl From<UpdateGroupRequest> for UpdateGroupParams {
     fn from(value: UpdateGroupRequest) -> Self {
-        let UpdateGroupRequest { name } = value;
-        Self { name }
+        let UpdateGroupRequest {
+            name,
+            public,
+            description,
+            website_url,
+        } = value;
+        Self {
+            name,
+            public,
+            description,
+            website_url,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupInviteResponse {
+    pub code: String,
+    pub group_id: uuid::Uuid,
+    pub created_by: uuid::Uuid,
+    pub single_use: bool,
+    pub use_count: i32,
+    pub expires_at: domain::Timestamp,
+    pub created_at: domain::Timestamp,
+}
+
+impl From<GroupInvite> for GroupInviteResponse {
+    fn from(value: GroupInvite) -> Self {
+        let GroupInvite {
+            code,
+            group_id,
+            created_by,
+            single_use,
+            use_count,
+            expires_at,
+            created_at,
+        } = value;
+        Self {
+            code: code.into_inner(),
+            group_id: group_id.into_inner(),
+            created_by: created_by.into_inner(),
+            single_use,
+            use_count,
+            expires_at,
+            created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct CreateGroupInviteRequest {
+    #[serde(default)]
+    pub single_use: bool,
+    pub expires_at: domain::Timestamp,
+}
+
+impl From<CreateGroupInviteRequest> for CreateGroupInviteParams {
+    fn from(value: CreateGroupInviteRequest) -> Self {
+        let CreateGroupInviteRequest {
+            single_use,
+            expires_at,
+        } = value;
+        Self {
+            single_use,
+            expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupJoinRequestResponse {
+    pub id: uuid::Uuid,
+    pub group_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub status: GroupJoinRequestStatus,
+    pub decided_by: Option<uuid::Uuid>,
+    pub created_at: domain::Timestamp,
+    pub updated_at: domain::Timestamp,
+}
+
+impl From<GroupJoinRequest> for GroupJoinRequestResponse {
+    fn from(value: GroupJoinRequest) -> Self {
+        let GroupJoinRequest {
+            id,
+            group_id,
+            user_id,
+            status,
+            decided_by,
+            created_at,
+            updated_at,
+        } = value;
+        Self {
+            id: id.into_inner(),
+            group_id: group_id.into_inner(),
+            user_id: user_id.into_inner(),
+            status,
+            decided_by: decided_by.map(UserId::into_inner),
+            created_at,
+            updated_at,
+        }
     }
 }
 
@@ -92,28 +304,105 @@ where
     pub(crate) fn group_router(&self) -> axum::Router<Self> {
         use axum::Json;
         use axum::extract::Path;
-        use axum::routing::{get, put};
+        use axum::routing::{get, post, put};
 
         axum::Router::new()
             .route(
                 "/groups",
-                get(async |a: AuthenticatedService<A>| a.list_groups().await.map(Json)).post(
-                    async |a: AuthenticatedService<A>, Json(r)| a.create_group(r).await.map(Json),
-                ),
+                get(async |a: AuthenticatedService<A>| {
+                    a.list_groups(ListGroupsFilter::Public).await.map(Json)
+                })
+                .post(async |a: AuthenticatedService<A>, Json(r)| {
+                    a.create_group(r).await.map(Json)
+                }),
+            )
+            .route(
+                "/me/groups",
+                get(async |a: AuthenticatedService<A>| {
+                    a.list_groups(ListGroupsFilter::Joined).await.map(Json)
+                }),
             )
             .route(
                 "/groups/{id}",
                 get(async |a: AuthenticatedService<A>, Path(id)| a.get_group(id).await.map(Json))
                     .put(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
                         a.update_group(id, r).await.map(Json)
-                    }),
+                    })
+                    .patch(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
+                        a.update_group(id, r).await.map(Json)
+                    })
+                    .delete(async |a: AuthenticatedService<A>, Path(id)| a.delete_group(id).await),
+            )
+            .route(
+                "/groups/{id}/invites",
+                post(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
+                    a.create_group_invite(id, r).await.map(Json)
+                }),
+            )
+            .route(
+                "/invites/{code}/accept",
+                post(async |a: AuthenticatedService<A>, Path(code)| {
+                    a.accept_group_invite(code).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/join-requests",
+                get(async |a: AuthenticatedService<A>, Path(id)| {
+                    a.list_group_join_requests(id).await.map(Json)
+                })
+                .post(async |a: AuthenticatedService<A>, Path(id)| {
+                    a.create_group_join_request(id).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/join-requests/{request_id}/approve",
+                post(async |a: AuthenticatedService<A>, Path((id, request_id))| {
+                    a.approve_group_join_request(id, request_id).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/join-requests/{request_id}/reject",
+                post(async |a: AuthenticatedService<A>, Path((id, request_id))| {
+                    a.reject_group_join_request(id, request_id).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/archive",
+                post(async |a: AuthenticatedService<A>, Path(id)| {
+                    a.archive_group(id).await.map(Json)
+                }),
             )
             .route(
                 "/groups/{id}/members",
                 put(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
                     a.update_group_members(id, r).await.map(Json)
+                })
+                .post(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
+                    a.add_group_members(id, r).await.map(Json)
                 }),
             )
+            .route(
+                "/groups/{id}/members/{user_id}",
+                post(
+                    async |a: AuthenticatedService<A>, Path((id, user_id)), r: Option<Json<_>>| {
+                        let r = r.map(|Json(r)| r).unwrap_or_default();
+                        a.add_group_member(id, user_id, r).await.map(Json)
+                    },
+                )
+                .delete(async |a: AuthenticatedService<A>, Path((id, user_id))| {
+                    a.remove_group_member(id, user_id).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/transfer-ownership",
+                post(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
+                    a.transfer_group_ownership(id, r).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/leave",
+                post(async |a: AuthenticatedService<A>, Path(id)| a.leave_group(id).await),
+            )
     }
 }
 
@@ -133,8 +422,11 @@ where
         Ok(group.into())
     }
 
-    pub(crate) async fn list_groups(&self) -> Result<Vec<GroupCoreResponse>, crate::Error> {
-        let groups = self.service.list_groups().await.map_err(Into::into)?;
+    pub(crate) async fn list_groups(
+        &self,
+        filter: ListGroupsFilter,
+    ) -> Result<Vec<GroupCoreResponse>, crate::Error> {
+        let groups = self.service.list_groups(filter).await.map_err(Into::into)?;
         let groups: Vec<_> = groups.into_iter().map(GroupCoreResponse::from).collect();
         Ok(groups)
     }
@@ -167,9 +459,9 @@ where
     pub(crate) async fn update_group_members(
         &self,
         group_id: uuid::Uuid,
-        members: Vec<uuid::Uuid>,
+        members: Vec<GroupMemberRequest>,
     ) -> Result<GroupResponse, crate::Error> {
-        let members: Vec<_> = members.into_iter().map(domain::UserId::new).collect();
+        let members: Vec<_> = members.into_iter().map(GroupMember::from).collect();
         let group = self
             .service
             .update_group_members(GroupId::new(group_id), &members)
@@ -177,4 +469,175 @@ where
             .map_err(Into::into)?;
         Ok(group.into())
     }
+
+    pub(crate) async fn add_group_members(
+        &self,
+        group_id: uuid::Uuid,
+        members: Vec<GroupMemberRequest>,
+    ) -> Result<GroupResponse, crate::Error> {
+        let members: Vec<_> = members.into_iter().map(GroupMember::from).collect();
+        let group = self
+            .service
+            .add_group_members(GroupId::new(group_id), &members)
+            .await
+            .map_err(Into::into)?;
+        Ok(group.into())
+    }
+
+    pub(crate) async fn add_group_member(
+        &self,
+        group_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+        request: AddGroupMemberRequest,
+    ) -> Result<GroupResponse, crate::Error> {
+        let member = GroupMember {
+            user_id: UserId::new(user_id),
+            role: request.role,
+        };
+        let group = self
+            .service
+            .add_group_members(GroupId::new(group_id), &[member])
+            .await
+            .map_err(Into::into)?;
+        Ok(group.into())
+    }
+
+    pub(crate) async fn remove_group_member(
+        &self,
+        group_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> Result<GroupResponse, crate::Error> {
+        let group = self
+            .service
+            .remove_group_member(GroupId::new(group_id), UserId::new(user_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(group.into())
+    }
+
+    pub(crate) async fn transfer_group_ownership(
+        &self,
+        group_id: uuid::Uuid,
+        request: TransferGroupOwnershipRequest,
+    ) -> Result<GroupResponse, crate::Error> {
+        let group = self
+            .service
+            .transfer_group_ownership(GroupId::new(group_id), UserId::new(request.user_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(group.into())
+    }
+
+    pub(crate) async fn leave_group(
+        &self,
+        group_id: uuid::Uuid,
+    ) -> Result<http::StatusCode, crate::Error> {
+        self.service
+            .leave_group(GroupId::new(group_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(http::StatusCode::NO_CONTENT)
+    }
+
+    pub(crate) async fn archive_group(
+        &self,
+        group_id: uuid::Uuid,
+    ) -> Result<GroupResponse, crate::Error> {
+        let group = self
+            .service
+            .archive_group(GroupId::new(group_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(group.into())
+    }
+
+    pub(crate) async fn delete_group(
+        &self,
+        group_id: uuid::Uuid,
+    ) -> Result<http::StatusCode, crate::Error> {
+        self.service
+            .delete_group(GroupId::new(group_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(http::StatusCode::NO_CONTENT)
+    }
+
+    pub(crate) async fn create_group_invite(
+        &self,
+        group_id: uuid::Uuid,
+        request: CreateGroupInviteRequest,
+    ) -> Result<GroupInviteResponse, crate::Error> {
+        let invite = self
+            .service
+            .create_group_invite(GroupId::new(group_id), request.into())
+            .await
+            .map_err(Into::into)?;
+        Ok(invite.into())
+    }
+
+    pub(crate) async fn accept_group_invite(
+        &self,
+        code: String,
+    ) -> Result<GroupResponse, crate::Error> {
+        let group = self
+            .service
+            .accept_group_invite(&GroupInviteCode::new(code))
+            .await
+            .map_err(Into::into)?;
+        Ok(group.into())
+    }
+
+    pub(crate) async fn list_group_join_requests(
+        &self,
+        group_id: uuid::Uuid,
+    ) -> Result<Vec<GroupJoinRequestResponse>, crate::Error> {
+        let requests = self
+            .service
+            .list_group_join_requests(GroupId::new(group_id))
+            .await
+            .map_err(Into::into)?;
+        let requests: Vec<_> = requests
+            .into_iter()
+            .map(GroupJoinRequestResponse::from)
+            .collect();
+        Ok(requests)
+    }
+
+    pub(crate) async fn create_group_join_request(
+        &self,
+        group_id: uuid::Uuid,
+    ) -> Result<GroupJoinRequestResponse, crate::Error> {
+        let request = self
+            .service
+            .create_group_join_request(GroupId::new(group_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(request.into())
+    }
+
+    pub(crate) async fn approve_group_join_request(
+        &self,
+        group_id: uuid::Uuid,
+        request_id: uuid::Uuid,
+    ) -> Result<GroupJoinRequestResponse, crate::Error> {
+        let request = self
+            .service
+            .approve_group_join_request(GroupId::new(group_id), GroupJoinRequestId::new(request_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(request.into())
+    }
+
+    pub(crate) async fn reject_group_join_request(
+        &self,
+        group_id: uuid::Uuid,
+        request_id: uuid::Uuid,
+    ) -> Result<GroupJoinRequestResponse, crate::Error> {
+        let request = self
+            .service
+            .reject_group_join_request(GroupId::new(group_id), GroupJoinRequestId::new(request_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(request.into())
+    }
 }
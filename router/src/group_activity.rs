@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use domain::{
+    GroupActivity, GroupActivityId, GroupActivityKind, GroupActivityPage, GroupId,
+    ListGroupActivitiesParams,
+};
+
+use crate::authn::AuthenticatedService;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupActivityResponse {
+    pub id: uuid::Uuid,
+    pub group_id: uuid::Uuid,
+    pub actor_id: Option<uuid::Uuid>,
+    #[serde(flatten)]
+    pub kind: GroupActivityKind,
+    pub created_at: domain::Timestamp,
+}
+
+impl From<GroupActivity> for GroupActivityResponse {
+    fn from(value: GroupActivity) -> Self {
+        let GroupActivity {
+            id,
+            group_id,
+            actor_id,
+            kind,
+            created_at,
+        } = value;
+        Self {
+            id: id.into_inner(),
+            group_id: group_id.into_inner(),
+            actor_id: actor_id.map(domain::UserId::into_inner),
+            kind,
+            created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupActivityPageResponse {
+    pub activities: Vec<GroupActivityResponse>,
+    pub next_cursor: Option<uuid::Uuid>,
+}
+
+impl From<GroupActivityPage> for GroupActivityPageResponse {
+    fn from(value: GroupActivityPage) -> Self {
+        let GroupActivityPage {
+            activities,
+            next_cursor,
+        } = value;
+        Self {
+            activities: activities
+                .into_iter()
+                .map(GroupActivityResponse::from)
+                .collect(),
+            next_cursor: next_cursor.map(GroupActivityId::into_inner),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct ListGroupActivitiesQuery {
+    pub cursor: Option<uuid::Uuid>,
+    #[serde(default = "ListGroupActivitiesQuery::default_limit")]
+    pub limit: u32,
+}
+
+impl ListGroupActivitiesQuery {
+    fn default_limit() -> u32 {
+        ListGroupActivitiesParams::DEFAULT_LIMIT
+    }
+}
+
+impl From<ListGroupActivitiesQuery> for ListGroupActivitiesParams {
+    fn from(value: ListGroupActivitiesQuery) -> Self {
+        let ListGroupActivitiesQuery { cursor, limit } = value;
+        Self {
+            cursor: cursor.map(GroupActivityId::new),
+            limit,
+        }
+    }
+}
+
+impl<T, A> crate::Service<T>
+where
+    T: crate::StateRequirements<Authn = A>,
+    A: crate::AuthenticatedRequirements<Err = T::Err>,
+{
+    pub(crate) fn group_activity_router(&self) -> axum::Router<Self> {
+        use axum::Json;
+        use axum::extract::{Path, Query};
+        use axum::routing::get;
+
+        axum::Router::new().route(
+            "/groups/{id}/activity",
+            get(async |a: AuthenticatedService<A>, Path(id), Query(q)| {
+                a.list_group_activities(id, q).await.map(Json)
+            }),
+        )
+    }
+}
+
+impl<A> AuthenticatedService<A>
+where
+    A: crate::AuthenticatedRequirements,
+{
+    pub(crate) async fn list_group_activities(
+        &self,
+        group_id: uuid::Uuid,
+        query: ListGroupActivitiesQuery,
+    ) -> Result<GroupActivityPageResponse, crate::Error> {
+        let page = self
+            .service
+            .list_group_activities(GroupId::new(group_id), query.into())
+            .await
+            .map_err(Into::into)?;
+        Ok(page.into())
+    }
+}
@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+
+use domain::{
+    CreateGroupEventParams, GroupEvent, GroupEventId, GroupEventRecurrence, GroupId,
+    RecurrenceFrequency, UpdateGroupEventParams,
+};
+
+use crate::authn::AuthenticatedService;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupEventRecurrenceBody {
+    pub frequency: RecurrenceFrequency,
+    #[serde(default = "default_interval")]
+    pub interval: i32,
+    pub until: Option<domain::Timestamp>,
+}
+
+const fn default_interval() -> i32 {
+    1
+}
+
+impl From<GroupEventRecurrence> for GroupEventRecurrenceBody {
+    fn from(value: GroupEventRecurrence) -> Self {
+        let GroupEventRecurrence {
+            frequency,
+            interval,
+            until,
+        } = value;
+        Self {
+            frequency,
+            interval,
+            until,
+        }
+    }
+}
+
+impl From<GroupEventRecurrenceBody> for GroupEventRecurrence {
+    fn from(value: GroupEventRecurrenceBody) -> Self {
+        let GroupEventRecurrenceBody {
+            frequency,
+            interval,
+            until,
+        } = value;
+        Self {
+            frequency,
+            interval,
+            until,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct GroupEventResponse {
+    pub id: uuid::Uuid,
+    pub group_id: uuid::Uuid,
+    pub title: String,
+    pub location: Option<String>,
+    pub starts_at: domain::Timestamp,
+    pub ends_at: domain::Timestamp,
+    pub recurrence: Option<GroupEventRecurrenceBody>,
+    pub created_by: Option<uuid::Uuid>,
+    pub created_at: domain::Timestamp,
+    pub updated_at: domain::Timestamp,
+}
+
+impl From<GroupEvent> for GroupEventResponse {
+    fn from(value: GroupEvent) -> Self {
+        let GroupEvent {
+            id,
+            group_id,
+            title,
+            location,
+            starts_at,
+            ends_at,
+            recurrence,
+            created_by,
+            created_at,
+            updated_at,
+        } = value;
+        Self {
+            id: id.into_inner(),
+            group_id: group_id.into_inner(),
+            title,
+            location,
+            starts_at,
+            ends_at,
+            recurrence: recurrence.map(GroupEventRecurrenceBody::from),
+            created_by: created_by.map(domain::UserId::into_inner),
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct CreateGroupEventRequest {
+    pub title: String,
+    pub location: Option<String>,
+    pub starts_at: domain::Timestamp,
+    pub ends_at: domain::Timestamp,
+    pub recurrence: Option<GroupEventRecurrenceBody>,
+}
+
+impl From<CreateGroupEventRequest> for CreateGroupEventParams {
+    fn from(value: CreateGroupEventRequest) -> Self {
+        let CreateGroupEventRequest {
+            title,
+            location,
+            starts_at,
+            ends_at,
+            recurrence,
+        } = value;
+        Self {
+            title,
+            location,
+            starts_at,
+            ends_at,
+            recurrence: recurrence.map(GroupEventRecurrence::from),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UpdateGroupEventRequest {
+    pub title: String,
+    pub location: Option<String>,
+    pub starts_at: domain::Timestamp,
+    pub ends_at: domain::Timestamp,
+    pub recurrence: Option<GroupEventRecurrenceBody>,
+}
+
+impl From<UpdateGroupEventRequest> for UpdateGroupEventParams {
+    fn from(value: UpdateGroupEventRequest) -> Self {
+        let UpdateGroupEventRequest {
+            title,
+            location,
+            starts_at,
+            ends_at,
+            recurrence,
+        } = value;
+        Self {
+            title,
+            location,
+            starts_at,
+            ends_at,
+            recurrence: recurrence.map(GroupEventRecurrence::from),
+        }
+    }
+}
+
+impl<T, A> crate::Service<T>
+where
+    T: crate::StateRequirements<Authn = A>,
+    A: crate::AuthenticatedRequirements<Err = T::Err>,
+{
+    pub(crate) fn group_event_router(&self) -> axum::Router<Self> {
+        use axum::Json;
+        use axum::extract::Path;
+        use axum::routing::get;
+
+        axum::Router::new()
+            .route(
+                "/groups/{id}/events",
+                get(async |a: AuthenticatedService<A>, Path(id)| {
+                    a.list_group_events(id).await.map(Json)
+                })
+                .post(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
+                    a.create_group_event(id, r).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/events/{event_id}",
+                get(async |a: AuthenticatedService<A>, Path((id, event_id))| {
+                    a.get_group_event(id, event_id).await.map(Json)
+                })
+                .put(
+                    async |a: AuthenticatedService<A>, Path((id, event_id)), Json(r)| {
+                        a.update_group_event(id, event_id, r).await.map(Json)
+                    },
+                )
+                .delete(
+                    async |a: AuthenticatedService<A>, Path((id, event_id))| {
+                        a.delete_group_event(id, event_id).await
+                    },
+                ),
+            )
+    }
+}
+
+impl<A> AuthenticatedService<A>
+where
+    A: crate::AuthenticatedRequirements,
+{
+    pub(crate) async fn list_group_events(
+        &self,
+        group_id: uuid::Uuid,
+    ) -> Result<Vec<GroupEventResponse>, crate::Error> {
+        let events = self
+            .service
+            .list_group_events(GroupId::new(group_id))
+            .await
+            .map_err(Into::into)?;
+        let events: Vec<_> = events.into_iter().map(GroupEventResponse::from).collect();
+        Ok(events)
+    }
+
+    pub(crate) async fn get_group_event(
+        &self,
+        group_id: uuid::Uuid,
+        event_id: uuid::Uuid,
+    ) -> Result<GroupEventResponse, crate::Error> {
+        let event = self
+            .service
+            .get_group_event(GroupId::new(group_id), GroupEventId::new(event_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(event.into())
+    }
+
+    pub(crate) async fn create_group_event(
+        &self,
+        group_id: uuid::Uuid,
+        request: CreateGroupEventRequest,
+    ) -> Result<GroupEventResponse, crate::Error> {
+        let event = self
+            .service
+            .create_group_event(GroupId::new(group_id), request.into())
+            .await
+            .map_err(Into::into)?;
+        Ok(event.into())
+    }
+
+    pub(crate) async fn update_group_event(
+        &self,
+        group_id: uuid::Uuid,
+        event_id: uuid::Uuid,
+        request: UpdateGroupEventRequest,
+    ) -> Result<GroupEventResponse, crate::Error> {
+        let event = self
+            .service
+            .update_group_event(
+                GroupId::new(group_id),
+                GroupEventId::new(event_id),
+                request.into(),
+            )
+            .await
+            .map_err(Into::into)?;
+        Ok(event.into())
+    }
+
+    pub(crate) async fn delete_group_event(
+        &self,
+        group_id: uuid::Uuid,
+        event_id: uuid::Uuid,
+    ) -> Result<(), crate::Error> {
+        self.service
+            .delete_group_event(GroupId::new(group_id), GroupEventId::new(event_id))
+            .await
+            .map_err(Into::into)
+    }
+}
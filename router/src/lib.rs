@@ -1,8 +1,13 @@
 use std::sync::Arc;
 
+mod announcement;
 mod authn;
 pub mod error;
 mod group;
+mod group_activity;
+mod group_event;
+mod media;
+mod meeting_poll;
 mod user;
 
 pub use error::Error;
@@ -11,6 +16,7 @@ pub struct Service<T>(Arc<T>);
 
 pub trait StateRequirements:
     domain::ProvideUserService<Error = Self::Err>
+    + domain::ProvideMediaService<Error = Self::Err>
     + service::MakeAuthenticated<Self::Err, Authenticated = Self::Authn>
     + 'static
 {
@@ -20,7 +26,12 @@ pub trait StateRequirements:
 
 pub trait AuthenticatedRequirements:
     domain::ProvideUserService<Error = Self::Err>
+    + domain::ProvideAnnouncementService<Error = Self::Err>
     + domain::ProvideGroupService<Error = Self::Err>
+    + domain::ProvideGroupActivityService<Error = Self::Err>
+    + domain::ProvideGroupEventService<Error = Self::Err>
+    + domain::ProvideMediaService<Error = Self::Err>
+    + domain::ProvideMeetingPollService<Error = Self::Err>
     + 'static
 {
     type Err: domain::Error + Into<Error>;
@@ -29,6 +40,7 @@ pub trait AuthenticatedRequirements:
 impl<T, A, E> StateRequirements for T
 where
     T: domain::ProvideUserService<Error = E>
+        + domain::ProvideMediaService<Error = E>
         + service::MakeAuthenticated<E, Authenticated = A>
         + 'static,
     E: domain::Error + Into<Error>,
@@ -40,7 +52,14 @@ where
 
 impl<A, E> AuthenticatedRequirements for A
 where
-    A: domain::ProvideUserService<Error = E> + domain::ProvideGroupService<Error = E> + 'static,
+    A: domain::ProvideUserService<Error = E>
+        + domain::ProvideAnnouncementService<Error = E>
+        + domain::ProvideGroupService<Error = E>
+        + domain::ProvideGroupActivityService<Error = E>
+        + domain::ProvideGroupEventService<Error = E>
+        + domain::ProvideMediaService<Error = E>
+        + domain::ProvideMeetingPollService<Error = E>
+        + 'static,
     E: domain::Error + Into<Error>,
 {
     type Err = E;
@@ -76,7 +95,12 @@ where
         ];
 
         let api = axum::Router::new()
+            .merge(self.announcement_router())
             .merge(self.group_router())
+            .merge(self.group_activity_router())
+            .merge(self.group_event_router())
+            .merge(self.media_upload_router())
+            .merge(self.meeting_poll_router())
             .merge(self.user_router());
         let layer = tower::ServiceBuilder::new()
             .set_x_request_id(MakeRequestUuid)
@@ -92,6 +116,7 @@ where
         axum::Router::new()
             .route("/ping", get(async || "pong"))
             .nest("/api", api)
+            .merge(self.media_router())
             .with_state(self)
             .layer(layer)
     }
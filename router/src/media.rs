@@ -0,0 +1,171 @@
+use domain::{GroupId, Media, MediaHash, UserId};
+
+use crate::authn::AuthenticatedService;
+use crate::group::GroupResponse;
+use crate::user::UserResponse;
+
+/// multipart のヘッダーなど, 画像以外の部分に見込む大きさ (バイト)
+const MULTIPART_OVERHEAD: usize = 64 * 1024;
+
+impl<T, A> crate::Service<T>
+where
+    T: crate::StateRequirements<Authn = A>,
+    A: crate::AuthenticatedRequirements<Err = T::Err>,
+{
+    /// 画像のアップロードと, アバターやアイコンの削除を受け付けます。
+    pub(crate) fn media_upload_router(&self) -> axum::Router<Self> {
+        use axum::Json;
+        use axum::extract::{DefaultBodyLimit, Multipart, Path};
+        use axum::routing::put;
+
+        axum::Router::new()
+            .route(
+                "/users/{id}/avatar",
+                put(async |a: AuthenticatedService<A>, Path(id), m: Multipart| {
+                    a.upload_user_avatar(id, m).await.map(Json)
+                })
+                .delete(async |a: AuthenticatedService<A>, Path(id)| {
+                    a.delete_user_avatar(id).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/icon",
+                put(async |a: AuthenticatedService<A>, Path(id), m: Multipart| {
+                    a.upload_group_icon(id, m).await.map(Json)
+                })
+                .delete(async |a: AuthenticatedService<A>, Path(id)| {
+                    a.delete_group_icon(id).await.map(Json)
+                }),
+            )
+            .layer(DefaultBodyLimit::max(
+                Media::MAX_UPLOAD_SIZE + MULTIPART_OVERHEAD,
+            ))
+    }
+
+    /// 保存された画像を配信します。`<img>` から直接参照されるよう `/api` の外に置きます。
+    pub(crate) fn media_router(&self) -> axum::Router<Self> {
+        use axum::extract::{Path, State};
+        use axum::routing::get;
+        use axum_extra::TypedHeader;
+        use headers::IfNoneMatch;
+
+        axum::Router::new().route(
+            "/media/{hash}",
+            get(
+                async |State(s): State<Self>,
+                       Path(hash),
+                       if_none_match: Option<TypedHeader<IfNoneMatch>>| {
+                    let if_none_match = if_none_match.map(|TypedHeader(h)| h);
+                    s.get_media(hash, if_none_match).await
+                },
+            ),
+        )
+    }
+
+    pub(crate) async fn get_media(
+        &self,
+        hash: String,
+        if_none_match: Option<headers::IfNoneMatch>,
+    ) -> Result<axum::response::Response, crate::Error> {
+        use axum::response::IntoResponse;
+        use http::header::{CACHE_CONTROL, CONTENT_TYPE, ETAG};
+
+        // 内容はハッシュで決まり, 同じ URL の中身が変わることはない
+        const CACHE: &str = "public, max-age=31536000, immutable";
+
+        let media = self
+            .0
+            .get_media(MediaHash::new(hash))
+            .await
+            .map_err(Into::into)?;
+        let etag = format!("\"{}\"", media.hash);
+        let not_modified = etag
+            .parse::<headers::ETag>()
+            .ok()
+            .zip(if_none_match)
+            .is_some_and(|(tag, inm)| !inm.precondition_passes(&tag));
+        if not_modified {
+            let headers = [(CACHE_CONTROL, CACHE.to_string()), (ETAG, etag)];
+            return Ok((http::StatusCode::NOT_MODIFIED, headers).into_response());
+        }
+        let headers = [
+            (CONTENT_TYPE, media.content_type),
+            (CACHE_CONTROL, CACHE.to_string()),
+            (ETAG, etag),
+        ];
+        Ok((headers, media.data).into_response())
+    }
+}
+
+/// multipart の `file` フィールドの中身を読みます。
+async fn read_file_field(mut multipart: axum::extract::Multipart) -> Result<Vec<u8>, crate::Error> {
+    let multipart_error =
+        |e: axum::extract::multipart::MultipartError| crate::Error::new(e.status(), e.body_text());
+    while let Some(field) = multipart.next_field().await.map_err(multipart_error)? {
+        if field.name() == Some("file") {
+            let data = field.bytes().await.map_err(multipart_error)?;
+            return Ok(data.into());
+        }
+    }
+    Err(crate::Error::new(
+        http::StatusCode::BAD_REQUEST,
+        "Missing file field",
+    ))
+}
+
+impl<A> AuthenticatedService<A>
+where
+    A: crate::AuthenticatedRequirements,
+{
+    pub(crate) async fn upload_user_avatar(
+        &self,
+        user_id: uuid::Uuid,
+        multipart: axum::extract::Multipart,
+    ) -> Result<UserResponse, crate::Error> {
+        let image = read_file_field(multipart).await?;
+        let user = self
+            .service
+            .upload_user_avatar(UserId::new(user_id), image)
+            .await
+            .map_err(Into::into)?;
+        Ok(user.into())
+    }
+
+    pub(crate) async fn delete_user_avatar(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> Result<UserResponse, crate::Error> {
+        let user = self
+            .service
+            .delete_user_avatar(UserId::new(user_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(user.into())
+    }
+
+    pub(crate) async fn upload_group_icon(
+        &self,
+        group_id: uuid::Uuid,
+        multipart: axum::extract::Multipart,
+    ) -> Result<GroupResponse, crate::Error> {
+        let image = read_file_field(multipart).await?;
+        let group = self
+            .service
+            .upload_group_icon(GroupId::new(group_id), image)
+            .await
+            .map_err(Into::into)?;
+        Ok(group.into())
+    }
+
+    pub(crate) async fn delete_group_icon(
+        &self,
+        group_id: uuid::Uuid,
+    ) -> Result<GroupResponse, crate::Error> {
+        let group = self
+            .service
+            .delete_group_icon(GroupId::new(group_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(group.into())
+    }
+}
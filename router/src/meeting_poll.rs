@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+
+use domain::{
+    CastMeetingPollVote, CreateMeetingPollParams, GroupEventId, GroupId, MeetingAvailability,
+    MeetingPoll, MeetingPollCandidate, MeetingPollCandidateId, MeetingPollId, MeetingPollVote,
+    MeetingSlot,
+};
+
+use crate::authn::AuthenticatedService;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct MeetingPollCandidateResponse {
+    pub id: uuid::Uuid,
+    pub starts_at: domain::Timestamp,
+    pub ends_at: domain::Timestamp,
+}
+
+impl From<MeetingPollCandidate> for MeetingPollCandidateResponse {
+    fn from(value: MeetingPollCandidate) -> Self {
+        let MeetingPollCandidate {
+            id,
+            starts_at,
+            ends_at,
+        } = value;
+        Self {
+            id: id.into_inner(),
+            starts_at,
+            ends_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct MeetingPollVoteResponse {
+    pub candidate_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub availability: MeetingAvailability,
+}
+
+impl From<MeetingPollVote> for MeetingPollVoteResponse {
+    fn from(value: MeetingPollVote) -> Self {
+        let MeetingPollVote {
+            candidate_id,
+            user_id,
+            availability,
+        } = value;
+        Self {
+            candidate_id: candidate_id.into_inner(),
+            user_id: user_id.into_inner(),
+            availability,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct MeetingPollResponse {
+    pub id: uuid::Uuid,
+    pub group_id: uuid::Uuid,
+    pub title: String,
+    pub organizer_id: Option<uuid::Uuid>,
+    pub candidates: Vec<MeetingPollCandidateResponse>,
+    pub votes: Vec<MeetingPollVoteResponse>,
+    pub finalized_candidate_id: Option<uuid::Uuid>,
+    pub event_id: Option<uuid::Uuid>,
+    pub created_at: domain::Timestamp,
+    pub updated_at: domain::Timestamp,
+}
+
+impl From<MeetingPoll> for MeetingPollResponse {
+    fn from(value: MeetingPoll) -> Self {
+        let MeetingPoll {
+            id,
+            group_id,
+            title,
+            organizer_id,
+            candidates,
+            votes,
+            finalized_candidate_id,
+            event_id,
+            created_at,
+            updated_at,
+        } = value;
+        let candidates: Vec<_> = candidates
+            .into_iter()
+            .map(MeetingPollCandidateResponse::from)
+            .collect();
+        let votes: Vec<_> = votes
+            .into_iter()
+            .map(MeetingPollVoteResponse::from)
+            .collect();
+        Self {
+            id: id.into_inner(),
+            group_id: group_id.into_inner(),
+            title,
+            organizer_id: organizer_id.map(domain::UserId::into_inner),
+            candidates,
+            votes,
+            finalized_candidate_id: finalized_candidate_id.map(MeetingPollCandidateId::into_inner),
+            event_id: event_id.map(GroupEventId::into_inner),
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct MeetingSlotRequest {
+    pub starts_at: domain::Timestamp,
+    pub ends_at: domain::Timestamp,
+}
+
+impl From<MeetingSlotRequest> for MeetingSlot {
+    fn from(value: MeetingSlotRequest) -> Self {
+        let MeetingSlotRequest { starts_at, ends_at } = value;
+        Self { starts_at, ends_at }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct CreateMeetingPollRequest {
+    pub title: String,
+    pub candidates: Vec<MeetingSlotRequest>,
+}
+
+impl From<CreateMeetingPollRequest> for CreateMeetingPollParams {
+    fn from(value: CreateMeetingPollRequest) -> Self {
+        let CreateMeetingPollRequest { title, candidates } = value;
+        let candidates: Vec<_> = candidates.into_iter().map(MeetingSlot::from).collect();
+        Self { title, candidates }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct MeetingPollVoteRequest {
+    pub candidate_id: uuid::Uuid,
+    pub availability: MeetingAvailability,
+}
+
+impl From<MeetingPollVoteRequest> for CastMeetingPollVote {
+    fn from(value: MeetingPollVoteRequest) -> Self {
+        let MeetingPollVoteRequest {
+            candidate_id,
+            availability,
+        } = value;
+        Self {
+            candidate_id: MeetingPollCandidateId::new(candidate_id),
+            availability,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct FinalizeMeetingPollRequest {
+    pub candidate_id: uuid::Uuid,
+}
+
+impl<T, A> crate::Service<T>
+where
+    T: crate::StateRequirements<Authn = A>,
+    A: crate::AuthenticatedRequirements<Err = T::Err>,
+{
+    pub(crate) fn meeting_poll_router(&self) -> axum::Router<Self> {
+        use axum::Json;
+        use axum::extract::Path;
+        use axum::routing::{get, post, put};
+
+        axum::Router::new()
+            .route(
+                "/groups/{id}/meeting-polls",
+                get(async |a: AuthenticatedService<A>, Path(id)| {
+                    a.list_meeting_polls(id).await.map(Json)
+                })
+                .post(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
+                    a.create_meeting_poll(id, r).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/meeting-polls/{poll_id}",
+                get(async |a: AuthenticatedService<A>, Path((id, poll_id))| {
+                    a.get_meeting_poll(id, poll_id).await.map(Json)
+                }),
+            )
+            .route(
+                "/groups/{id}/meeting-polls/{poll_id}/votes",
+                put(
+                    async |a: AuthenticatedService<A>, Path((id, poll_id)), Json(r)| {
+                        a.vote_meeting_poll(id, poll_id, r).await.map(Json)
+                    },
+                ),
+            )
+            .route(
+                "/groups/{id}/meeting-polls/{poll_id}/finalize",
+                post(
+                    async |a: AuthenticatedService<A>, Path((id, poll_id)), Json(r)| {
+                        a.finalize_meeting_poll(id, poll_id, r).await.map(Json)
+                    },
+                ),
+            )
+    }
+}
+
+impl<A> AuthenticatedService<A>
+where
+    A: crate::AuthenticatedRequirements,
+{
+    pub(crate) async fn list_meeting_polls(
+        &self,
+        group_id: uuid::Uuid,
+    ) -> Result<Vec<MeetingPollResponse>, crate::Error> {
+        let polls = self
+            .service
+            .list_meeting_polls(GroupId::new(group_id))
+            .await
+            .map_err(Into::into)?;
+        let polls: Vec<_> = polls.into_iter().map(MeetingPollResponse::from).collect();
+        Ok(polls)
+    }
+
+    pub(crate) async fn get_meeting_poll(
+        &self,
+        group_id: uuid::Uuid,
+        poll_id: uuid::Uuid,
+    ) -> Result<MeetingPollResponse, crate::Error> {
+        let poll = self
+            .service
+            .get_meeting_poll(GroupId::new(group_id), MeetingPollId::new(poll_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(poll.into())
+    }
+
+    pub(crate) async fn create_meeting_poll(
+        &self,
+        group_id: uuid::Uuid,
+        request: CreateMeetingPollRequest,
+    ) -> Result<MeetingPollResponse, crate::Error> {
+        let poll = self
+            .service
+            .create_meeting_poll(GroupId::new(group_id), request.into())
+            .await
+            .map_err(Into::into)?;
+        Ok(poll.into())
+    }
+
+    pub(crate) async fn vote_meeting_poll(
+        &self,
+        group_id: uuid::Uuid,
+        poll_id: uuid::Uuid,
+        votes: Vec<MeetingPollVoteRequest>,
+    ) -> Result<MeetingPollResponse, crate::Error> {
+        let votes: Vec<_> = votes.into_iter().map(CastMeetingPollVote::from).collect();
+        let poll = self
+            .service
+            .vote_meeting_poll(GroupId::new(group_id), MeetingPollId::new(poll_id), &votes)
+            .await
+            .map_err(Into::into)?;
+        Ok(poll.into())
+    }
+
+    pub(crate) async fn finalize_meeting_poll(
+        &self,
+        group_id: uuid::Uuid,
+        poll_id: uuid::Uuid,
+        request: FinalizeMeetingPollRequest,
+    ) -> Result<MeetingPollResponse, crate::Error> {
+        let poll = self
+            .service
+            .finalize_meeting_poll(
+                GroupId::new(group_id),
+                MeetingPollId::new(poll_id),
+                MeetingPollCandidateId::new(request.candidate_id),
+            )
+            .await
+            .map_err(Into::into)?;
+        Ok(poll.into())
+    }
+}
@@ -1,15 +1,29 @@
 use serde::{Deserialize, Serialize};
 
-use domain::{CreateUserParams, UpdateUserParams, User, UserId};
+use domain::{
+    Affiliation, CreateUserParams, GroupRole, MeetingAvailability, UpdateUserParams, User,
+    UserAnnouncementRead, UserDataExport, UserGroupMembership, UserId, UserMeetingPollVote,
+};
 
+use crate::announcement::AnnouncementResponse;
 use crate::authn::AuthenticatedService;
+use crate::group::{GroupInviteResponse, GroupJoinRequestResponse};
+use crate::group_activity::GroupActivityResponse;
+use crate::group_event::GroupEventResponse;
+use crate::meeting_poll::MeetingPollResponse;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct UserResponse {
     pub id: uuid::Uuid,
     pub name: String,
+    pub handle: Option<String>,
+    pub affiliation: Affiliation,
+    pub enrollment_year: Option<i32>,
+    pub bio: String,
+    pub avatar_url: Option<String>,
     pub created_at: domain::Timestamp,
     pub updated_at: domain::Timestamp,
+    pub deleted_at: Option<domain::Timestamp>,
 }
 
 impl From<User> for UserResponse {
@@ -17,14 +31,26 @@ impl From<User> for UserResponse {
         let User {
             id,
             name,
+            handle,
+            affiliation,
+            enrollment_year,
+            bio,
+            avatar_url,
             created_at,
             updated_at,
+            deleted_at,
         } = value;
         Self {
             id: id.into_inner(),
             name,
+            handle,
+            affiliation,
+            enrollment_year,
+            bio,
+            avatar_url,
             created_at,
             updated_at,
+            deleted_at,
         }
     }
 }
@@ -41,15 +67,169 @@ impl From<CreateUserRequest> for CreateUserParams {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+/// 省略したフィールドは変更されず, `null` を指定したフィールドは値が消えます。
+///
+/// アバターは `PUT /users/{id}/avatar` でアップロードした画像だけを設定できます。
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct UpdateUserRequest {
-    pub name: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub handle: Option<Option<String>>,
+    #[serde(default)]
+    pub affiliation: Option<Affiliation>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub enrollment_year: Option<Option<i32>>,
+    #[serde(default)]
+    pub bio: Option<String>,
+}
+
+/// 存在するフィールドを `Some` で包み, `null` と省略を区別します。
+pub(crate) fn deserialize_nullable<'de, D, T>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
 impl From<UpdateUserRequest> for UpdateUserParams {
     fn from(value: UpdateUserRequest) -> Self {
-        let UpdateUserRequest { name } = value;
-        Self { name }
+        let UpdateUserRequest {
+            name,
+            handle,
+            affiliation,
+            enrollment_year,
+            bio,
+        } = value;
+        Self {
+            name,
+            handle,
+            affiliation,
+            enrollment_year,
+            bio,
+            avatar_url: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UserGroupMembershipResponse {
+    pub group_id: uuid::Uuid,
+    pub group_name: String,
+    pub role: GroupRole,
+}
+
+impl From<UserGroupMembership> for UserGroupMembershipResponse {
+    fn from(value: UserGroupMembership) -> Self {
+        let UserGroupMembership {
+            group_id,
+            group_name,
+            role,
+        } = value;
+        Self {
+            group_id: group_id.into_inner(),
+            group_name,
+            role,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UserMeetingPollVoteResponse {
+    pub poll_id: uuid::Uuid,
+    pub candidate_id: uuid::Uuid,
+    pub availability: MeetingAvailability,
+    pub updated_at: domain::Timestamp,
+}
+
+impl From<UserMeetingPollVote> for UserMeetingPollVoteResponse {
+    fn from(value: UserMeetingPollVote) -> Self {
+        let UserMeetingPollVote {
+            poll_id,
+            candidate_id,
+            availability,
+            updated_at,
+        } = value;
+        Self {
+            poll_id: poll_id.into_inner(),
+            candidate_id: candidate_id.into_inner(),
+            availability,
+            updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UserAnnouncementReadResponse {
+    pub announcement_id: uuid::Uuid,
+    pub read_at: domain::Timestamp,
+}
+
+impl From<UserAnnouncementRead> for UserAnnouncementReadResponse {
+    fn from(value: UserAnnouncementRead) -> Self {
+        let UserAnnouncementRead {
+            announcement_id,
+            read_at,
+        } = value;
+        Self {
+            announcement_id: announcement_id.into_inner(),
+            read_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UserDataExportResponse {
+    pub exported_at: domain::Timestamp,
+    pub profile: UserResponse,
+    pub group_memberships: Vec<UserGroupMembershipResponse>,
+    pub group_join_requests: Vec<GroupJoinRequestResponse>,
+    pub decided_group_join_requests: Vec<GroupJoinRequestResponse>,
+    pub group_invites: Vec<GroupInviteResponse>,
+    pub meeting_poll_votes: Vec<UserMeetingPollVoteResponse>,
+    pub meeting_polls: Vec<MeetingPollResponse>,
+    pub group_events: Vec<GroupEventResponse>,
+    pub announcements: Vec<AnnouncementResponse>,
+    pub announcement_reads: Vec<UserAnnouncementReadResponse>,
+    pub group_activities: Vec<GroupActivityResponse>,
+}
+
+impl From<UserDataExport> for UserDataExportResponse {
+    fn from(value: UserDataExport) -> Self {
+        let UserDataExport {
+            exported_at,
+            profile,
+            group_memberships,
+            group_join_requests,
+            decided_group_join_requests,
+            group_invites,
+            meeting_poll_votes,
+            meeting_polls,
+            group_events,
+            announcements,
+            announcement_reads,
+            group_activities,
+        } = value;
+        Self {
+            exported_at,
+            profile: profile.into(),
+            group_memberships: group_memberships.into_iter().map(Into::into).collect(),
+            group_join_requests: group_join_requests.into_iter().map(Into::into).collect(),
+            decided_group_join_requests: decided_group_join_requests
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            group_invites: group_invites.into_iter().map(Into::into).collect(),
+            meeting_poll_votes: meeting_poll_votes.into_iter().map(Into::into).collect(),
+            meeting_polls: meeting_polls.into_iter().map(Into::into).collect(),
+            group_events: group_events.into_iter().map(Into::into).collect(),
+            announcements: announcements.into_iter().map(Into::into).collect(),
+            announcement_reads: announcement_reads.into_iter().map(Into::into).collect(),
+            group_activities: group_activities.into_iter().map(Into::into).collect(),
+        }
     }
 }
 
@@ -73,7 +253,7 @@ where
     pub(crate) fn user_router(&self) -> axum::Router<Self> {
         use axum::Json;
         use axum::extract::{Path, State};
-        use axum::routing::get;
+        use axum::routing::{get, post};
 
         axum::Router::new()
             .route(
@@ -86,8 +266,30 @@ where
                 get(async |a: AuthenticatedService<A>, Path(id)| a.get_user(id).await.map(Json))
                     .put(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
                         a.update_user(id, r).await.map(Json)
+                    })
+                    .patch(async |a: AuthenticatedService<A>, Path(id), Json(r)| {
+                        a.update_user(id, r).await.map(Json)
+                    })
+                    .delete(async |a: AuthenticatedService<A>, Path(id)| {
+                        a.delete_user(id).await.map(Json)
                     }),
             )
+            .route(
+                "/me/export",
+                get(async |a: AuthenticatedService<A>| {
+                    // ブラウザから開いたときにファイルとして保存されるようにする
+                    const DISPOSITION: &str = "attachment; filename=\"jikanwari-export.json\"";
+                    let export = a.export_user_data().await?;
+                    let headers = [(http::header::CONTENT_DISPOSITION, DISPOSITION)];
+                    Ok::<_, crate::Error>((headers, Json(export)))
+                }),
+            )
+            .route(
+                "/users/{id}/restore",
+                post(async |a: AuthenticatedService<A>, Path(id)| {
+                    a.restore_user(id).await.map(Json)
+                }),
+            )
     }
 }
 
@@ -122,4 +324,31 @@ where
             .map_err(Into::into)?;
         Ok(user.into())
     }
+    pub(crate) async fn delete_user(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> Result<UserResponse, crate::Error> {
+        let user = self
+            .service
+            .delete_user(UserId::new(user_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(user.into())
+    }
+
+    pub(crate) async fn restore_user(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> Result<UserResponse, crate::Error> {
+        let user = self
+            .service
+            .restore_user(UserId::new(user_id))
+            .await
+            .map_err(Into::into)?;
+        Ok(user.into())
+    }
+    pub(crate) async fn export_user_data(&self) -> Result<UserDataExportResponse, crate::Error> {
+        let export = self.service.export_user_data().await.map_err(Into::into)?;
+        Ok(export.into())
+    }
 }
@@ -0,0 +1,438 @@
+use domain::{
+    Announcement, AnnouncementId, AnnouncementService, CreateAnnouncementParams, GroupId,
+    UpdateAnnouncementParams, UserId,
+};
+
+use crate::rbac::ProvideAnnouncementAccessControl;
+
+// MARK: AnnouncementRepository
+
+pub trait AnnouncementRepository<Context, E: domain::Error>: Send + Sync {
+    /// ピン留めされたものを先に, それぞれ新しい順に返します。
+    fn list_announcements(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<Announcement>, E>> + Send;
+
+    /// お知らせが `group_id` のグループのものでなければ見つからない扱いにします。
+    fn get_announcement(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send;
+
+    fn create_announcement(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        author_id: UserId,
+        params: CreateAnnouncementParams,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send;
+
+    fn update_announcement(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+        params: UpdateAnnouncementParams,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send;
+
+    fn delete_announcement(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<(), E>> + Send;
+
+    fn mark_announcement_read(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send;
+}
+
+impl<R, C, E> AnnouncementRepository<C, E> for &R
+where
+    R: AnnouncementRepository<C, E>,
+    E: domain::Error,
+{
+    fn list_announcements(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<Announcement>, E>> + Send {
+        R::list_announcements(self, ctx, group_id)
+    }
+
+    fn get_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send {
+        R::get_announcement(self, ctx, group_id, announcement_id)
+    }
+
+    fn create_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        author_id: UserId,
+        params: CreateAnnouncementParams,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send {
+        R::create_announcement(self, ctx, group_id, author_id, params)
+    }
+
+    fn update_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+        params: UpdateAnnouncementParams,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send {
+        R::update_announcement(self, ctx, group_id, announcement_id, params)
+    }
+
+    fn delete_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<(), E>> + Send {
+        R::delete_announcement(self, ctx, group_id, announcement_id)
+    }
+
+    fn mark_announcement_read(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Announcement, E>> + Send {
+        R::mark_announcement_read(self, ctx, group_id, announcement_id, user_id)
+    }
+}
+
+pub trait ProvideAnnouncementRepository: Send + Sync {
+    type Context<'a>: Send + Sync
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type AnnouncementRepository<'a>: AnnouncementRepository<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn announcement_repository(&self) -> &Self::AnnouncementRepository<'_>;
+
+    fn list_announcements(
+        &self,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<Announcement>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_repository()
+            .list_announcements(ctx, group_id)
+    }
+
+    fn get_announcement(
+        &self,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<Announcement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_repository()
+            .get_announcement(ctx, group_id, announcement_id)
+    }
+
+    fn create_announcement(
+        &self,
+        group_id: GroupId,
+        author_id: UserId,
+        params: CreateAnnouncementParams,
+    ) -> impl Future<Output = Result<Announcement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_repository()
+            .create_announcement(ctx, group_id, author_id, params)
+    }
+
+    fn update_announcement(
+        &self,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+        params: UpdateAnnouncementParams,
+    ) -> impl Future<Output = Result<Announcement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_repository()
+            .update_announcement(ctx, group_id, announcement_id, params)
+    }
+
+    fn delete_announcement(
+        &self,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_repository()
+            .delete_announcement(ctx, group_id, announcement_id)
+    }
+
+    fn mark_announcement_read(
+        &self,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Announcement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_repository().mark_announcement_read(
+            ctx,
+            group_id,
+            announcement_id,
+            user_id,
+        )
+    }
+}
+
+/// タイトルと本文が空でないことを確かめます。
+fn validate_announcement_content<E: crate::Error>(title: &str, body: &str) -> Result<(), E> {
+    if title.trim().is_empty() {
+        return Err(E::bad_request("An announcement needs a title"));
+    }
+    if body.trim().is_empty() {
+        return Err(E::bad_request("An announcement needs a body"));
+    }
+    Ok(())
+}
+
+// MARK: impl for Service
+
+impl<C, E> AnnouncementService<C, E> for super::Service
+where
+    C: ProvideAnnouncementRepository<Error = E> + ProvideAnnouncementAccessControl<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn list_announcements(&self, ctx: C, group_id: GroupId) -> Result<Vec<Announcement>, E> {
+        ctx.judge_list_announcements(self.principal(), group_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for announcement listing");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはどのグループのメンバーでもない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, announcement_id = %announcement_id))]
+    async fn get_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> Result<Announcement, E> {
+        ctx.judge_get_announcement(self.principal(), group_id, announcement_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for announcement retrieval");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはどのグループのメンバーでもない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn create_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        params: CreateAnnouncementParams,
+    ) -> Result<Announcement, E> {
+        ctx.judge_create_announcement(self.principal(), group_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for announcement creation");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 投稿者を記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, announcement_id = %announcement_id))]
+    async fn update_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+        params: UpdateAnnouncementParams,
+    ) -> Result<Announcement, E> {
+        ctx.judge_update_announcement(self.principal(), group_id, announcement_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for announcement update");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーは投稿者にもオーナーにもなれない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, announcement_id = %announcement_id))]
+    async fn delete_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> Result<(), E> {
+        ctx.judge_delete_announcement(self.principal(), group_id, announcement_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for announcement deletion");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーは投稿者にもオーナーにもなれない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, announcement_id = %announcement_id))]
+    async fn mark_announcement_read(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> Result<Announcement, E> {
+        ctx.judge_mark_announcement_read(self.principal(), group_id, announcement_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for announcement read receipt");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 既読にしたユーザーを記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+}
+
+// MARK: impl for AuthenticatedService
+
+impl<C, E> AnnouncementService<C, E> for super::AuthenticatedService
+where
+    C: ProvideAnnouncementRepository<Error = E> + ProvideAnnouncementAccessControl<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn list_announcements(&self, ctx: C, group_id: GroupId) -> Result<Vec<Announcement>, E> {
+        ctx.judge_list_announcements(self.principal(), group_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for announcement listing");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.list_announcements(group_id).await.inspect(|a| {
+            tracing::debug!(group_id = %group_id, count = a.len(), "Listed announcements");
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, announcement_id = %announcement_id))]
+    async fn get_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> Result<Announcement, E> {
+        ctx.judge_get_announcement(self.principal(), group_id, announcement_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for announcement retrieval");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.get_announcement(group_id, announcement_id)
+            .await
+            .inspect(|a| {
+                tracing::debug!(announcement_id = %a.id, "Retrieved announcement");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn create_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        params: CreateAnnouncementParams,
+    ) -> Result<Announcement, E> {
+        ctx.judge_create_announcement(self.principal(), group_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for announcement creation");
+                E::forbidden("Access forbidden")
+            })?;
+        validate_announcement_content(&params.title, &params.body)?;
+        ctx.create_announcement(group_id, self.user_id, params)
+            .await
+            .inspect(|a| {
+                tracing::debug!(announcement_id = %a.id, pinned = a.pinned, "Created announcement");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, announcement_id = %announcement_id))]
+    async fn update_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+        params: UpdateAnnouncementParams,
+    ) -> Result<Announcement, E> {
+        ctx.judge_update_announcement(self.principal(), group_id, announcement_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for announcement update");
+                E::forbidden("Access forbidden")
+            })?;
+        validate_announcement_content(&params.title, &params.body)?;
+        ctx.update_announcement(group_id, announcement_id, params)
+            .await
+            .inspect(|a| {
+                tracing::debug!(announcement_id = %a.id, pinned = a.pinned, "Updated announcement");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, announcement_id = %announcement_id))]
+    async fn delete_announcement(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> Result<(), E> {
+        ctx.judge_delete_announcement(self.principal(), group_id, announcement_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for announcement deletion");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.delete_announcement(group_id, announcement_id)
+            .await
+            .inspect(|()| {
+                tracing::debug!(announcement_id = %announcement_id, "Deleted announcement");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, announcement_id = %announcement_id))]
+    async fn mark_announcement_read(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        announcement_id: AnnouncementId,
+    ) -> Result<Announcement, E> {
+        ctx.judge_mark_announcement_read(self.principal(), group_id, announcement_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for announcement read receipt");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.mark_announcement_read(group_id, announcement_id, self.user_id)
+            .await
+            .inspect(|a| {
+                tracing::debug!(announcement_id = %a.id, "Marked announcement as read");
+            })
+    }
+}
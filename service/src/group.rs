@@ -1,7 +1,10 @@
 use domain::{
-    CreateGroupParams, Group, GroupCore, GroupId, GroupService, UpdateGroupParams, UserId,
+    CreateGroupInviteParams, CreateGroupParams, Group, GroupCore, GroupId, GroupInvite,
+    GroupInviteCode, GroupJoinRequest, GroupJoinRequestId, GroupMember, GroupRole, GroupService,
+    ListGroupsFilter, UpdateGroupParams, UserId,
 };
 
+use crate::media::{ProvideBlobStore, ProvideMediaRepository, delete_unreferenced_media};
 use crate::rbac::ProvideGroupAccessControl;
 
 // MARK: GroupRepository
@@ -10,11 +13,24 @@ pub trait GroupRepository<Context, E: domain::Error>: Send + Sync {
     fn get_group(&self, ctx: Context, id: GroupId)
     -> impl Future<Output = Result<Group, E>> + Send;
 
-    fn list_groups(&self, ctx: Context) -> impl Future<Output = Result<Vec<GroupCore>, E>> + Send;
+    /// `user_id` が所属するグループを返します。
+    fn list_joined_groups(
+        &self,
+        ctx: Context,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Vec<GroupCore>, E>> + Send;
 
+    /// アーカイブされていない公開グループを返します。
+    fn list_public_groups(
+        &self,
+        ctx: Context,
+    ) -> impl Future<Output = Result<Vec<GroupCore>, E>> + Send;
+
+    /// `actor_id` は作成したユーザーです。
     fn create_group(
         &self,
         ctx: Context,
+        actor_id: UserId,
         params: CreateGroupParams,
     ) -> impl Future<Output = Result<Group, E>> + Send;
 
@@ -22,15 +38,110 @@ pub trait GroupRepository<Context, E: domain::Error>: Send + Sync {
         &self,
         ctx: Context,
         id: GroupId,
+        actor_id: UserId,
         params: UpdateGroupParams,
     ) -> impl Future<Output = Result<Group, E>> + Send;
 
+    /// アイコンの URL だけを書き換えます。`None` でアイコンを外します。
+    fn set_group_icon(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        actor_id: UserId,
+        icon_url: Option<String>,
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
     fn update_group_members(
         &self,
         ctx: Context,
         id: GroupId,
-        members: &[UserId],
+        actor_id: UserId,
+        members: &[GroupMember],
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    /// 既にメンバーであるユーザーはロールを変えずに無視します。
+    fn add_group_members(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        actor_id: UserId,
+        members: &[GroupMember],
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    /// オーナーが 1 人もいなくなる場合は, 何も変えずに conflict を返します。
+    fn remove_group_member(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        user_id: UserId,
+        actor_id: UserId,
     ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    /// `from` を管理者に, `to` をオーナーにします。どちらかがメンバーでなければ何もしません。
+    fn transfer_group_ownership(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        from: UserId,
+        to: UserId,
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    fn archive_group(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        actor_id: UserId,
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    fn delete_group(&self, ctx: Context, id: GroupId)
+    -> impl Future<Output = Result<(), E>> + Send;
+
+    fn create_group_invite(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        created_by: UserId,
+        params: CreateGroupInviteParams,
+    ) -> impl Future<Output = Result<GroupInvite, E>> + Send;
+
+    /// 招待コードを消費して `user_id` をメンバーとして追加します。
+    fn accept_group_invite(
+        &self,
+        ctx: Context,
+        code: &GroupInviteCode,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Group, E>> + Send;
+
+    fn list_group_join_requests(
+        &self,
+        ctx: Context,
+        id: GroupId,
+    ) -> impl Future<Output = Result<Vec<GroupJoinRequest>, E>> + Send;
+
+    /// 保留中のリクエストが既にあればそれを返します。
+    fn create_group_join_request(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, E>> + Send;
+
+    /// リクエストを承認済みにして申請者をメンバーとして追加します。
+    fn approve_group_join_request(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+        decided_by: UserId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, E>> + Send;
+
+    fn reject_group_join_request(
+        &self,
+        ctx: Context,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+        decided_by: UserId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, E>> + Send;
 }
 
 impl<R, C, E> GroupRepository<C, E> for &R
@@ -42,34 +153,154 @@ where
         R::get_group(self, ctx, id)
     }
 
-    fn list_groups(&self, ctx: C) -> impl Future<Output = Result<Vec<GroupCore>, E>> + Send {
-        R::list_groups(self, ctx)
+    fn list_joined_groups(
+        &self,
+        ctx: C,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Vec<GroupCore>, E>> + Send {
+        R::list_joined_groups(self, ctx, user_id)
+    }
+
+    fn list_public_groups(&self, ctx: C) -> impl Future<Output = Result<Vec<GroupCore>, E>> + Send {
+        R::list_public_groups(self, ctx)
     }
 
     fn create_group(
         &self,
         ctx: C,
+        actor_id: UserId,
         params: CreateGroupParams,
     ) -> impl Future<Output = Result<Group, E>> + Send {
-        R::create_group(self, ctx, params)
+        R::create_group(self, ctx, actor_id, params)
     }
 
     fn update_group(
         &self,
         ctx: C,
         id: GroupId,
+        actor_id: UserId,
         params: UpdateGroupParams,
     ) -> impl Future<Output = Result<Group, E>> + Send {
-        R::update_group(self, ctx, id, params)
+        R::update_group(self, ctx, id, actor_id, params)
+    }
+
+    fn set_group_icon(
+        &self,
+        ctx: C,
+        id: GroupId,
+        actor_id: UserId,
+        icon_url: Option<String>,
+    ) -> impl Future<Output = Result<Group, E>> + Send {
+        R::set_group_icon(self, ctx, id, actor_id, icon_url)
     }
 
     fn update_group_members(
         &self,
         ctx: C,
         id: GroupId,
-        members: &[UserId],
+        actor_id: UserId,
+        members: &[GroupMember],
+    ) -> impl Future<Output = Result<Group, E>> + Send {
+        R::update_group_members(self, ctx, id, actor_id, members)
+    }
+
+    fn add_group_members(
+        &self,
+        ctx: C,
+        id: GroupId,
+        actor_id: UserId,
+        members: &[GroupMember],
+    ) -> impl Future<Output = Result<Group, E>> + Send {
+        R::add_group_members(self, ctx, id, actor_id, members)
+    }
+
+    fn remove_group_member(
+        &self,
+        ctx: C,
+        id: GroupId,
+        user_id: UserId,
+        actor_id: UserId,
     ) -> impl Future<Output = Result<Group, E>> + Send {
-        R::update_group_members(self, ctx, id, members)
+        R::remove_group_member(self, ctx, id, user_id, actor_id)
+    }
+
+    fn transfer_group_ownership(
+        &self,
+        ctx: C,
+        id: GroupId,
+        from: UserId,
+        to: UserId,
+    ) -> impl Future<Output = Result<Group, E>> + Send {
+        R::transfer_group_ownership(self, ctx, id, from, to)
+    }
+
+    fn archive_group(
+        &self,
+        ctx: C,
+        id: GroupId,
+        actor_id: UserId,
+    ) -> impl Future<Output = Result<Group, E>> + Send {
+        R::archive_group(self, ctx, id, actor_id)
+    }
+
+    fn delete_group(&self, ctx: C, id: GroupId) -> impl Future<Output = Result<(), E>> + Send {
+        R::delete_group(self, ctx, id)
+    }
+
+    fn create_group_invite(
+        &self,
+        ctx: C,
+        id: GroupId,
+        created_by: UserId,
+        params: CreateGroupInviteParams,
+    ) -> impl Future<Output = Result<GroupInvite, E>> + Send {
+        R::create_group_invite(self, ctx, id, created_by, params)
+    }
+
+    fn accept_group_invite(
+        &self,
+        ctx: C,
+        code: &GroupInviteCode,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Group, E>> + Send {
+        R::accept_group_invite(self, ctx, code, user_id)
+    }
+
+    fn list_group_join_requests(
+        &self,
+        ctx: C,
+        id: GroupId,
+    ) -> impl Future<Output = Result<Vec<GroupJoinRequest>, E>> + Send {
+        R::list_group_join_requests(self, ctx, id)
+    }
+
+    fn create_group_join_request(
+        &self,
+        ctx: C,
+        id: GroupId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, E>> + Send {
+        R::create_group_join_request(self, ctx, id, user_id)
+    }
+
+    fn approve_group_join_request(
+        &self,
+        ctx: C,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+        decided_by: UserId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, E>> + Send {
+        R::approve_group_join_request(self, ctx, id, request_id, decided_by)
+    }
+
+    fn reject_group_join_request(
+        &self,
+        ctx: C,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+        decided_by: UserId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, E>> + Send {
+        R::reject_group_join_request(self, ctx, id, request_id, decided_by)
     }
 }
 
@@ -90,37 +321,197 @@ pub trait ProvideGroupRepository: Send + Sync {
         self.group_repository().get_group(ctx, id)
     }
 
-    fn list_groups(&self) -> impl Future<Output = Result<Vec<GroupCore>, Self::Error>> + Send {
+    fn list_joined_groups(
+        &self,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Vec<GroupCore>, Self::Error>> + Send {
         let ctx = self.context();
-        self.group_repository().list_groups(ctx)
+        self.group_repository().list_joined_groups(ctx, user_id)
+    }
+
+    fn list_public_groups(
+        &self,
+    ) -> impl Future<Output = Result<Vec<GroupCore>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository().list_public_groups(ctx)
     }
 
     fn create_group(
         &self,
+        actor_id: UserId,
         params: CreateGroupParams,
     ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
         let ctx = self.context();
-        self.group_repository().create_group(ctx, params)
+        self.group_repository().create_group(ctx, actor_id, params)
     }
 
     fn update_group(
         &self,
         id: GroupId,
+        actor_id: UserId,
         params: UpdateGroupParams,
     ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
         let ctx = self.context();
-        self.group_repository().update_group(ctx, id, params)
+        self.group_repository()
+            .update_group(ctx, id, actor_id, params)
+    }
+
+    fn set_group_icon(
+        &self,
+        id: GroupId,
+        actor_id: UserId,
+        icon_url: Option<String>,
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository()
+            .set_group_icon(ctx, id, actor_id, icon_url)
     }
 
     fn update_group_members(
         &self,
         id: GroupId,
-        members: &[UserId],
+        actor_id: UserId,
+        members: &[GroupMember],
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository()
+            .update_group_members(ctx, id, actor_id, members)
+    }
+
+    fn add_group_members(
+        &self,
+        id: GroupId,
+        actor_id: UserId,
+        members: &[GroupMember],
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository()
+            .add_group_members(ctx, id, actor_id, members)
+    }
+
+    fn remove_group_member(
+        &self,
+        id: GroupId,
+        user_id: UserId,
+        actor_id: UserId,
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository()
+            .remove_group_member(ctx, id, user_id, actor_id)
+    }
+
+    fn transfer_group_ownership(
+        &self,
+        id: GroupId,
+        from: UserId,
+        to: UserId,
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository()
+            .transfer_group_ownership(ctx, id, from, to)
+    }
+
+    fn archive_group(
+        &self,
+        id: GroupId,
+        actor_id: UserId,
+    ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository().archive_group(ctx, id, actor_id)
+    }
+
+    fn delete_group(&self, id: GroupId) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository().delete_group(ctx, id)
+    }
+
+    fn create_group_invite(
+        &self,
+        id: GroupId,
+        created_by: UserId,
+        params: CreateGroupInviteParams,
+    ) -> impl Future<Output = Result<GroupInvite, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository()
+            .create_group_invite(ctx, id, created_by, params)
+    }
+
+    fn accept_group_invite(
+        &self,
+        code: &GroupInviteCode,
+        user_id: UserId,
     ) -> impl Future<Output = Result<Group, Self::Error>> + Send {
         let ctx = self.context();
         self.group_repository()
-            .update_group_members(ctx, id, members)
+            .accept_group_invite(ctx, code, user_id)
+    }
+
+    fn list_group_join_requests(
+        &self,
+        id: GroupId,
+    ) -> impl Future<Output = Result<Vec<GroupJoinRequest>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository().list_group_join_requests(ctx, id)
+    }
+
+    fn create_group_join_request(
+        &self,
+        id: GroupId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository()
+            .create_group_join_request(ctx, id, user_id)
+    }
+
+    fn approve_group_join_request(
+        &self,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+        decided_by: UserId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository()
+            .approve_group_join_request(ctx, id, request_id, decided_by)
+    }
+
+    fn reject_group_join_request(
+        &self,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+        decided_by: UserId,
+    ) -> impl Future<Output = Result<GroupJoinRequest, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_repository()
+            .reject_group_join_request(ctx, id, request_id, decided_by)
+    }
+}
+
+/// 編集後のメンバーに少なくとも 1 人のオーナーが残ることを確かめます。
+pub(crate) fn ensure_owner_remains<'a, E: crate::Error>(
+    members: impl IntoIterator<Item = &'a GroupMember>,
+) -> Result<(), E> {
+    if members.into_iter().any(|m| m.role == GroupRole::Owner) {
+        return Ok(());
+    }
+    tracing::debug!("Rejected an operation that leaves a group without owners");
+    Err(E::conflict(
+        "A group must have at least one owner; transfer ownership or delete the group instead",
+    ))
+}
+
+/// グループのウェブサイトが http(s) の URL で, 長すぎないことを確かめます。
+fn validate_group_website_url<E: crate::Error>(url: &str) -> Result<(), E> {
+    const MAX_WEBSITE_URL_LEN: usize = 2048;
+
+    if url.len() > MAX_WEBSITE_URL_LEN {
+        return Err(E::bad_request("Website URL is too long"));
     }
+    let url = url::Url::parse(url).map_err(|_| E::bad_request("Invalid website URL"))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(E::bad_request("Website URL must use http or https"));
+    }
+    Ok(())
 }
 
 // MARK: impl for Service
@@ -143,15 +534,20 @@ where
         })
     }
 
-    #[tracing::instrument(skip_all)]
-    async fn list_groups(&self, ctx: C) -> Result<Vec<GroupCore>, E> {
-        ctx.judge_list_groups(self.principal())
+    #[tracing::instrument(skip_all, fields(filter = ?filter))]
+    async fn list_groups(&self, ctx: C, filter: ListGroupsFilter) -> Result<Vec<GroupCore>, E> {
+        ctx.judge_list_groups(self.principal(), filter)
             .await?
             .allow_or_else(|| {
-                tracing::debug!("Anonymous access denied for group listing");
+                tracing::debug!(filter = ?filter, "Anonymous access denied for group listing");
                 E::unauthenticated("Unauthenticated access")
             })?;
-        ctx.list_groups().await.inspect(|gs| {
+        let groups = match filter {
+            // 匿名ユーザーはどのグループのメンバーでもない
+            ListGroupsFilter::Joined => Ok(Vec::new()),
+            ListGroupsFilter::Public => ctx.list_public_groups().await,
+        };
+        groups.inspect(|gs| {
             tracing::debug!(count = gs.len(), "Listed groups");
         })
     }
@@ -164,9 +560,12 @@ where
                 tracing::debug!("Anonymous access denied for group creation");
                 E::unauthenticated("Unauthenticated access")
             })?;
-        ctx.create_group(params).await.inspect(|g| {
-            tracing::debug!(id = %g.id, members = g.members.len(), "Created group");
-        })
+        ensure_owner_remains(&params.members)?;
+        if let Some(url) = &params.website_url {
+            validate_group_website_url(url)?;
+        }
+        // 操作したユーザーを記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
     }
 
     #[tracing::instrument(skip_all, fields(id = %id))]
@@ -182,9 +581,11 @@ where
                 tracing::debug!(id = %id, "Anonymous access denied for group update");
                 E::unauthenticated("Unauthenticated access")
             })?;
-        ctx.update_group(id, params).await.inspect(|g| {
-            tracing::debug!(id = %g.id, "Updated group");
-        })
+        if let Some(Some(url)) = &params.website_url {
+            validate_group_website_url(url)?;
+        }
+        // 操作したユーザーを記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
     }
 
     #[tracing::instrument(skip_all, fields(id = %id))]
@@ -192,7 +593,7 @@ where
         &self,
         ctx: C,
         id: GroupId,
-        members: &[UserId],
+        members: &[GroupMember],
     ) -> Result<Group, E> {
         ctx.judge_update_group_members(self.principal(), id, members)
             .await?
@@ -200,17 +601,190 @@ where
                 tracing::debug!(id = %id, "Anonymous access denied for group members update");
                 E::unauthenticated("Unauthenticated access")
             })?;
-        ctx.update_group_members(id, members).await.inspect(|g| {
-            tracing::debug!(id = %g.id, members = g.members.len(), "Updated group members");
+        ensure_owner_remains(members)?;
+        // 操作したユーザーを記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn add_group_members(
+        &self,
+        ctx: C,
+        id: GroupId,
+        members: &[GroupMember],
+    ) -> Result<Group, E> {
+        ctx.judge_add_group_members(self.principal(), id, members)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for group members addition");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 操作したユーザーを記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id, user_id = %user_id))]
+    async fn remove_group_member(&self, ctx: C, id: GroupId, user_id: UserId) -> Result<Group, E> {
+        ctx.judge_remove_group_member(self.principal(), id, user_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for group member removal");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 操作したユーザーを記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn leave_group(&self, ctx: C, id: GroupId) -> Result<(), E> {
+        ctx.judge_leave_group(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for leaving group");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはどのグループのメンバーでもない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id, to = %to))]
+    async fn transfer_group_ownership(&self, ctx: C, id: GroupId, to: UserId) -> Result<Group, E> {
+        ctx.judge_transfer_group_ownership(self.principal(), id, to)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for group ownership transfer");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはオーナーになれない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn archive_group(&self, ctx: C, id: GroupId) -> Result<Group, E> {
+        ctx.judge_archive_group(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for group archival");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 操作したユーザーを記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn delete_group(&self, ctx: C, id: GroupId) -> Result<(), E> {
+        ctx.judge_delete_group(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for group deletion");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        ctx.delete_group(id).await.inspect(|()| {
+            tracing::debug!(id = %id, "Deleted group");
         })
     }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn create_group_invite(
+        &self,
+        ctx: C,
+        id: GroupId,
+        params: CreateGroupInviteParams,
+    ) -> Result<GroupInvite, E> {
+        ctx.judge_create_group_invite(self.principal(), id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for group invite creation");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 招待コードの発行者を記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn accept_group_invite(&self, ctx: C, code: &GroupInviteCode) -> Result<Group, E> {
+        ctx.judge_accept_group_invite(self.principal(), code)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!("Anonymous access denied for group invite acceptance");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはメンバーになれない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn list_group_join_requests(
+        &self,
+        ctx: C,
+        id: GroupId,
+    ) -> Result<Vec<GroupJoinRequest>, E> {
+        ctx.judge_list_group_join_requests(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for group join request listing");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        ctx.list_group_join_requests(id).await.inspect(|rs| {
+            tracing::debug!(id = %id, count = rs.len(), "Listed group join requests");
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn create_group_join_request(&self, ctx: C, id: GroupId) -> Result<GroupJoinRequest, E> {
+        ctx.judge_create_group_join_request(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for group join request creation");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはメンバーになれない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id, request_id = %request_id))]
+    async fn approve_group_join_request(
+        &self,
+        ctx: C,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+    ) -> Result<GroupJoinRequest, E> {
+        ctx.judge_decide_group_join_request(self.principal(), id, request_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for group join request approval");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 承認者を記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id, request_id = %request_id))]
+    async fn reject_group_join_request(
+        &self,
+        ctx: C,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+    ) -> Result<GroupJoinRequest, E> {
+        ctx.judge_decide_group_join_request(self.principal(), id, request_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for group join request rejection");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 却下者を記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
 }
 
 // MARK: impl for AuthenticatedService
 
 impl<C, E> GroupService<C, E> for super::AuthenticatedService
 where
-    C: ProvideGroupRepository<Error = E> + ProvideGroupAccessControl<Error = E>,
+    C: ProvideGroupRepository<Error = E>
+        + ProvideGroupAccessControl<Error = E>
+        + ProvideBlobStore<Error = E>
+        + ProvideMediaRepository<Error = E>,
     E: crate::Error,
 {
     #[tracing::instrument(skip_all, fields(id = %id))]
@@ -226,15 +800,19 @@ where
         })
     }
 
-    #[tracing::instrument(skip_all)]
-    async fn list_groups(&self, ctx: C) -> Result<Vec<GroupCore>, E> {
-        ctx.judge_list_groups(self.principal())
+    #[tracing::instrument(skip_all, fields(filter = ?filter))]
+    async fn list_groups(&self, ctx: C, filter: ListGroupsFilter) -> Result<Vec<GroupCore>, E> {
+        ctx.judge_list_groups(self.principal(), filter)
             .await?
             .allow_or_else(|| {
-                tracing::debug!("User access denied for group listing");
+                tracing::debug!(filter = ?filter, "User access denied for group listing");
                 E::forbidden("Access forbidden")
             })?;
-        ctx.list_groups().await.inspect(|gs| {
+        let groups = match filter {
+            ListGroupsFilter::Joined => ctx.list_joined_groups(self.user_id).await,
+            ListGroupsFilter::Public => ctx.list_public_groups().await,
+        };
+        groups.inspect(|gs| {
             tracing::debug!(count = gs.len(), "Listed groups");
         })
     }
@@ -247,7 +825,11 @@ where
                 tracing::debug!("User access denied for group creation");
                 E::forbidden("Access forbidden")
             })?;
-        ctx.create_group(params).await.inspect(|g| {
+        ensure_owner_remains(&params.members)?;
+        if let Some(url) = &params.website_url {
+            validate_group_website_url(url)?;
+        }
+        ctx.create_group(self.user_id, params).await.inspect(|g| {
             tracing::debug!(id = %g.id, members = g.members.len(), "Created group");
         })
     }
@@ -265,9 +847,14 @@ where
                 tracing::debug!(id = %id, "User access denied for group update");
                 E::forbidden("Access forbidden")
             })?;
-        ctx.update_group(id, params).await.inspect(|g| {
-            tracing::debug!(id = %g.id, "Updated group");
-        })
+        if let Some(Some(url)) = &params.website_url {
+            validate_group_website_url(url)?;
+        }
+        ctx.update_group(id, self.user_id, params)
+            .await
+            .inspect(|g| {
+                tracing::debug!(id = %g.id, "Updated group");
+            })
     }
 
     #[tracing::instrument(skip_all, fields(id = %id))]
@@ -275,7 +862,7 @@ where
         &self,
         ctx: C,
         id: GroupId,
-        members: &[UserId],
+        members: &[GroupMember],
     ) -> Result<Group, E> {
         ctx.judge_update_group_members(self.principal(), id, members)
             .await?
@@ -283,8 +870,215 @@ where
                 tracing::debug!(id = %id, "User access denied for group members update");
                 E::forbidden("Access forbidden")
             })?;
-        ctx.update_group_members(id, members).await.inspect(|g| {
-            tracing::debug!(id = %g.id, members = g.members.len(), "Updated group members");
+        ensure_owner_remains(members)?;
+        ctx.update_group_members(id, self.user_id, members)
+            .await
+            .inspect(|g| {
+                tracing::debug!(id = %g.id, members = g.members.len(), "Updated group members");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn add_group_members(
+        &self,
+        ctx: C,
+        id: GroupId,
+        members: &[GroupMember],
+    ) -> Result<Group, E> {
+        ctx.judge_add_group_members(self.principal(), id, members)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for group members addition");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.add_group_members(id, self.user_id, members)
+            .await
+            .inspect(|g| {
+                tracing::debug!(id = %g.id, members = g.members.len(), "Added group members");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id, user_id = %user_id))]
+    async fn remove_group_member(&self, ctx: C, id: GroupId, user_id: UserId) -> Result<Group, E> {
+        ctx.judge_remove_group_member(self.principal(), id, user_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for group member removal");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.remove_group_member(id, user_id, self.user_id)
+            .await
+            .inspect(|g| {
+                tracing::debug!(id = %g.id, members = g.members.len(), "Removed group member");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn leave_group(&self, ctx: C, id: GroupId) -> Result<(), E> {
+        ctx.judge_leave_group(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for leaving group");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.remove_group_member(id, self.user_id, self.user_id)
+            .await
+            .map(|_| ())
+            .inspect(|()| {
+                tracing::debug!(id = %id, "Left group");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id, to = %to))]
+    async fn transfer_group_ownership(&self, ctx: C, id: GroupId, to: UserId) -> Result<Group, E> {
+        ctx.judge_transfer_group_ownership(self.principal(), id, to)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for group ownership transfer");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.transfer_group_ownership(id, self.user_id, to)
+            .await
+            .inspect(|g| {
+                tracing::debug!(id = %g.id, "Transferred group ownership");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn archive_group(&self, ctx: C, id: GroupId) -> Result<Group, E> {
+        ctx.judge_archive_group(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for group archival");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.archive_group(id, self.user_id).await.inspect(|g| {
+            tracing::debug!(id = %g.id, "Archived group");
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn delete_group(&self, ctx: C, id: GroupId) -> Result<(), E> {
+        ctx.judge_delete_group(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for group deletion");
+                E::forbidden("Access forbidden")
+            })?;
+        let icon_url = ctx.get_group(id).await?.icon_url;
+        ctx.delete_group(id).await?;
+        tracing::debug!(id = %id, "Deleted group");
+        delete_unreferenced_media(&ctx, icon_url).await;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn create_group_invite(
+        &self,
+        ctx: C,
+        id: GroupId,
+        params: CreateGroupInviteParams,
+    ) -> Result<GroupInvite, E> {
+        ctx.judge_create_group_invite(self.principal(), id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for group invite creation");
+                E::forbidden("Access forbidden")
+            })?;
+        if params.expires_at <= chrono::Utc::now() {
+            return Err(E::bad_request("Invite expiry must be in the future"));
+        }
+        ctx.create_group_invite(id, self.user_id, params)
+            .await
+            .inspect(|i| {
+                tracing::debug!(id = %i.group_id, single_use = i.single_use, "Created group invite");
+            })
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn accept_group_invite(&self, ctx: C, code: &GroupInviteCode) -> Result<Group, E> {
+        ctx.judge_accept_group_invite(self.principal(), code)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!("User access denied for group invite acceptance");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.accept_group_invite(code, self.user_id)
+            .await
+            .inspect(|g| {
+                tracing::debug!(id = %g.id, members = g.members.len(), "Accepted group invite");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn list_group_join_requests(
+        &self,
+        ctx: C,
+        id: GroupId,
+    ) -> Result<Vec<GroupJoinRequest>, E> {
+        ctx.judge_list_group_join_requests(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for group join request listing");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.list_group_join_requests(id).await.inspect(|rs| {
+            tracing::debug!(id = %id, count = rs.len(), "Listed group join requests");
         })
     }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn create_group_join_request(&self, ctx: C, id: GroupId) -> Result<GroupJoinRequest, E> {
+        ctx.judge_create_group_join_request(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for group join request creation");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.create_group_join_request(id, self.user_id)
+            .await
+            .inspect(|r| {
+                tracing::debug!(id = %r.group_id, request_id = %r.id, "Created group join request");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id, request_id = %request_id))]
+    async fn approve_group_join_request(
+        &self,
+        ctx: C,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+    ) -> Result<GroupJoinRequest, E> {
+        ctx.judge_decide_group_join_request(self.principal(), id, request_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for group join request approval");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.approve_group_join_request(id, request_id, self.user_id)
+            .await
+            .inspect(|r| {
+                tracing::debug!(id = %r.group_id, request_id = %r.id, "Approved group join request");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id, request_id = %request_id))]
+    async fn reject_group_join_request(
+        &self,
+        ctx: C,
+        id: GroupId,
+        request_id: GroupJoinRequestId,
+    ) -> Result<GroupJoinRequest, E> {
+        ctx.judge_decide_group_join_request(self.principal(), id, request_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for group join request rejection");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.reject_group_join_request(id, request_id, self.user_id)
+            .await
+            .inspect(|r| {
+                tracing::debug!(id = %r.group_id, request_id = %r.id, "Rejected group join request");
+            })
+    }
 }
@@ -0,0 +1,127 @@
+use domain::{
+    GroupActivity, GroupActivityId, GroupActivityPage, GroupActivityService, GroupId,
+    ListGroupActivitiesParams,
+};
+
+use crate::rbac::ProvideGroupActivityAccessControl;
+
+// MARK: GroupActivityRepository
+
+/// アクティビティはグループを変更する各リポジトリメソッドが, 変更と同じトランザクションで記録します。
+pub trait GroupActivityRepository<Context, E: domain::Error>: Send + Sync {
+    /// `cursor` より古いものを新しい順に最大 `limit` 件返します。
+    fn list_group_activities(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        cursor: Option<GroupActivityId>,
+        limit: u32,
+    ) -> impl Future<Output = Result<Vec<GroupActivity>, E>> + Send;
+}
+
+impl<R, C, E> GroupActivityRepository<C, E> for &R
+where
+    R: GroupActivityRepository<C, E>,
+    E: domain::Error,
+{
+    fn list_group_activities(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        cursor: Option<GroupActivityId>,
+        limit: u32,
+    ) -> impl Future<Output = Result<Vec<GroupActivity>, E>> + Send {
+        R::list_group_activities(self, ctx, group_id, cursor, limit)
+    }
+}
+
+pub trait ProvideGroupActivityRepository: Send + Sync {
+    type Context<'a>: Send + Sync
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type GroupActivityRepository<'a>: GroupActivityRepository<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn group_activity_repository(&self) -> &Self::GroupActivityRepository<'_>;
+
+    fn list_group_activities(
+        &self,
+        group_id: GroupId,
+        cursor: Option<GroupActivityId>,
+        limit: u32,
+    ) -> impl Future<Output = Result<Vec<GroupActivity>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_activity_repository()
+            .list_group_activities(ctx, group_id, cursor, limit)
+    }
+}
+
+// MARK: impl for Service
+
+impl<C, E> GroupActivityService<C, E> for super::Service
+where
+    C: ProvideGroupActivityRepository<Error = E> + ProvideGroupActivityAccessControl<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn list_group_activities(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        _params: ListGroupActivitiesParams,
+    ) -> Result<GroupActivityPage, E> {
+        ctx.judge_list_group_activities(self.principal(), group_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for group activity listing");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはどのグループのメンバーでもない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+}
+
+// MARK: impl for AuthenticatedService
+
+impl<C, E> GroupActivityService<C, E> for super::AuthenticatedService
+where
+    C: ProvideGroupActivityRepository<Error = E> + ProvideGroupActivityAccessControl<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn list_group_activities(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        params: ListGroupActivitiesParams,
+    ) -> Result<GroupActivityPage, E> {
+        ctx.judge_list_group_activities(self.principal(), group_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for group activity listing");
+                E::forbidden("Access forbidden")
+            })?;
+        let ListGroupActivitiesParams { cursor, limit } = params;
+        if !(1..=ListGroupActivitiesParams::MAX_LIMIT).contains(&limit) {
+            return Err(E::bad_request("Limit is out of range"));
+        }
+        // 1 件多く取って続きがあるかを判定する
+        let mut activities = ctx
+            .list_group_activities(group_id, cursor, limit + 1)
+            .await?;
+        let next_cursor = if activities.len() > limit as usize {
+            activities.truncate(limit as usize);
+            activities.last().map(|a| a.id)
+        } else {
+            None
+        };
+        tracing::debug!(group_id = %group_id, count = activities.len(), "Listed group activities");
+        Ok(GroupActivityPage {
+            activities,
+            next_cursor,
+        })
+    }
+}
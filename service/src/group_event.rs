@@ -0,0 +1,392 @@
+use domain::{
+    CreateGroupEventParams, GroupEvent, GroupEventId, GroupEventRecurrence, GroupEventService,
+    GroupId, Timestamp, UpdateGroupEventParams, UserId,
+};
+
+use crate::rbac::ProvideGroupEventAccessControl;
+
+// MARK: GroupEventRepository
+
+pub trait GroupEventRepository<Context, E: domain::Error>: Send + Sync {
+    /// 開始時刻順に返します。
+    fn list_group_events(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<GroupEvent>, E>> + Send;
+
+    /// イベントが `group_id` のグループのものでなければ見つからない扱いにします。
+    fn get_group_event(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> impl Future<Output = Result<GroupEvent, E>> + Send;
+
+    fn create_group_event(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        created_by: UserId,
+        params: CreateGroupEventParams,
+    ) -> impl Future<Output = Result<GroupEvent, E>> + Send;
+
+    fn update_group_event(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        event_id: GroupEventId,
+        actor_id: UserId,
+        params: UpdateGroupEventParams,
+    ) -> impl Future<Output = Result<GroupEvent, E>> + Send;
+
+    fn delete_group_event(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        event_id: GroupEventId,
+        actor_id: UserId,
+    ) -> impl Future<Output = Result<(), E>> + Send;
+}
+
+impl<R, C, E> GroupEventRepository<C, E> for &R
+where
+    R: GroupEventRepository<C, E>,
+    E: domain::Error,
+{
+    fn list_group_events(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<GroupEvent>, E>> + Send {
+        R::list_group_events(self, ctx, group_id)
+    }
+
+    fn get_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> impl Future<Output = Result<GroupEvent, E>> + Send {
+        R::get_group_event(self, ctx, group_id, event_id)
+    }
+
+    fn create_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        created_by: UserId,
+        params: CreateGroupEventParams,
+    ) -> impl Future<Output = Result<GroupEvent, E>> + Send {
+        R::create_group_event(self, ctx, group_id, created_by, params)
+    }
+
+    fn update_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        event_id: GroupEventId,
+        actor_id: UserId,
+        params: UpdateGroupEventParams,
+    ) -> impl Future<Output = Result<GroupEvent, E>> + Send {
+        R::update_group_event(self, ctx, group_id, event_id, actor_id, params)
+    }
+
+    fn delete_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        event_id: GroupEventId,
+        actor_id: UserId,
+    ) -> impl Future<Output = Result<(), E>> + Send {
+        R::delete_group_event(self, ctx, group_id, event_id, actor_id)
+    }
+}
+
+pub trait ProvideGroupEventRepository: Send + Sync {
+    type Context<'a>: Send + Sync
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type GroupEventRepository<'a>: GroupEventRepository<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn group_event_repository(&self) -> &Self::GroupEventRepository<'_>;
+
+    fn list_group_events(
+        &self,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<GroupEvent>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_repository()
+            .list_group_events(ctx, group_id)
+    }
+
+    fn get_group_event(
+        &self,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> impl Future<Output = Result<GroupEvent, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_repository()
+            .get_group_event(ctx, group_id, event_id)
+    }
+
+    fn create_group_event(
+        &self,
+        group_id: GroupId,
+        created_by: UserId,
+        params: CreateGroupEventParams,
+    ) -> impl Future<Output = Result<GroupEvent, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_repository()
+            .create_group_event(ctx, group_id, created_by, params)
+    }
+
+    fn update_group_event(
+        &self,
+        group_id: GroupId,
+        event_id: GroupEventId,
+        actor_id: UserId,
+        params: UpdateGroupEventParams,
+    ) -> impl Future<Output = Result<GroupEvent, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_repository()
+            .update_group_event(ctx, group_id, event_id, actor_id, params)
+    }
+
+    fn delete_group_event(
+        &self,
+        group_id: GroupId,
+        event_id: GroupEventId,
+        actor_id: UserId,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_repository()
+            .delete_group_event(ctx, group_id, event_id, actor_id)
+    }
+}
+
+/// 開始時刻が終了時刻より前で, 繰り返しの間隔が正, 繰り返しの終わりが初回より後であることを確かめます。
+fn validate_group_event_schedule<E: crate::Error>(
+    starts_at: Timestamp,
+    ends_at: Timestamp,
+    recurrence: Option<&GroupEventRecurrence>,
+) -> Result<(), E> {
+    if starts_at >= ends_at {
+        return Err(E::bad_request("An event must start before it ends"));
+    }
+    let Some(recurrence) = recurrence else {
+        return Ok(());
+    };
+    if recurrence.interval < 1 {
+        return Err(E::bad_request("Recurrence interval must be positive"));
+    }
+    if recurrence.until.is_some_and(|until| until < starts_at) {
+        return Err(E::bad_request(
+            "Recurrence must not end before the first occurrence",
+        ));
+    }
+    Ok(())
+}
+
+// MARK: impl for Service
+
+impl<C, E> GroupEventService<C, E> for super::Service
+where
+    C: ProvideGroupEventRepository<Error = E> + ProvideGroupEventAccessControl<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn list_group_events(&self, ctx: C, group_id: GroupId) -> Result<Vec<GroupEvent>, E> {
+        ctx.judge_list_group_events(self.principal(), group_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for group event listing");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはどのグループのメンバーでもない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, event_id = %event_id))]
+    async fn get_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> Result<GroupEvent, E> {
+        ctx.judge_get_group_event(self.principal(), group_id, event_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for group event retrieval");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはどのグループのメンバーでもない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn create_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        params: CreateGroupEventParams,
+    ) -> Result<GroupEvent, E> {
+        ctx.judge_create_group_event(self.principal(), group_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for group event creation");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 作成者を記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, event_id = %event_id))]
+    async fn update_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        event_id: GroupEventId,
+        params: UpdateGroupEventParams,
+    ) -> Result<GroupEvent, E> {
+        ctx.judge_update_group_event(self.principal(), group_id, event_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for group event update");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはオーナーにも管理者にもなれない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, event_id = %event_id))]
+    async fn delete_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> Result<(), E> {
+        ctx.judge_delete_group_event(self.principal(), group_id, event_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for group event deletion");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはオーナーにも管理者にもなれない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+}
+
+// MARK: impl for AuthenticatedService
+
+impl<C, E> GroupEventService<C, E> for super::AuthenticatedService
+where
+    C: ProvideGroupEventRepository<Error = E> + ProvideGroupEventAccessControl<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn list_group_events(&self, ctx: C, group_id: GroupId) -> Result<Vec<GroupEvent>, E> {
+        ctx.judge_list_group_events(self.principal(), group_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for group event listing");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.list_group_events(group_id).await.inspect(|es| {
+            tracing::debug!(group_id = %group_id, count = es.len(), "Listed group events");
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, event_id = %event_id))]
+    async fn get_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> Result<GroupEvent, E> {
+        ctx.judge_get_group_event(self.principal(), group_id, event_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for group event retrieval");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.get_group_event(group_id, event_id).await.inspect(|e| {
+            tracing::debug!(event_id = %e.id, "Retrieved group event");
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn create_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        params: CreateGroupEventParams,
+    ) -> Result<GroupEvent, E> {
+        ctx.judge_create_group_event(self.principal(), group_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for group event creation");
+                E::forbidden("Access forbidden")
+            })?;
+        validate_group_event_schedule(
+            params.starts_at,
+            params.ends_at,
+            params.recurrence.as_ref(),
+        )?;
+        ctx.create_group_event(group_id, self.user_id, params)
+            .await
+            .inspect(|e| {
+                tracing::debug!(event_id = %e.id, "Created group event");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, event_id = %event_id))]
+    async fn update_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        event_id: GroupEventId,
+        params: UpdateGroupEventParams,
+    ) -> Result<GroupEvent, E> {
+        ctx.judge_update_group_event(self.principal(), group_id, event_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for group event update");
+                E::forbidden("Access forbidden")
+            })?;
+        validate_group_event_schedule(
+            params.starts_at,
+            params.ends_at,
+            params.recurrence.as_ref(),
+        )?;
+        ctx.update_group_event(group_id, event_id, self.user_id, params)
+            .await
+            .inspect(|e| {
+                tracing::debug!(event_id = %e.id, "Updated group event");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, event_id = %event_id))]
+    async fn delete_group_event(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        event_id: GroupEventId,
+    ) -> Result<(), E> {
+        ctx.judge_delete_group_event(self.principal(), group_id, event_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for group event deletion");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.delete_group_event(group_id, event_id, self.user_id)
+            .await
+            .inspect(|()| {
+                tracing::debug!(event_id = %event_id, "Deleted group event");
+            })
+    }
+}
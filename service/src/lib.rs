@@ -1,10 +1,20 @@
+mod announcement;
 mod group;
+mod group_activity;
+mod group_event;
+mod media;
+mod meeting_poll;
 mod rbac;
 mod user;
 
 pub trait Error: domain::Error {
+    fn not_found(message: &str) -> Self;
     fn unauthenticated(message: &str) -> Self;
     fn forbidden(message: &str) -> Self;
+    /// グループのオーナーがいなくなるなど, 状態の整合性を壊す操作に対するエラー
+    fn conflict(message: &str) -> Self;
+    /// 時間帯の前後が逆転しているなど, 入力そのものが不正な場合のエラー
+    fn bad_request(message: &str) -> Self;
 }
 
 #[must_use]
@@ -32,20 +42,31 @@ pub trait MakeAuthenticated<E>: Send + Sync {
 pub struct AuthenticatedService {
     service: Service,
     user_id: domain::UserId,
+    /// 削除予定のアカウントか
+    pending_deletion: bool,
 }
 
 impl Service {
-    pub fn authenticated(&self, user_id: domain::UserId) -> AuthenticatedService {
+    pub fn authenticated(&self, user: &domain::User) -> AuthenticatedService {
         AuthenticatedService {
             service: *self,
-            user_id,
+            user_id: user.id,
+            pending_deletion: user.deleted_at.is_some(),
         }
     }
 }
 
+pub use announcement::{AnnouncementRepository, ProvideAnnouncementRepository};
 pub use group::{GroupRepository, ProvideGroupRepository};
+pub use group_activity::{GroupActivityRepository, ProvideGroupActivityRepository};
+pub use group_event::{GroupEventRepository, ProvideGroupEventRepository};
+pub use media::{BlobStore, MediaRepository, ProvideBlobStore, ProvideMediaRepository};
+pub use meeting_poll::{MeetingPollRepository, ProvideMeetingPollRepository};
 pub use rbac::{
-    GroupAccessControl, Judgement, Principal, ProvideGroupAccessControl, ProvideUserAccessControl,
-    UserAccessControl,
+    AnnouncementAccessControl, GroupAccessControl, GroupActivityAccessControl,
+    GroupEventAccessControl, Judgement, MediaAccessControl, MeetingPollAccessControl, Principal,
+    ProvideAnnouncementAccessControl, ProvideGroupAccessControl, ProvideGroupActivityAccessControl,
+    ProvideGroupEventAccessControl, ProvideMediaAccessControl, ProvideMeetingPollAccessControl,
+    ProvideUserAccessControl, UserAccessControl,
 };
 pub use user::{ProvideUserRepository, UserRepository};
@@ -0,0 +1,565 @@
+use std::io::Cursor;
+
+use domain::{
+    Group, GroupId, Media, MediaHash, MediaService, UpdateGroupParams, UpdateUserParams, User,
+    UserId,
+};
+use sha2::{Digest, Sha256};
+
+use crate::group::ProvideGroupRepository;
+use crate::rbac::{ProvideGroupAccessControl, ProvideMediaAccessControl, ProvideUserAccessControl};
+use crate::user::ProvideUserRepository;
+
+// MARK: BlobStore
+
+/// 内容のハッシュをキーとしてバイト列を保存する先
+///
+/// 同じキーには常に同じ内容が対応するので, `put_blob` は何度呼んでも結果が変わりません。
+pub trait BlobStore<Context, E: domain::Error>: Send + Sync {
+    fn put_blob(
+        &self,
+        ctx: Context,
+        key: &MediaHash,
+        data: &[u8],
+    ) -> impl Future<Output = Result<(), E>> + Send;
+
+    fn get_blob(
+        &self,
+        ctx: Context,
+        key: &MediaHash,
+    ) -> impl Future<Output = Result<Vec<u8>, E>> + Send;
+
+    /// キーの blob を削除します。存在しなければ何もしません。
+    fn delete_blob(
+        &self,
+        ctx: Context,
+        key: &MediaHash,
+    ) -> impl Future<Output = Result<(), E>> + Send;
+}
+
+impl<R, C, E> BlobStore<C, E> for &R
+where
+    R: BlobStore<C, E>,
+    E: domain::Error,
+{
+    fn put_blob(
+        &self,
+        ctx: C,
+        key: &MediaHash,
+        data: &[u8],
+    ) -> impl Future<Output = Result<(), E>> + Send {
+        R::put_blob(self, ctx, key, data)
+    }
+
+    fn get_blob(&self, ctx: C, key: &MediaHash) -> impl Future<Output = Result<Vec<u8>, E>> + Send {
+        R::get_blob(self, ctx, key)
+    }
+
+    fn delete_blob(&self, ctx: C, key: &MediaHash) -> impl Future<Output = Result<(), E>> + Send {
+        R::delete_blob(self, ctx, key)
+    }
+}
+
+pub trait ProvideBlobStore: Send + Sync {
+    type Context<'a>: Send + Sync
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type BlobStore<'a>: BlobStore<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn blob_store(&self) -> &Self::BlobStore<'_>;
+
+    fn put_blob(
+        &self,
+        key: &MediaHash,
+        data: &[u8],
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let ctx = self.context();
+        self.blob_store().put_blob(ctx, key, data)
+    }
+
+    fn get_blob(
+        &self,
+        key: &MediaHash,
+    ) -> impl Future<Output = Result<Vec<u8>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.blob_store().get_blob(ctx, key)
+    }
+
+    fn delete_blob(&self, key: &MediaHash) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let ctx = self.context();
+        self.blob_store().delete_blob(ctx, key)
+    }
+}
+
+impl<R> ProvideBlobStore for &R
+where
+    R: ProvideBlobStore,
+{
+    type Context<'a>
+        = R::Context<'a>
+    where
+        Self: 'a;
+    type Error = R::Error;
+    type BlobStore<'a>
+        = R::BlobStore<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        R::context(self)
+    }
+
+    fn blob_store(&self) -> &Self::BlobStore<'_> {
+        R::blob_store(self)
+    }
+}
+
+// MARK: MediaRepository
+
+/// 画像の参照数はアバターやアイコンの URL を書き換えるトランザクションの中で増減します。
+pub trait MediaRepository<Context, E: domain::Error>: Send + Sync {
+    /// 画像の参照数が 0 なら, その行をロックしたまま `blob_store` から削除してから行を消します。
+    ///
+    /// ロックの間は同じ画像の参照を増やす更新が待たされます。参照が残っていれば
+    /// 何もせずに `false` を返します。
+    fn delete_unreferenced_media<B>(
+        &self,
+        ctx: Context,
+        hash: &MediaHash,
+        blob_store: &B,
+    ) -> impl Future<Output = Result<bool, E>> + Send
+    where
+        B: ProvideBlobStore<Error = E>;
+
+    /// 参照数が 0 のまま残っている画像のハッシュを返します。
+    fn list_unreferenced_media(
+        &self,
+        ctx: Context,
+    ) -> impl Future<Output = Result<Vec<MediaHash>, E>> + Send;
+}
+
+impl<R, C, E> MediaRepository<C, E> for &R
+where
+    R: MediaRepository<C, E>,
+    E: domain::Error,
+{
+    fn delete_unreferenced_media<B>(
+        &self,
+        ctx: C,
+        hash: &MediaHash,
+        blob_store: &B,
+    ) -> impl Future<Output = Result<bool, E>> + Send
+    where
+        B: ProvideBlobStore<Error = E>,
+    {
+        R::delete_unreferenced_media(self, ctx, hash, blob_store)
+    }
+
+    fn list_unreferenced_media(
+        &self,
+        ctx: C,
+    ) -> impl Future<Output = Result<Vec<MediaHash>, E>> + Send {
+        R::list_unreferenced_media(self, ctx)
+    }
+}
+
+pub trait ProvideMediaRepository: Send + Sync {
+    type Context<'a>: Send + Sync
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type MediaRepository<'a>: MediaRepository<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn media_repository(&self) -> &Self::MediaRepository<'_>;
+
+    fn delete_unreferenced_media<B>(
+        &self,
+        hash: &MediaHash,
+        blob_store: &B,
+    ) -> impl Future<Output = Result<bool, Self::Error>> + Send
+    where
+        B: ProvideBlobStore<Error = Self::Error>,
+    {
+        let ctx = self.context();
+        self.media_repository()
+            .delete_unreferenced_media(ctx, hash, blob_store)
+    }
+
+    fn list_unreferenced_media(
+        &self,
+    ) -> impl Future<Output = Result<Vec<MediaHash>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.media_repository().list_unreferenced_media(ctx)
+    }
+}
+
+impl<R> ProvideMediaRepository for &R
+where
+    R: ProvideMediaRepository,
+{
+    type Context<'a>
+        = R::Context<'a>
+    where
+        Self: 'a;
+    type Error = R::Error;
+    type MediaRepository<'a>
+        = R::MediaRepository<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        R::context(self)
+    }
+
+    fn media_repository(&self) -> &Self::MediaRepository<'_> {
+        R::media_repository(self)
+    }
+}
+
+// MARK: 使われなくなった画像の削除
+
+/// アバターやアイコンから外れた `urls` の画像のうち, どこからも参照されていないものを削除します。
+///
+/// 保存した画像を指さない URL は無視します。削除に失敗しても呼び出し元の操作は
+/// 終わっているので, 警告を残すだけにします。消し損ねた画像は
+/// [`sweep_unreferenced_media`] が後で削除します。
+pub(crate) async fn delete_unreferenced_media<C, E>(ctx: &C, urls: impl IntoIterator<Item = String>)
+where
+    C: ProvideBlobStore<Error = E> + ProvideMediaRepository<Error = E>,
+    E: crate::Error,
+{
+    for hash in urls
+        .into_iter()
+        .filter_map(|url| MediaHash::from_url_path(&url))
+    {
+        delete_media_if_unreferenced(ctx, &hash).await;
+    }
+}
+
+/// 参照数が 0 のまま残っている画像をすべて削除します。
+pub(crate) async fn sweep_unreferenced_media<C, E>(ctx: &C)
+where
+    C: ProvideBlobStore<Error = E> + ProvideMediaRepository<Error = E>,
+    E: crate::Error,
+{
+    let hashes = match ctx.list_unreferenced_media().await {
+        Ok(hashes) => hashes,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to list unreferenced media");
+            return;
+        }
+    };
+    for hash in &hashes {
+        delete_media_if_unreferenced(ctx, hash).await;
+    }
+}
+
+async fn delete_media_if_unreferenced<C, E>(ctx: &C, hash: &MediaHash)
+where
+    C: ProvideBlobStore<Error = E> + ProvideMediaRepository<Error = E>,
+    E: crate::Error,
+{
+    let result = ctx.delete_unreferenced_media(hash, ctx).await;
+    match result {
+        Ok(true) => tracing::debug!(hash = %hash, "Deleted unreferenced media"),
+        Ok(false) => {}
+        Err(e) => tracing::warn!(error = %e, hash = %hash, "Failed to delete unreferenced media"),
+    }
+}
+
+// MARK: 画像の正規化
+
+/// 保存する画像の形式
+const MEDIA_CONTENT_TYPE: &str = "image/png";
+/// アバター画像の一辺の長さ (px)
+const AVATAR_SIZE: u32 = 256;
+/// グループアイコンの一辺の長さ (px)
+const GROUP_ICON_SIZE: u32 = 512;
+/// デコードを受け付ける画像の一辺の最大長 (px)
+const MAX_IMAGE_DIMENSION: u32 = 4096;
+/// デコードに使うメモリの上限 (バイト)
+const MAX_DECODE_ALLOC: u64 = 128 * 1024 * 1024;
+/// 同時に変換する画像の数
+const MAX_CONCURRENT_NORMALIZATIONS: usize = 2;
+
+/// 変換は CPU とメモリを多く使うので, 同時に動く数を絞る
+static NORMALIZATION_PERMITS: tokio::sync::Semaphore =
+    tokio::sync::Semaphore::const_new(MAX_CONCURRENT_NORMALIZATIONS);
+
+/// [`normalize_image`] を非同期ランタイムのワーカーを塞がないよう別スレッドで実行します。
+async fn normalize_image_in_background<E: crate::Error>(
+    data: Vec<u8>,
+    size: u32,
+) -> Result<Media, E> {
+    // セマフォは閉じないので, 取得は失敗しない
+    let _permit = NORMALIZATION_PERMITS.acquire().await;
+    tokio::task::spawn_blocking(move || normalize_image(&data, size))
+        .await
+        .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+}
+
+/// アップロードされた画像を検証し, `size` px 四方の PNG に変換します。
+///
+/// 一辺が [`MAX_IMAGE_DIMENSION`] px を超える画像は受け付けません。変換後の大きさは
+/// アバターやアイコンといった用途ごとに 1 つだけです。EXIF などのメタデータは
+/// 再エンコードで取り除かれます。
+fn normalize_image<E: crate::Error>(data: &[u8], size: u32) -> Result<Media, E> {
+    use image::{ImageFormat, ImageReader, imageops::FilterType};
+
+    if data.len() > Media::MAX_UPLOAD_SIZE {
+        return Err(E::bad_request("Image is too large"));
+    }
+    // 拡張子や Content-Type ではなく, 中身から形式を判定する
+    let format = image::guess_format(data).map_err(|_| E::bad_request("Unsupported image type"))?;
+    if !matches!(
+        format,
+        ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP | ImageFormat::Gif
+    ) {
+        return Err(E::bad_request("Unsupported image type"));
+    }
+    let mut limits = image::Limits::default();
+    limits.max_image_width = Some(MAX_IMAGE_DIMENSION);
+    limits.max_image_height = Some(MAX_IMAGE_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_ALLOC);
+    let mut reader = ImageReader::with_format(Cursor::new(data), format);
+    reader.limits(limits);
+    let image = reader.decode().map_err(|e| {
+        tracing::debug!(error = %e, "Failed to decode image");
+        match e {
+            image::ImageError::Limits(_) => E::bad_request(&format!(
+                "Image must be at most {MAX_IMAGE_DIMENSION}px on a side"
+            )),
+            _ => E::bad_request("Invalid image"),
+        }
+    })?;
+    let image = image.resize_to_fill(size, size, FilterType::Lanczos3);
+    let mut encoded = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut encoded), ImageFormat::Png)
+        .map_err(|e| {
+            tracing::error!(error = %e, "Failed to encode image");
+            E::bad_request("Invalid image")
+        })?;
+    let hash = MediaHash::new(format!("{:x}", Sha256::digest(&encoded)));
+    Ok(Media {
+        hash,
+        content_type: MEDIA_CONTENT_TYPE.to_string(),
+        data: encoded,
+    })
+}
+
+// MARK: impl for Service
+
+impl<C, E> MediaService<C, E> for super::Service
+where
+    C: ProvideBlobStore<Error = E>
+        + ProvideMediaAccessControl<Error = E>
+        + ProvideUserRepository<Error = E>
+        + ProvideUserAccessControl<Error = E>
+        + ProvideGroupRepository<Error = E>
+        + ProvideGroupAccessControl<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip_all, fields(hash = %hash))]
+    async fn get_media(&self, ctx: C, hash: MediaHash) -> Result<Media, E> {
+        ctx.judge_get_media(self.principal(), &hash)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(hash = %hash, "Anonymous access denied for media retrieval");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // `<img>` から参照されるため, 匿名ユーザーにも配信する
+        let data = ctx.get_blob(&hash).await?;
+        Ok(Media {
+            hash,
+            content_type: MEDIA_CONTENT_TYPE.to_string(),
+            data,
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(user_id = %user_id))]
+    async fn upload_user_avatar(
+        &self,
+        ctx: C,
+        user_id: UserId,
+        _image: Vec<u8>,
+    ) -> Result<User, E> {
+        ctx.judge_update_user(self.principal(), user_id, &UpdateUserParams::default())
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(user_id = %user_id, "Anonymous access denied for avatar upload");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーが更新できるアカウントはない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(user_id = %user_id))]
+    async fn delete_user_avatar(&self, ctx: C, user_id: UserId) -> Result<User, E> {
+        let params = UpdateUserParams {
+            avatar_url: Some(None),
+            ..Default::default()
+        };
+        ctx.judge_update_user(self.principal(), user_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(user_id = %user_id, "Anonymous access denied for avatar deletion");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーが更新できるアカウントはない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn upload_group_icon(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        _image: Vec<u8>,
+    ) -> Result<Group, E> {
+        ctx.judge_update_group(self.principal(), group_id, &UpdateGroupParams::default())
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for group icon upload");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはどのグループのメンバーでもない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn delete_group_icon(&self, ctx: C, group_id: GroupId) -> Result<Group, E> {
+        ctx.judge_update_group(self.principal(), group_id, &UpdateGroupParams::default())
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for group icon deletion");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはどのグループのメンバーでもない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+}
+
+// MARK: impl for AuthenticatedService
+
+impl<C, E> MediaService<C, E> for super::AuthenticatedService
+where
+    C: ProvideBlobStore<Error = E>
+        + ProvideMediaAccessControl<Error = E>
+        + ProvideUserRepository<Error = E>
+        + ProvideUserAccessControl<Error = E>
+        + ProvideGroupRepository<Error = E>
+        + ProvideGroupAccessControl<Error = E>
+        + ProvideMediaRepository<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip_all, fields(hash = %hash))]
+    async fn get_media(&self, ctx: C, hash: MediaHash) -> Result<Media, E> {
+        ctx.judge_get_media(self.principal(), &hash)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(hash = %hash, "User access denied for media retrieval");
+                E::forbidden("Access forbidden")
+            })?;
+        let data = ctx.get_blob(&hash).await?;
+        Ok(Media {
+            hash,
+            content_type: MEDIA_CONTENT_TYPE.to_string(),
+            data,
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(user_id = %user_id))]
+    async fn upload_user_avatar(&self, ctx: C, user_id: UserId, image: Vec<u8>) -> Result<User, E> {
+        // 権限のないアップロードで変換の負荷をかけさせないよう, 画像を読む前に
+        // 変更なしの更新として権限を確かめる
+        ctx.judge_update_user(self.principal(), user_id, &UpdateUserParams::default())
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(user_id = %user_id, "User access denied for avatar upload");
+                E::forbidden("Access forbidden")
+            })?;
+        let media = normalize_image_in_background(image, AVATAR_SIZE).await?;
+        ctx.put_blob(&media.hash, &media.data).await?;
+        let previous = ctx.get_user(user_id).await?.avatar_url;
+        let params = UpdateUserParams {
+            avatar_url: Some(Some(media.hash.url_path())),
+            ..Default::default()
+        };
+        let user = ctx.update_user(user_id, params).await?;
+        // 参照を増やす前に同じ画像が削除されていたら, 書き戻す
+        ctx.put_blob(&media.hash, &media.data).await?;
+        tracing::debug!(user_id = %user.id, hash = %media.hash, "Updated user avatar");
+        delete_unreferenced_media(&ctx, previous).await;
+        Ok(user)
+    }
+
+    #[tracing::instrument(skip_all, fields(user_id = %user_id))]
+    async fn delete_user_avatar(&self, ctx: C, user_id: UserId) -> Result<User, E> {
+        let params = UpdateUserParams {
+            avatar_url: Some(None),
+            ..Default::default()
+        };
+        ctx.judge_update_user(self.principal(), user_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(user_id = %user_id, "User access denied for avatar deletion");
+                E::forbidden("Access forbidden")
+            })?;
+        let previous = ctx.get_user(user_id).await?.avatar_url;
+        let user = ctx.update_user(user_id, params).await?;
+        tracing::debug!(user_id = %user.id, "Deleted user avatar");
+        delete_unreferenced_media(&ctx, previous).await;
+        Ok(user)
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn upload_group_icon(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        image: Vec<u8>,
+    ) -> Result<Group, E> {
+        // 権限のないアップロードで変換の負荷をかけさせないよう, 画像を読む前に
+        // 変更なしの更新として権限を確かめる
+        ctx.judge_update_group(self.principal(), group_id, &UpdateGroupParams::default())
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for group icon upload");
+                E::forbidden("Access forbidden")
+            })?;
+        let media = normalize_image_in_background(image, GROUP_ICON_SIZE).await?;
+        ctx.put_blob(&media.hash, &media.data).await?;
+        let previous = ctx.get_group(group_id).await?.icon_url;
+        let group = ctx
+            .set_group_icon(group_id, self.user_id, Some(media.hash.url_path()))
+            .await?;
+        // 参照を増やす前に同じ画像が削除されていたら, 書き戻す
+        ctx.put_blob(&media.hash, &media.data).await?;
+        tracing::debug!(group_id = %group.id, hash = %media.hash, "Updated group icon");
+        delete_unreferenced_media(&ctx, previous).await;
+        Ok(group)
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn delete_group_icon(&self, ctx: C, group_id: GroupId) -> Result<Group, E> {
+        ctx.judge_update_group(self.principal(), group_id, &UpdateGroupParams::default())
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for group icon deletion");
+                E::forbidden("Access forbidden")
+            })?;
+        let previous = ctx.get_group(group_id).await?.icon_url;
+        let group = ctx.set_group_icon(group_id, self.user_id, None).await?;
+        tracing::debug!(group_id = %group.id, "Deleted group icon");
+        delete_unreferenced_media(&ctx, previous).await;
+        Ok(group)
+    }
+}
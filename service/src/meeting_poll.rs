@@ -0,0 +1,410 @@
+use domain::{
+    CastMeetingPollVote, CreateMeetingPollParams, GroupId, MeetingPoll, MeetingPollCandidateId,
+    MeetingPollId, MeetingPollService, UserId,
+};
+
+use crate::rbac::ProvideMeetingPollAccessControl;
+
+// MARK: MeetingPollRepository
+
+pub trait MeetingPollRepository<Context, E: domain::Error>: Send + Sync {
+    fn list_meeting_polls(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<MeetingPoll>, E>> + Send;
+
+    /// 日程調整が `group_id` のグループのものでなければ見つからない扱いにします。
+    fn get_meeting_poll(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send;
+
+    fn create_meeting_poll(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        organizer_id: UserId,
+        params: CreateMeetingPollParams,
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send;
+
+    /// `user_id` の投票をすべて `votes` で置き換えます。
+    fn vote_meeting_poll(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        user_id: UserId,
+        votes: &[CastMeetingPollVote],
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send;
+
+    /// 既に確定している日程調整は見つからない扱いにします。
+    /// 確定と同時に `finalized_by` が作成者のグループのイベントを作ります。
+    fn finalize_meeting_poll(
+        &self,
+        ctx: Context,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        candidate_id: MeetingPollCandidateId,
+        finalized_by: UserId,
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send;
+}
+
+impl<R, C, E> MeetingPollRepository<C, E> for &R
+where
+    R: MeetingPollRepository<C, E>,
+    E: domain::Error,
+{
+    fn list_meeting_polls(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<MeetingPoll>, E>> + Send {
+        R::list_meeting_polls(self, ctx, group_id)
+    }
+
+    fn get_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send {
+        R::get_meeting_poll(self, ctx, group_id, poll_id)
+    }
+
+    fn create_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        organizer_id: UserId,
+        params: CreateMeetingPollParams,
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send {
+        R::create_meeting_poll(self, ctx, group_id, organizer_id, params)
+    }
+
+    fn vote_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        user_id: UserId,
+        votes: &[CastMeetingPollVote],
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send {
+        R::vote_meeting_poll(self, ctx, group_id, poll_id, user_id, votes)
+    }
+
+    fn finalize_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        candidate_id: MeetingPollCandidateId,
+        finalized_by: UserId,
+    ) -> impl Future<Output = Result<MeetingPoll, E>> + Send {
+        R::finalize_meeting_poll(self, ctx, group_id, poll_id, candidate_id, finalized_by)
+    }
+}
+
+pub trait ProvideMeetingPollRepository: Send + Sync {
+    type Context<'a>: Send + Sync
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type MeetingPollRepository<'a>: MeetingPollRepository<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn meeting_poll_repository(&self) -> &Self::MeetingPollRepository<'_>;
+
+    fn list_meeting_polls(
+        &self,
+        group_id: GroupId,
+    ) -> impl Future<Output = Result<Vec<MeetingPoll>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_repository()
+            .list_meeting_polls(ctx, group_id)
+    }
+
+    fn get_meeting_poll(
+        &self,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+    ) -> impl Future<Output = Result<MeetingPoll, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_repository()
+            .get_meeting_poll(ctx, group_id, poll_id)
+    }
+
+    fn create_meeting_poll(
+        &self,
+        group_id: GroupId,
+        organizer_id: UserId,
+        params: CreateMeetingPollParams,
+    ) -> impl Future<Output = Result<MeetingPoll, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_repository()
+            .create_meeting_poll(ctx, group_id, organizer_id, params)
+    }
+
+    fn vote_meeting_poll(
+        &self,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        user_id: UserId,
+        votes: &[CastMeetingPollVote],
+    ) -> impl Future<Output = Result<MeetingPoll, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_repository()
+            .vote_meeting_poll(ctx, group_id, poll_id, user_id, votes)
+    }
+
+    fn finalize_meeting_poll(
+        &self,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        candidate_id: MeetingPollCandidateId,
+        finalized_by: UserId,
+    ) -> impl Future<Output = Result<MeetingPoll, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_repository().finalize_meeting_poll(
+            ctx,
+            group_id,
+            poll_id,
+            candidate_id,
+            finalized_by,
+        )
+    }
+}
+
+/// 候補が 1 つ以上あり, どの候補も開始時刻が終了時刻より前であることを確かめます。
+fn validate_meeting_poll_params<E: crate::Error>(
+    params: &CreateMeetingPollParams,
+) -> Result<(), E> {
+    if params.candidates.is_empty() {
+        return Err(E::bad_request(
+            "A meeting poll needs at least one candidate",
+        ));
+    }
+    if params.candidates.iter().any(|c| c.starts_at >= c.ends_at) {
+        return Err(E::bad_request("A candidate must start before it ends"));
+    }
+    Ok(())
+}
+
+/// 投票先がすべて `poll` の候補で, 同じ候補に重複して投票していないことを確かめます。
+fn validate_meeting_poll_votes<E: crate::Error>(
+    poll: &MeetingPoll,
+    votes: &[CastMeetingPollVote],
+) -> Result<(), E> {
+    let mut seen = std::collections::HashSet::new();
+    for vote in votes {
+        if !poll.candidates.iter().any(|c| c.id == vote.candidate_id) {
+            return Err(E::bad_request("Vote for an unknown candidate"));
+        }
+        if !seen.insert(vote.candidate_id) {
+            return Err(E::bad_request("Duplicate votes for the same candidate"));
+        }
+    }
+    Ok(())
+}
+
+// MARK: impl for Service
+
+impl<C, E> MeetingPollService<C, E> for super::Service
+where
+    C: ProvideMeetingPollRepository<Error = E> + ProvideMeetingPollAccessControl<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn list_meeting_polls(&self, ctx: C, group_id: GroupId) -> Result<Vec<MeetingPoll>, E> {
+        ctx.judge_list_meeting_polls(self.principal(), group_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for meeting poll listing");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはどのグループのメンバーでもない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, poll_id = %poll_id))]
+    async fn get_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+    ) -> Result<MeetingPoll, E> {
+        ctx.judge_get_meeting_poll(self.principal(), group_id, poll_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for meeting poll retrieval");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーはどのグループのメンバーでもない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn create_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        params: CreateMeetingPollParams,
+    ) -> Result<MeetingPoll, E> {
+        ctx.judge_create_meeting_poll(self.principal(), group_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for meeting poll creation");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 作成者を記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, poll_id = %poll_id))]
+    async fn vote_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        _votes: &[CastMeetingPollVote],
+    ) -> Result<MeetingPoll, E> {
+        ctx.judge_vote_meeting_poll(self.principal(), group_id, poll_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for meeting poll vote");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 投票者を記録できない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, poll_id = %poll_id))]
+    async fn finalize_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        _candidate_id: MeetingPollCandidateId,
+    ) -> Result<MeetingPoll, E> {
+        ctx.judge_finalize_meeting_poll(self.principal(), group_id, poll_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "Anonymous access denied for meeting poll finalization");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーは作成者にもオーナーにもなれない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+}
+
+// MARK: impl for AuthenticatedService
+
+impl<C, E> MeetingPollService<C, E> for super::AuthenticatedService
+where
+    C: ProvideMeetingPollRepository<Error = E> + ProvideMeetingPollAccessControl<Error = E>,
+    E: crate::Error,
+{
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn list_meeting_polls(&self, ctx: C, group_id: GroupId) -> Result<Vec<MeetingPoll>, E> {
+        ctx.judge_list_meeting_polls(self.principal(), group_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for meeting poll listing");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.list_meeting_polls(group_id).await.inspect(|ps| {
+            tracing::debug!(group_id = %group_id, count = ps.len(), "Listed meeting polls");
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, poll_id = %poll_id))]
+    async fn get_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+    ) -> Result<MeetingPoll, E> {
+        ctx.judge_get_meeting_poll(self.principal(), group_id, poll_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for meeting poll retrieval");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.get_meeting_poll(group_id, poll_id).await.inspect(|p| {
+            tracing::debug!(poll_id = %p.id, "Retrieved meeting poll");
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id))]
+    async fn create_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        params: CreateMeetingPollParams,
+    ) -> Result<MeetingPoll, E> {
+        ctx.judge_create_meeting_poll(self.principal(), group_id, &params)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for meeting poll creation");
+                E::forbidden("Access forbidden")
+            })?;
+        validate_meeting_poll_params(&params)?;
+        ctx.create_meeting_poll(group_id, self.user_id, params)
+            .await
+            .inspect(|p| {
+                tracing::debug!(poll_id = %p.id, candidates = p.candidates.len(), "Created meeting poll");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, poll_id = %poll_id))]
+    async fn vote_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        votes: &[CastMeetingPollVote],
+    ) -> Result<MeetingPoll, E> {
+        ctx.judge_vote_meeting_poll(self.principal(), group_id, poll_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for meeting poll vote");
+                E::forbidden("Access forbidden")
+            })?;
+        let poll = ctx.get_meeting_poll(group_id, poll_id).await?;
+        validate_meeting_poll_votes(&poll, votes)?;
+        ctx.vote_meeting_poll(group_id, poll_id, self.user_id, votes)
+            .await
+            .inspect(|p| {
+                tracing::debug!(poll_id = %p.id, votes = votes.len(), "Voted on meeting poll");
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(group_id = %group_id, poll_id = %poll_id))]
+    async fn finalize_meeting_poll(
+        &self,
+        ctx: C,
+        group_id: GroupId,
+        poll_id: MeetingPollId,
+        candidate_id: MeetingPollCandidateId,
+    ) -> Result<MeetingPoll, E> {
+        ctx.judge_finalize_meeting_poll(self.principal(), group_id, poll_id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(group_id = %group_id, "User access denied for meeting poll finalization");
+                E::forbidden("Access forbidden")
+            })?;
+        let poll = ctx.get_meeting_poll(group_id, poll_id).await?;
+        if !poll.candidates.iter().any(|c| c.id == candidate_id) {
+            return Err(E::bad_request("Finalize with an unknown candidate"));
+        }
+        ctx.finalize_meeting_poll(group_id, poll_id, candidate_id, self.user_id)
+            .await
+            .inspect(|p| {
+                tracing::debug!(poll_id = %p.id, candidate_id = %candidate_id, "Finalized meeting poll");
+            })
+    }
+}
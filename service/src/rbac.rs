@@ -2,6 +2,8 @@
 pub enum Principal {
     Anonymous,
     User(domain::UserId),
+    /// 削除予定のユーザー。復元やデータのエクスポートなど, 一部の操作だけが許されます。
+    PendingDeletion(domain::UserId),
 }
 
 impl crate::Service {
@@ -12,7 +14,11 @@ impl crate::Service {
 
 impl crate::AuthenticatedService {
     pub(crate) fn principal(&self) -> Principal {
-        Principal::User(self.user_id)
+        if self.pending_deletion {
+            Principal::PendingDeletion(self.user_id)
+        } else {
+            Principal::User(self.user_id)
+        }
     }
 }
 
@@ -91,6 +97,26 @@ pub trait UserAccessControl<Context, E: domain::Error>: Send + Sync {
         user_id: domain::UserId,
         params: &domain::UpdateUserParams,
     ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_delete_user(
+        &self,
+        ctx: Context,
+        by: Principal,
+        user_id: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_restore_user(
+        &self,
+        ctx: Context,
+        by: Principal,
+        user_id: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_export_user_data(
+        &self,
+        ctx: Context,
+        by: Principal,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
 }
 
 impl<A, C, E> UserAccessControl<C, E> for &A
@@ -133,6 +159,32 @@ where
     ) -> impl Future<Output = Result<Judgement, E>> + Send {
         A::judge_update_user(self, ctx, by, user_id, params)
     }
+
+    fn judge_delete_user(
+        &self,
+        ctx: C,
+        by: Principal,
+        user_id: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_delete_user(self, ctx, by, user_id)
+    }
+
+    fn judge_restore_user(
+        &self,
+        ctx: C,
+        by: Principal,
+        user_id: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_restore_user(self, ctx, by, user_id)
+    }
+
+    fn judge_export_user_data(
+        &self,
+        ctx: C,
+        by: Principal,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_export_user_data(self, ctx, by)
+    }
 }
 
 pub trait ProvideUserAccessControl: Send + Sync {
@@ -184,6 +236,34 @@ pub trait ProvideUserAccessControl: Send + Sync {
         self.user_access_control()
             .judge_update_user(ctx, by, user_id, params)
     }
+
+    fn judge_delete_user(
+        &self,
+        by: Principal,
+        user_id: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.user_access_control()
+            .judge_delete_user(ctx, by, user_id)
+    }
+
+    fn judge_restore_user(
+        &self,
+        by: Principal,
+        user_id: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.user_access_control()
+            .judge_restore_user(ctx, by, user_id)
+    }
+
+    fn judge_export_user_data(
+        &self,
+        by: Principal,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.user_access_control().judge_export_user_data(ctx, by)
+    }
 }
 
 impl<A> ProvideUserAccessControl for &A
@@ -223,6 +303,7 @@ pub trait GroupAccessControl<Context, E: domain::Error>: Send + Sync {
         &self,
         ctx: Context,
         by: Principal,
+        filter: domain::ListGroupsFilter,
     ) -> impl Future<Output = Result<Judgement, E>> + Send;
 
     fn judge_create_group(
@@ -245,7 +326,91 @@ pub trait GroupAccessControl<Context, E: domain::Error>: Send + Sync {
         ctx: Context,
         by: Principal,
         group_id: domain::GroupId,
-        members: &[domain::UserId],
+        members: &[domain::GroupMember],
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_add_group_members(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        members: &[domain::GroupMember],
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_remove_group_member(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        user_id: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_leave_group(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_transfer_group_ownership(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        to: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_archive_group(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_delete_group(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_create_group_invite(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateGroupInviteParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    /// 存在しない招待コードは許可し, 受け入れる処理で not found にさせます。
+    fn judge_accept_group_invite(
+        &self,
+        ctx: Context,
+        by: Principal,
+        code: &domain::GroupInviteCode,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_list_group_join_requests(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_create_group_join_request(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    /// 参加リクエストの承認と却下で共通の判定です。
+    fn judge_decide_group_join_request(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        request_id: domain::GroupJoinRequestId,
     ) -> impl Future<Output = Result<Judgement, E>> + Send;
 }
 
@@ -267,8 +432,9 @@ where
         &self,
         ctx: C,
         by: Principal,
+        filter: domain::ListGroupsFilter,
     ) -> impl Future<Output = Result<Judgement, E>> + Send {
-        A::judge_list_groups(self, ctx, by)
+        A::judge_list_groups(self, ctx, by, filter)
     }
 
     fn judge_create_group(
@@ -295,10 +461,114 @@ where
         ctx: C,
         by: Principal,
         group_id: domain::GroupId,
-        members: &[domain::UserId],
+        members: &[domain::GroupMember],
     ) -> impl Future<Output = Result<Judgement, E>> + Send {
         A::judge_update_group_members(self, ctx, by, group_id, members)
     }
+
+    fn judge_add_group_members(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        members: &[domain::GroupMember],
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_add_group_members(self, ctx, by, group_id, members)
+    }
+
+    fn judge_remove_group_member(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        user_id: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_remove_group_member(self, ctx, by, group_id, user_id)
+    }
+
+    fn judge_leave_group(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_leave_group(self, ctx, by, group_id)
+    }
+
+    fn judge_transfer_group_ownership(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        to: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_transfer_group_ownership(self, ctx, by, group_id, to)
+    }
+
+    fn judge_archive_group(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_archive_group(self, ctx, by, group_id)
+    }
+
+    fn judge_delete_group(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_delete_group(self, ctx, by, group_id)
+    }
+
+    fn judge_create_group_invite(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateGroupInviteParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_create_group_invite(self, ctx, by, group_id, params)
+    }
+
+    fn judge_accept_group_invite(
+        &self,
+        ctx: C,
+        by: Principal,
+        code: &domain::GroupInviteCode,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_accept_group_invite(self, ctx, by, code)
+    }
+
+    fn judge_list_group_join_requests(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_list_group_join_requests(self, ctx, by, group_id)
+    }
+
+    fn judge_create_group_join_request(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_create_group_join_request(self, ctx, by, group_id)
+    }
+
+    fn judge_decide_group_join_request(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        request_id: domain::GroupJoinRequestId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_decide_group_join_request(self, ctx, by, group_id, request_id)
+    }
 }
 
 pub trait ProvideGroupAccessControl: Send + Sync {
@@ -326,9 +596,11 @@ pub trait ProvideGroupAccessControl: Send + Sync {
     fn judge_list_groups(
         &self,
         by: Principal,
+        filter: domain::ListGroupsFilter,
     ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
         let ctx = self.context();
-        self.group_access_control().judge_list_groups(ctx, by)
+        self.group_access_control()
+            .judge_list_groups(ctx, by, filter)
     }
 
     fn judge_create_group(
@@ -356,33 +628,894 @@ pub trait ProvideGroupAccessControl: Send + Sync {
         &self,
         by: Principal,
         group_id: domain::GroupId,
-        members: &[domain::UserId],
+        members: &[domain::GroupMember],
     ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
         let ctx = self.context();
         self.group_access_control()
             .judge_update_group_members(ctx, by, group_id, members)
     }
-}
 
-impl<A> ProvideGroupAccessControl for &A
-where
-    A: ProvideGroupAccessControl,
-{
-    type Context<'a>
-        = A::Context<'a>
-    where
-        Self: 'a;
-    type Error = A::Error;
-    type GroupAccessControl<'a>
-        = A::GroupAccessControl<'a>
-    where
-        Self: 'a;
+    fn judge_add_group_members(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        members: &[domain::GroupMember],
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_add_group_members(ctx, by, group_id, members)
+    }
 
-    fn context(&self) -> Self::Context<'_> {
-        A::context(self)
+    fn judge_remove_group_member(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        user_id: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_remove_group_member(ctx, by, group_id, user_id)
     }
 
-    fn group_access_control(&self) -> &Self::GroupAccessControl<'_> {
-        A::group_access_control(self)
+    fn judge_leave_group(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_leave_group(ctx, by, group_id)
+    }
+
+    fn judge_transfer_group_ownership(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        to: domain::UserId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_transfer_group_ownership(ctx, by, group_id, to)
+    }
+
+    fn judge_archive_group(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_archive_group(ctx, by, group_id)
+    }
+
+    fn judge_delete_group(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_delete_group(ctx, by, group_id)
+    }
+
+    fn judge_create_group_invite(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateGroupInviteParams,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_create_group_invite(ctx, by, group_id, params)
+    }
+
+    fn judge_accept_group_invite(
+        &self,
+        by: Principal,
+        code: &domain::GroupInviteCode,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_accept_group_invite(ctx, by, code)
+    }
+
+    fn judge_list_group_join_requests(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_list_group_join_requests(ctx, by, group_id)
+    }
+
+    fn judge_create_group_join_request(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_create_group_join_request(ctx, by, group_id)
+    }
+
+    fn judge_decide_group_join_request(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        request_id: domain::GroupJoinRequestId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_access_control()
+            .judge_decide_group_join_request(ctx, by, group_id, request_id)
+    }
+}
+
+impl<A> ProvideGroupAccessControl for &A
+where
+    A: ProvideGroupAccessControl,
+{
+    type Context<'a>
+        = A::Context<'a>
+    where
+        Self: 'a;
+    type Error = A::Error;
+    type GroupAccessControl<'a>
+        = A::GroupAccessControl<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        A::context(self)
+    }
+
+    fn group_access_control(&self) -> &Self::GroupAccessControl<'_> {
+        A::group_access_control(self)
+    }
+}
+
+// MARK: MeetingPollAccessControl
+
+pub trait MeetingPollAccessControl<Context, E: domain::Error>: Send + Sync {
+    fn judge_list_meeting_polls(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_get_meeting_poll(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_create_meeting_poll(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateMeetingPollParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_vote_meeting_poll(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_finalize_meeting_poll(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+}
+
+impl<A, C, E> MeetingPollAccessControl<C, E> for &A
+where
+    A: MeetingPollAccessControl<C, E>,
+    E: domain::Error,
+{
+    fn judge_list_meeting_polls(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_list_meeting_polls(self, ctx, by, group_id)
+    }
+
+    fn judge_get_meeting_poll(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_get_meeting_poll(self, ctx, by, group_id, poll_id)
+    }
+
+    fn judge_create_meeting_poll(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateMeetingPollParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_create_meeting_poll(self, ctx, by, group_id, params)
+    }
+
+    fn judge_vote_meeting_poll(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_vote_meeting_poll(self, ctx, by, group_id, poll_id)
+    }
+
+    fn judge_finalize_meeting_poll(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_finalize_meeting_poll(self, ctx, by, group_id, poll_id)
+    }
+}
+
+pub trait ProvideMeetingPollAccessControl: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type MeetingPollAccessControl<'a>: MeetingPollAccessControl<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn meeting_poll_access_control(&self) -> &Self::MeetingPollAccessControl<'_>;
+
+    fn judge_list_meeting_polls(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_access_control()
+            .judge_list_meeting_polls(ctx, by, group_id)
+    }
+
+    fn judge_get_meeting_poll(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_access_control()
+            .judge_get_meeting_poll(ctx, by, group_id, poll_id)
+    }
+
+    fn judge_create_meeting_poll(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateMeetingPollParams,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_access_control()
+            .judge_create_meeting_poll(ctx, by, group_id, params)
+    }
+
+    fn judge_vote_meeting_poll(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_access_control()
+            .judge_vote_meeting_poll(ctx, by, group_id, poll_id)
+    }
+
+    fn judge_finalize_meeting_poll(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        poll_id: domain::MeetingPollId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.meeting_poll_access_control()
+            .judge_finalize_meeting_poll(ctx, by, group_id, poll_id)
+    }
+}
+
+impl<A> ProvideMeetingPollAccessControl for &A
+where
+    A: ProvideMeetingPollAccessControl,
+{
+    type Context<'a>
+        = A::Context<'a>
+    where
+        Self: 'a;
+    type Error = A::Error;
+    type MeetingPollAccessControl<'a>
+        = A::MeetingPollAccessControl<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        A::context(self)
+    }
+
+    fn meeting_poll_access_control(&self) -> &Self::MeetingPollAccessControl<'_> {
+        A::meeting_poll_access_control(self)
+    }
+}
+
+// MARK: GroupEventAccessControl
+
+pub trait GroupEventAccessControl<Context, E: domain::Error>: Send + Sync {
+    fn judge_list_group_events(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_get_group_event(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_create_group_event(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateGroupEventParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_update_group_event(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+        params: &domain::UpdateGroupEventParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_delete_group_event(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+}
+
+impl<A, C, E> GroupEventAccessControl<C, E> for &A
+where
+    A: GroupEventAccessControl<C, E>,
+    E: domain::Error,
+{
+    fn judge_list_group_events(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_list_group_events(self, ctx, by, group_id)
+    }
+
+    fn judge_get_group_event(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_get_group_event(self, ctx, by, group_id, event_id)
+    }
+
+    fn judge_create_group_event(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateGroupEventParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_create_group_event(self, ctx, by, group_id, params)
+    }
+
+    fn judge_update_group_event(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+        params: &domain::UpdateGroupEventParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_update_group_event(self, ctx, by, group_id, event_id, params)
+    }
+
+    fn judge_delete_group_event(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_delete_group_event(self, ctx, by, group_id, event_id)
+    }
+}
+
+pub trait ProvideGroupEventAccessControl: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type GroupEventAccessControl<'a>: GroupEventAccessControl<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn group_event_access_control(&self) -> &Self::GroupEventAccessControl<'_>;
+
+    fn judge_list_group_events(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_access_control()
+            .judge_list_group_events(ctx, by, group_id)
+    }
+
+    fn judge_get_group_event(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_access_control()
+            .judge_get_group_event(ctx, by, group_id, event_id)
+    }
+
+    fn judge_create_group_event(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateGroupEventParams,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_access_control()
+            .judge_create_group_event(ctx, by, group_id, params)
+    }
+
+    fn judge_update_group_event(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+        params: &domain::UpdateGroupEventParams,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_access_control()
+            .judge_update_group_event(ctx, by, group_id, event_id, params)
+    }
+
+    fn judge_delete_group_event(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        event_id: domain::GroupEventId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_event_access_control()
+            .judge_delete_group_event(ctx, by, group_id, event_id)
+    }
+}
+
+impl<A> ProvideGroupEventAccessControl for &A
+where
+    A: ProvideGroupEventAccessControl,
+{
+    type Context<'a>
+        = A::Context<'a>
+    where
+        Self: 'a;
+    type Error = A::Error;
+    type GroupEventAccessControl<'a>
+        = A::GroupEventAccessControl<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        A::context(self)
+    }
+
+    fn group_event_access_control(&self) -> &Self::GroupEventAccessControl<'_> {
+        A::group_event_access_control(self)
+    }
+}
+
+// MARK: AnnouncementAccessControl
+
+pub trait AnnouncementAccessControl<Context, E: domain::Error>: Send + Sync {
+    fn judge_list_announcements(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_get_announcement(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_create_announcement(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateAnnouncementParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_update_announcement(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+        params: &domain::UpdateAnnouncementParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_delete_announcement(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+
+    fn judge_mark_announcement_read(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+}
+
+impl<A, C, E> AnnouncementAccessControl<C, E> for &A
+where
+    A: AnnouncementAccessControl<C, E>,
+    E: domain::Error,
+{
+    fn judge_list_announcements(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_list_announcements(self, ctx, by, group_id)
+    }
+
+    fn judge_get_announcement(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_get_announcement(self, ctx, by, group_id, announcement_id)
+    }
+
+    fn judge_create_announcement(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateAnnouncementParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_create_announcement(self, ctx, by, group_id, params)
+    }
+
+    fn judge_update_announcement(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+        params: &domain::UpdateAnnouncementParams,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_update_announcement(self, ctx, by, group_id, announcement_id, params)
+    }
+
+    fn judge_delete_announcement(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_delete_announcement(self, ctx, by, group_id, announcement_id)
+    }
+
+    fn judge_mark_announcement_read(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_mark_announcement_read(self, ctx, by, group_id, announcement_id)
+    }
+}
+
+pub trait ProvideAnnouncementAccessControl: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type AnnouncementAccessControl<'a>: AnnouncementAccessControl<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn announcement_access_control(&self) -> &Self::AnnouncementAccessControl<'_>;
+
+    fn judge_list_announcements(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_access_control()
+            .judge_list_announcements(ctx, by, group_id)
+    }
+
+    fn judge_get_announcement(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_access_control().judge_get_announcement(
+            ctx,
+            by,
+            group_id,
+            announcement_id,
+        )
+    }
+
+    fn judge_create_announcement(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        params: &domain::CreateAnnouncementParams,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_access_control()
+            .judge_create_announcement(ctx, by, group_id, params)
+    }
+
+    fn judge_update_announcement(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+        params: &domain::UpdateAnnouncementParams,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_access_control()
+            .judge_update_announcement(ctx, by, group_id, announcement_id, params)
+    }
+
+    fn judge_delete_announcement(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_access_control()
+            .judge_delete_announcement(ctx, by, group_id, announcement_id)
+    }
+
+    fn judge_mark_announcement_read(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+        announcement_id: domain::AnnouncementId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.announcement_access_control()
+            .judge_mark_announcement_read(ctx, by, group_id, announcement_id)
+    }
+}
+
+impl<A> ProvideAnnouncementAccessControl for &A
+where
+    A: ProvideAnnouncementAccessControl,
+{
+    type Context<'a>
+        = A::Context<'a>
+    where
+        Self: 'a;
+    type Error = A::Error;
+    type AnnouncementAccessControl<'a>
+        = A::AnnouncementAccessControl<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        A::context(self)
+    }
+
+    fn announcement_access_control(&self) -> &Self::AnnouncementAccessControl<'_> {
+        A::announcement_access_control(self)
+    }
+}
+
+// MARK: GroupActivityAccessControl
+
+pub trait GroupActivityAccessControl<Context, E: domain::Error>: Send + Sync {
+    fn judge_list_group_activities(
+        &self,
+        ctx: Context,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+}
+
+impl<A, C, E> GroupActivityAccessControl<C, E> for &A
+where
+    A: GroupActivityAccessControl<C, E>,
+    E: domain::Error,
+{
+    fn judge_list_group_activities(
+        &self,
+        ctx: C,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_list_group_activities(self, ctx, by, group_id)
+    }
+}
+
+pub trait ProvideGroupActivityAccessControl: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type GroupActivityAccessControl<'a>: GroupActivityAccessControl<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn group_activity_access_control(&self) -> &Self::GroupActivityAccessControl<'_>;
+
+    fn judge_list_group_activities(
+        &self,
+        by: Principal,
+        group_id: domain::GroupId,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.group_activity_access_control()
+            .judge_list_group_activities(ctx, by, group_id)
+    }
+}
+
+impl<A> ProvideGroupActivityAccessControl for &A
+where
+    A: ProvideGroupActivityAccessControl,
+{
+    type Context<'a>
+        = A::Context<'a>
+    where
+        Self: 'a;
+    type Error = A::Error;
+    type GroupActivityAccessControl<'a>
+        = A::GroupActivityAccessControl<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        A::context(self)
+    }
+
+    fn group_activity_access_control(&self) -> &Self::GroupActivityAccessControl<'_> {
+        A::group_activity_access_control(self)
+    }
+}
+
+// MARK: MediaAccessControl
+
+pub trait MediaAccessControl<Context, E: domain::Error>: Send + Sync {
+    fn judge_get_media(
+        &self,
+        ctx: Context,
+        by: Principal,
+        hash: &domain::MediaHash,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send;
+}
+
+impl<A, C, E> MediaAccessControl<C, E> for &A
+where
+    A: MediaAccessControl<C, E>,
+    E: domain::Error,
+{
+    fn judge_get_media(
+        &self,
+        ctx: C,
+        by: Principal,
+        hash: &domain::MediaHash,
+    ) -> impl Future<Output = Result<Judgement, E>> + Send {
+        A::judge_get_media(self, ctx, by, hash)
+    }
+}
+
+pub trait ProvideMediaAccessControl: Send + Sync {
+    type Context<'a>
+    where
+        Self: 'a;
+    type Error: domain::Error;
+    type MediaAccessControl<'a>: MediaAccessControl<Self::Context<'a>, Self::Error>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_>;
+    fn media_access_control(&self) -> &Self::MediaAccessControl<'_>;
+
+    fn judge_get_media(
+        &self,
+        by: Principal,
+        hash: &domain::MediaHash,
+    ) -> impl Future<Output = Result<Judgement, Self::Error>> + Send {
+        let ctx = self.context();
+        self.media_access_control().judge_get_media(ctx, by, hash)
+    }
+}
+
+impl<A> ProvideMediaAccessControl for &A
+where
+    A: ProvideMediaAccessControl,
+{
+    type Context<'a>
+        = A::Context<'a>
+    where
+        Self: 'a;
+    type Error = A::Error;
+    type MediaAccessControl<'a>
+        = A::MediaAccessControl<'a>
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        A::context(self)
+    }
+
+    fn media_access_control(&self) -> &Self::MediaAccessControl<'_> {
+        A::media_access_control(self)
     }
 }
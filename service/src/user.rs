@@ -1,5 +1,9 @@
-use domain::{CreateUserParams, UpdateUserParams, User, UserId, UserService};
+use domain::{
+    Affiliation, CreateUserParams, Timestamp, UpdateUserParams, User, UserDataExport, UserId,
+    UserService,
+};
 
+use crate::media::{ProvideBlobStore, ProvideMediaRepository, sweep_unreferenced_media};
 use crate::rbac::ProvideUserAccessControl;
 
 // MARK: UserRepository
@@ -21,6 +25,35 @@ pub trait UserRepository<Context, E: domain::Error>: Send + Sync {
         id: UserId,
         params: UpdateUserParams,
     ) -> impl Future<Output = Result<User, E>> + Send;
+
+    /// 削除日時を記録します。既に削除予定なら元の日時を保ちます。
+    ///
+    /// 他のメンバーが残るのにオーナーがいなくなるグループがあれば conflict を返します。
+    fn delete_user(&self, ctx: Context, id: UserId)
+    -> impl Future<Output = Result<User, E>> + Send;
+
+    fn restore_user(
+        &self,
+        ctx: Context,
+        id: UserId,
+    ) -> impl Future<Output = Result<User, E>> + Send;
+
+    /// `id` のユーザーについて保存されているデータを集めます。
+    fn export_user_data(
+        &self,
+        ctx: Context,
+        id: UserId,
+    ) -> impl Future<Output = Result<UserDataExport, E>> + Send;
+
+    /// `deleted_before` より前に削除予定になったユーザーを消去します。
+    ///
+    /// 他に誰もいないグループは一緒に消去し、唯一のオーナーだったグループでは
+    /// 別のメンバーをオーナーにします。
+    fn purge_deleted_users(
+        &self,
+        ctx: Context,
+        deleted_before: Timestamp,
+    ) -> impl Future<Output = Result<Vec<UserId>, E>> + Send;
 }
 
 impl<R, C, E> UserRepository<C, E> for &R
@@ -52,6 +85,30 @@ where
     ) -> impl Future<Output = Result<User, E>> + Send {
         R::update_user(self, ctx, id, params)
     }
+
+    fn delete_user(&self, ctx: C, id: UserId) -> impl Future<Output = Result<User, E>> + Send {
+        R::delete_user(self, ctx, id)
+    }
+
+    fn restore_user(&self, ctx: C, id: UserId) -> impl Future<Output = Result<User, E>> + Send {
+        R::restore_user(self, ctx, id)
+    }
+
+    fn export_user_data(
+        &self,
+        ctx: C,
+        id: UserId,
+    ) -> impl Future<Output = Result<UserDataExport, E>> + Send {
+        R::export_user_data(self, ctx, id)
+    }
+
+    fn purge_deleted_users(
+        &self,
+        ctx: C,
+        deleted_before: Timestamp,
+    ) -> impl Future<Output = Result<Vec<UserId>, E>> + Send {
+        R::purge_deleted_users(self, ctx, deleted_before)
+    }
 }
 
 pub trait ProvideUserRepository: Send + Sync {
@@ -92,6 +149,33 @@ pub trait ProvideUserRepository: Send + Sync {
         let ctx = self.context();
         self.user_repository().update_user(ctx, id, params)
     }
+
+    fn delete_user(&self, id: UserId) -> impl Future<Output = Result<User, Self::Error>> + Send {
+        let ctx = self.context();
+        self.user_repository().delete_user(ctx, id)
+    }
+
+    fn restore_user(&self, id: UserId) -> impl Future<Output = Result<User, Self::Error>> + Send {
+        let ctx = self.context();
+        self.user_repository().restore_user(ctx, id)
+    }
+
+    fn export_user_data(
+        &self,
+        id: UserId,
+    ) -> impl Future<Output = Result<UserDataExport, Self::Error>> + Send {
+        let ctx = self.context();
+        self.user_repository().export_user_data(ctx, id)
+    }
+
+    fn purge_deleted_users(
+        &self,
+        deleted_before: Timestamp,
+    ) -> impl Future<Output = Result<Vec<UserId>, Self::Error>> + Send {
+        let ctx = self.context();
+        self.user_repository()
+            .purge_deleted_users(ctx, deleted_before)
+    }
 }
 
 impl<R> ProvideUserRepository for &R
@@ -117,6 +201,91 @@ where
     }
 }
 
+/// 表示名が空白だけでなく, 長すぎないことを確かめます。
+fn validate_user_name<E: crate::Error>(name: &str) -> Result<(), E> {
+    const MAX_NAME_LEN: usize = 50;
+
+    if name.trim().is_empty() {
+        return Err(E::bad_request("Name must not be blank"));
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(E::bad_request("Name is too long"));
+    }
+    Ok(())
+}
+
+/// プロフィールの各項目を検証し, ハンドルを `@` なしの小文字にそろえます。
+fn normalize_update_user_params<E: crate::Error>(
+    params: UpdateUserParams,
+) -> Result<UpdateUserParams, E> {
+    const HANDLE_LEN: std::ops::RangeInclusive<usize> = 3..=30;
+    const ENROLLMENT_YEAR: std::ops::RangeInclusive<i32> = 1900..=2100;
+    const MAX_AFFILIATION_LEN: usize = 100;
+    const MAX_BIO_LEN: usize = 1000;
+
+    let UpdateUserParams {
+        name,
+        handle,
+        affiliation,
+        enrollment_year,
+        bio,
+        avatar_url,
+    } = params;
+    if let Some(name) = &name {
+        validate_user_name(name)?;
+    }
+    let handle = match handle {
+        Some(Some(handle)) => {
+            let handle = handle.strip_prefix('@').unwrap_or(&handle);
+            let handle = handle.to_ascii_lowercase();
+            let valid_chars = handle
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+            if !valid_chars || !HANDLE_LEN.contains(&handle.len()) {
+                return Err(E::bad_request(
+                    "Handle must be 3 to 30 letters, digits or underscores",
+                ));
+            }
+            Some(Some(handle))
+        }
+        handle => handle,
+    };
+    if let Some(affiliation) = &affiliation {
+        let Affiliation {
+            university,
+            faculty,
+            department,
+        } = affiliation;
+        let too_long = [university, faculty, department]
+            .into_iter()
+            .flatten()
+            .any(|s| s.chars().count() > MAX_AFFILIATION_LEN);
+        if too_long {
+            return Err(E::bad_request("Affiliation is too long"));
+        }
+    }
+    if enrollment_year
+        .flatten()
+        .is_some_and(|year| !ENROLLMENT_YEAR.contains(&year))
+    {
+        return Err(E::bad_request("Enrollment year is out of range"));
+    }
+    if bio
+        .as_ref()
+        .is_some_and(|bio| bio.chars().count() > MAX_BIO_LEN)
+    {
+        return Err(E::bad_request("Bio is too long"));
+    }
+    Ok(UpdateUserParams {
+        name,
+        handle,
+        affiliation,
+        enrollment_year,
+        bio,
+        avatar_url,
+    })
+}
+
 // MARK: impl for Service
 
 impl<C, E> UserService<C, E> for super::Service
@@ -158,6 +327,7 @@ where
                 tracing::debug!("Anonymous access denied for user creation");
                 E::unauthenticated("Unauthenticated access")
             })?;
+        validate_user_name(&params.name)?;
         ctx.create_user(params).await.inspect(|u| {
             tracing::debug!(id = %u.id, "Created user");
         })
@@ -175,6 +345,64 @@ where
             tracing::debug!(id = %u.id, "Updated user");
         })
     }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn delete_user(&self, ctx: C, id: UserId) -> Result<User, E> {
+        ctx.judge_delete_user(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for user deletion");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーが削除できるアカウントはない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn restore_user(&self, ctx: C, id: UserId) -> Result<User, E> {
+        ctx.judge_restore_user(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "Anonymous access denied for user restoration");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーが復元できるアカウントはない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn export_user_data(&self, ctx: C) -> Result<UserDataExport, E> {
+        ctx.judge_export_user_data(self.principal())
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!("Anonymous access denied for user data export");
+                E::unauthenticated("Unauthenticated access")
+            })?;
+        // 匿名ユーザーについて保存されているデータはない
+        Err(E::unauthenticated("Unauthenticated access"))
+    }
+}
+
+impl super::Service {
+    /// 猶予期間を過ぎた削除予定のユーザーを消去します。定期実行されるジョブから呼ばれます。
+    #[tracing::instrument(skip_all, fields(deleted_before = %deleted_before))]
+    pub async fn purge_deleted_users<C, E>(
+        &self,
+        ctx: C,
+        deleted_before: Timestamp,
+    ) -> Result<Vec<UserId>, E>
+    where
+        C: ProvideUserRepository<Error = E>
+            + ProvideBlobStore<Error = E>
+            + ProvideMediaRepository<Error = E>,
+        E: crate::Error,
+    {
+        let user_ids = ctx.purge_deleted_users(deleted_before).await?;
+        tracing::debug!(count = user_ids.len(), "Purged deleted users");
+        // 消去したユーザーやグループの画像と, これまでに消し損ねた画像をまとめて消す
+        sweep_unreferenced_media(&ctx).await;
+        Ok(user_ids)
+    }
 }
 
 // MARK: impl for AuthenticatedService
@@ -192,9 +420,14 @@ where
                 tracing::debug!(id = %id, "User access denied for user retrieval");
                 E::forbidden("Access forbidden")
             })?;
-        ctx.get_user(id).await.inspect(|u| {
-            tracing::debug!(id = %u.id, "Retrieved user");
-        })
+        let user = ctx.get_user(id).await?;
+        // 削除予定のアカウントは本人以外からは存在しないものとして扱う
+        if user.deleted_at.is_some() && user.id != self.user_id {
+            tracing::debug!(id = %id, "User is scheduled for deletion");
+            return Err(E::not_found("User not found"));
+        }
+        tracing::debug!(id = %user.id, "Retrieved user");
+        Ok(user)
     }
 
     #[tracing::instrument(skip_all)]
@@ -218,6 +451,7 @@ where
                 tracing::debug!("User access denied for user creation");
                 E::forbidden("Access forbidden")
             })?;
+        validate_user_name(&params.name)?;
         ctx.create_user(params).await.inspect(|u| {
             tracing::debug!(id = %u.id, "Created user");
         })
@@ -231,8 +465,47 @@ where
                 tracing::debug!(id = %id, "User access denied for user update");
                 E::forbidden("Access forbidden")
             })?;
+        let params = normalize_update_user_params(params)?;
         ctx.update_user(id, params).await.inspect(|u| {
             tracing::debug!(id = %u.id, "Updated user");
         })
     }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn delete_user(&self, ctx: C, id: UserId) -> Result<User, E> {
+        ctx.judge_delete_user(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for user deletion");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.delete_user(id).await.inspect(|u| {
+            tracing::debug!(id = %u.id, "Scheduled user deletion");
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    async fn restore_user(&self, ctx: C, id: UserId) -> Result<User, E> {
+        ctx.judge_restore_user(self.principal(), id)
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!(id = %id, "User access denied for user restoration");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.restore_user(id).await.inspect(|u| {
+            tracing::debug!(id = %u.id, "Restored user");
+        })
+    }
+    #[tracing::instrument(skip_all)]
+    async fn export_user_data(&self, ctx: C) -> Result<UserDataExport, E> {
+        ctx.judge_export_user_data(self.principal())
+            .await?
+            .allow_or_else(|| {
+                tracing::debug!("User access denied for user data export");
+                E::forbidden("Access forbidden")
+            })?;
+        ctx.export_user_data(self.user_id).await.inspect(|d| {
+            tracing::debug!(id = %d.profile.id, "Exported user data");
+        })
+    }
 }
@@ -72,3 +72,59 @@ impl ServeConfig {
         std::net::SocketAddr::new(self.addr, self.port)
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct AccountConfig {
+    /// 削除を申請してからアカウントを消去するまでの日数
+    pub deletion_grace_days: u32,
+    /// 消去対象を探す間隔の秒数
+    pub purge_interval_secs: u64,
+}
+
+impl AccountConfig {
+    pub fn load_env(prefix: &str) -> anyhow::Result<Self> {
+        use anyhow::Context;
+
+        let var = |key: &str| {
+            std::env::var(format!("{prefix}{key}"))
+                .with_context(|| format!("Environment variable {prefix}{key} not set"))
+        };
+        let deletion_grace_days = var("DELETION_GRACE_DAYS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .context("Invalid number of grace days")?;
+        let purge_interval_secs = var("PURGE_INTERVAL_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse()
+            .context("Invalid purge interval")?;
+
+        Ok(Self {
+            deletion_grace_days,
+            purge_interval_secs,
+        })
+    }
+
+    pub fn deletion_grace_period(&self) -> chrono::TimeDelta {
+        chrono::TimeDelta::days(self.deletion_grace_days.into())
+    }
+
+    pub fn purge_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.purge_interval_secs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct MediaConfig {
+    /// アップロードされた画像を保存するディレクトリ
+    pub dir: std::path::PathBuf,
+}
+
+impl MediaConfig {
+    pub fn load_env(prefix: &str) -> anyhow::Result<Self> {
+        let dir = std::env::var(format!("{prefix}DIR"))
+            .unwrap_or_else(|_| "media".to_string())
+            .into();
+
+        Ok(Self { dir })
+    }
+}
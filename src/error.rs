@@ -5,6 +5,8 @@ pub enum Error {
     NotFound(String),
     Unauthenticated(String),
     Forbidden(String),
+    Conflict(String),
+    BadRequest(String),
     Unexpected(anyhow::Error),
 }
 
@@ -14,6 +16,8 @@ impl fmt::Display for Error {
             Error::NotFound(msg) => write!(f, "Not Found: {msg}"),
             Error::Unauthenticated(msg) => write!(f, "Unauthenticated: {msg}"),
             Error::Forbidden(msg) => write!(f, "Forbidden: {msg}"),
+            Error::Conflict(msg) => write!(f, "Conflict: {msg}"),
+            Error::BadRequest(msg) => write!(f, "Bad Request: {msg}"),
             Error::Unexpected(err) => write!(f, "Unexpected error: {err}"),
         }
     }
@@ -33,9 +37,23 @@ impl repository::Error for Error {
     fn not_found(message: &str) -> Self {
         Error::NotFound(message.to_string())
     }
+
+    fn conflict(message: &str) -> Self {
+        Error::Conflict(message.to_string())
+    }
+}
+
+impl storage::Error for Error {
+    fn not_found(message: &str) -> Self {
+        Error::NotFound(message.to_string())
+    }
 }
 
 impl service::Error for Error {
+    fn not_found(message: &str) -> Self {
+        Error::NotFound(message.to_string())
+    }
+
     fn unauthenticated(message: &str) -> Self {
         Error::Unauthenticated(message.to_string())
     }
@@ -43,6 +61,14 @@ impl service::Error for Error {
     fn forbidden(message: &str) -> Self {
         Error::Forbidden(message.to_string())
     }
+
+    fn conflict(message: &str) -> Self {
+        Error::Conflict(message.to_string())
+    }
+
+    fn bad_request(message: &str) -> Self {
+        Error::BadRequest(message.to_string())
+    }
 }
 
 impl From<Error> for router::Error {
@@ -51,6 +77,8 @@ impl From<Error> for router::Error {
             Error::NotFound(msg) => Self::new(http::StatusCode::NOT_FOUND, msg),
             Error::Unauthenticated(msg) => Self::new(http::StatusCode::UNAUTHORIZED, msg),
             Error::Forbidden(msg) => Self::new(http::StatusCode::FORBIDDEN, msg),
+            Error::Conflict(msg) => Self::new(http::StatusCode::CONFLICT, msg),
+            Error::BadRequest(msg) => Self::new(http::StatusCode::BAD_REQUEST, msg),
             Error::Unexpected(err) => err.into(),
         }
     }
@@ -11,7 +11,10 @@ async fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt().with_env_filter(env_filter).init();
 
     let pg_config = config::PgConfig::load_env("POSTGRES_")?;
-    let state = state::State::load_pg(&pg_config).await?;
+    let media_config = config::MediaConfig::load_env("MEDIA_")?;
+    let state = state::State::load_pg(&pg_config, &media_config).await?;
+    let account_config = config::AccountConfig::load_env("ACCOUNT_")?;
+    tokio::spawn(purge_deleted_users(state.clone(), account_config));
     let router = router::Service::new(state).into_router();
     let serve_config = config::ServeConfig::load_env("")?;
     let addr = serve_config.socket_addr();
@@ -33,3 +36,17 @@ async fn signal() {
         Err(e) => tracing::error!(error = %e, "Failed to listen for Ctrl+C signal"),
     }
 }
+
+#[tracing::instrument(skip_all)]
+async fn purge_deleted_users(state: state::State, config: config::AccountConfig) {
+    let grace_period = config.deletion_grace_period();
+    let mut interval = tokio::time::interval(config.purge_interval());
+    loop {
+        interval.tick().await;
+        match state.purge_deleted_users(grace_period).await {
+            Ok(ids) if ids.is_empty() => {}
+            Ok(ids) => tracing::info!(count = ids.len(), "Purged deleted users"),
+            Err(e) => tracing::error!(error = %e, "Failed to purge deleted users"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use anyhow::Context;
 
 use repository::Repository;
+use storage::LocalBlobStore;
 
 // MARK: structs
 
@@ -10,6 +11,7 @@ pub struct State {
     repository: Repository,
     authz: authz::Engine,
     pg_pool: sqlx::PgPool,
+    blob_store: LocalBlobStore,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -17,6 +19,7 @@ pub struct ServiceContext<'a> {
     repository: &'a Repository,
     authz: &'a authz::Engine,
     pg_pool: &'a sqlx::PgPool,
+    blob_store: &'a LocalBlobStore,
 }
 
 #[derive(Debug, Clone)]
@@ -25,6 +28,7 @@ pub struct AuthnState {
     repository: Repository,
     authz: authz::Engine,
     pg_pool: sqlx::PgPool,
+    blob_store: LocalBlobStore,
 }
 
 pub struct EngineContext<'a> {
@@ -35,18 +39,23 @@ pub struct EngineContext<'a> {
 // MARK: impl State
 
 impl State {
-    pub async fn load_pg(config: &crate::config::PgConfig) -> anyhow::Result<Self> {
+    pub async fn load_pg(
+        config: &crate::config::PgConfig,
+        media_config: &crate::config::MediaConfig,
+    ) -> anyhow::Result<Self> {
         let conn_opts = config.conn_options();
         let pool = sqlx::PgPool::connect_with(conn_opts)
             .await
             .context("Failed to connect to PostgreSQL")?;
         let repository = repository::Repository::up(&pool).await?;
         let authz = authz::Engine::new()?;
+        let blob_store = LocalBlobStore::new(&media_config.dir).await?;
         Ok(Self {
             service: service::Service::new(),
             repository,
             authz,
             pg_pool: pool,
+            blob_store,
         })
     }
 
@@ -55,8 +64,20 @@ impl State {
             repository: &self.repository,
             authz: &self.authz,
             pg_pool: &self.pg_pool,
+            blob_store: &self.blob_store,
         }
     }
+
+    /// 削除を申請してから `grace_period` が過ぎたユーザーを消去します。
+    pub async fn purge_deleted_users(
+        &self,
+        grace_period: chrono::TimeDelta,
+    ) -> Result<Vec<domain::UserId>, crate::error::Error> {
+        let deleted_before = chrono::Utc::now() - grace_period;
+        self.service
+            .purge_deleted_users(self.service_context(), deleted_before)
+            .await
+    }
 }
 
 impl domain::ProvideUserService for State {
@@ -79,6 +100,26 @@ impl domain::ProvideUserService for State {
     }
 }
 
+impl domain::ProvideMediaService for State {
+    type Context<'a>
+        = ServiceContext<'a>
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type MediaService<'a>
+        = service::Service
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.service_context()
+    }
+
+    fn media_service(&self) -> &Self::MediaService<'_> {
+        &self.service
+    }
+}
+
 impl service::MakeAuthenticated<crate::error::Error> for State {
     type Authenticated = AuthnState;
 
@@ -90,10 +131,11 @@ impl service::MakeAuthenticated<crate::error::Error> for State {
 
         let user = self.service_context().get_user(user_id).await?;
         Ok(AuthnState {
-            service: self.service.authenticated(user.id),
+            service: self.service.authenticated(&user),
             repository: self.repository.clone(),
             authz: self.authz.clone(),
             pg_pool: self.pg_pool.clone(),
+            blob_store: self.blob_store.clone(),
         })
     }
 }
@@ -106,6 +148,7 @@ impl AuthnState {
             repository: &self.repository,
             authz: &self.authz,
             pg_pool: &self.pg_pool,
+            blob_store: &self.blob_store,
         }
     }
 }
@@ -130,6 +173,86 @@ impl domain::ProvideGroupService for AuthnState {
     }
 }
 
+impl domain::ProvideAnnouncementService for AuthnState {
+    type Context<'a>
+        = ServiceContext<'a>
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type AnnouncementService<'a>
+        = service::AuthenticatedService
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.service_context()
+    }
+
+    fn announcement_service(&self) -> &Self::AnnouncementService<'_> {
+        &self.service
+    }
+}
+
+impl domain::ProvideGroupActivityService for AuthnState {
+    type Context<'a>
+        = ServiceContext<'a>
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type GroupActivityService<'a>
+        = service::AuthenticatedService
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.service_context()
+    }
+
+    fn group_activity_service(&self) -> &Self::GroupActivityService<'_> {
+        &self.service
+    }
+}
+
+impl domain::ProvideGroupEventService for AuthnState {
+    type Context<'a>
+        = ServiceContext<'a>
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type GroupEventService<'a>
+        = service::AuthenticatedService
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.service_context()
+    }
+
+    fn group_event_service(&self) -> &Self::GroupEventService<'_> {
+        &self.service
+    }
+}
+
+impl domain::ProvideMeetingPollService for AuthnState {
+    type Context<'a>
+        = ServiceContext<'a>
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type MeetingPollService<'a>
+        = service::AuthenticatedService
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.service_context()
+    }
+
+    fn meeting_poll_service(&self) -> &Self::MeetingPollService<'_> {
+        &self.service
+    }
+}
+
 impl domain::ProvideUserService for AuthnState {
     type Context<'a>
         = ServiceContext<'a>
@@ -150,6 +273,26 @@ impl domain::ProvideUserService for AuthnState {
     }
 }
 
+impl domain::ProvideMediaService for AuthnState {
+    type Context<'a>
+        = ServiceContext<'a>
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type MediaService<'a>
+        = service::AuthenticatedService
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.service_context()
+    }
+
+    fn media_service(&self) -> &Self::MediaService<'_> {
+        &self.service
+    }
+}
+
 // MARK: impl ServiceContext
 
 impl service::ProvideUserRepository for ServiceContext<'_> {
@@ -192,6 +335,124 @@ impl service::ProvideGroupRepository for ServiceContext<'_> {
     }
 }
 
+impl service::ProvideAnnouncementRepository for ServiceContext<'_> {
+    type Context<'a>
+        = &'a sqlx::PgPool
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type AnnouncementRepository<'a>
+        = Repository
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.pg_pool
+    }
+
+    fn announcement_repository(&self) -> &Self::AnnouncementRepository<'_> {
+        self.repository
+    }
+}
+
+impl service::ProvideGroupActivityRepository for ServiceContext<'_> {
+    type Context<'a>
+        = &'a sqlx::PgPool
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type GroupActivityRepository<'a>
+        = Repository
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.pg_pool
+    }
+
+    fn group_activity_repository(&self) -> &Self::GroupActivityRepository<'_> {
+        self.repository
+    }
+}
+
+impl service::ProvideGroupEventRepository for ServiceContext<'_> {
+    type Context<'a>
+        = &'a sqlx::PgPool
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type GroupEventRepository<'a>
+        = Repository
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.pg_pool
+    }
+
+    fn group_event_repository(&self) -> &Self::GroupEventRepository<'_> {
+        self.repository
+    }
+}
+
+impl service::ProvideMeetingPollRepository for ServiceContext<'_> {
+    type Context<'a>
+        = &'a sqlx::PgPool
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type MeetingPollRepository<'a>
+        = Repository
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.pg_pool
+    }
+
+    fn meeting_poll_repository(&self) -> &Self::MeetingPollRepository<'_> {
+        self.repository
+    }
+}
+
+impl service::ProvideBlobStore for ServiceContext<'_> {
+    type Context<'a>
+        = ()
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type BlobStore<'a>
+        = LocalBlobStore
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {}
+
+    fn blob_store(&self) -> &Self::BlobStore<'_> {
+        self.blob_store
+    }
+}
+
+impl service::ProvideMediaRepository for ServiceContext<'_> {
+    type Context<'a>
+        = &'a sqlx::PgPool
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type MediaRepository<'a>
+        = Repository
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.pg_pool
+    }
+
+    fn media_repository(&self) -> &Self::MediaRepository<'_> {
+        self.repository
+    }
+}
+
 impl service::ProvideUserAccessControl for ServiceContext<'_> {
     type Context<'a>
         = ()
@@ -230,6 +491,104 @@ impl service::ProvideGroupAccessControl for ServiceContext<'_> {
     }
 }
 
+impl service::ProvideAnnouncementAccessControl for ServiceContext<'_> {
+    type Context<'a>
+        = EngineContext<'a>
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type AnnouncementAccessControl<'a>
+        = authz::Engine
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        EngineContext::new(self.repository, self.pg_pool)
+    }
+
+    fn announcement_access_control(&self) -> &Self::AnnouncementAccessControl<'_> {
+        self.authz
+    }
+}
+
+impl service::ProvideGroupActivityAccessControl for ServiceContext<'_> {
+    type Context<'a>
+        = EngineContext<'a>
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type GroupActivityAccessControl<'a>
+        = authz::Engine
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        EngineContext::new(self.repository, self.pg_pool)
+    }
+
+    fn group_activity_access_control(&self) -> &Self::GroupActivityAccessControl<'_> {
+        self.authz
+    }
+}
+
+impl service::ProvideGroupEventAccessControl for ServiceContext<'_> {
+    type Context<'a>
+        = EngineContext<'a>
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type GroupEventAccessControl<'a>
+        = authz::Engine
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        EngineContext::new(self.repository, self.pg_pool)
+    }
+
+    fn group_event_access_control(&self) -> &Self::GroupEventAccessControl<'_> {
+        self.authz
+    }
+}
+
+impl service::ProvideMeetingPollAccessControl for ServiceContext<'_> {
+    type Context<'a>
+        = EngineContext<'a>
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type MeetingPollAccessControl<'a>
+        = authz::Engine
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {
+        EngineContext::new(self.repository, self.pg_pool)
+    }
+
+    fn meeting_poll_access_control(&self) -> &Self::MeetingPollAccessControl<'_> {
+        self.authz
+    }
+}
+
+impl service::ProvideMediaAccessControl for ServiceContext<'_> {
+    type Context<'a>
+        = ()
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+    type MediaAccessControl<'a>
+        = authz::Engine
+    where
+        Self: 'a;
+
+    fn context(&self) -> Self::Context<'_> {}
+
+    fn media_access_control(&self) -> &Self::MediaAccessControl<'_> {
+        self.authz
+    }
+}
+
 // MARK: impl EngineContext
 
 impl<'a> EngineContext<'a> {
@@ -259,3 +618,60 @@ impl authz::ProvideGroupEntityRepository for EngineContext<'_> {
         self.repository
     }
 }
+
+impl authz::ProvideAnnouncementEntityRepository for EngineContext<'_> {
+    type Context<'a>
+        = &'a sqlx::PgPool
+    where
+        Self: 'a;
+    type AnnouncementEntityRepository<'a>
+        = Repository
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.pg_pool
+    }
+    fn announcement_entity_repository(&self) -> &Self::AnnouncementEntityRepository<'_> {
+        self.repository
+    }
+}
+
+impl authz::ProvideGroupEventEntityRepository for EngineContext<'_> {
+    type Context<'a>
+        = &'a sqlx::PgPool
+    where
+        Self: 'a;
+    type GroupEventEntityRepository<'a>
+        = Repository
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.pg_pool
+    }
+    fn group_event_entity_repository(&self) -> &Self::GroupEventEntityRepository<'_> {
+        self.repository
+    }
+}
+
+impl authz::ProvideMeetingPollEntityRepository for EngineContext<'_> {
+    type Context<'a>
+        = &'a sqlx::PgPool
+    where
+        Self: 'a;
+    type MeetingPollEntityRepository<'a>
+        = Repository
+    where
+        Self: 'a;
+    type Error = crate::error::Error;
+
+    fn context(&self) -> Self::Context<'_> {
+        self.pg_pool
+    }
+    fn meeting_poll_entity_repository(&self) -> &Self::MeetingPollEntityRepository<'_> {
+        self.repository
+    }
+}
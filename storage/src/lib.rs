@@ -0,0 +1,217 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+pub trait Error: domain::Error + From<anyhow::Error> {
+    fn not_found(message: &str) -> Self;
+}
+
+/// ローカルのディレクトリに blob を保存する [`service::BlobStore`] の実装です。
+///
+/// キーの先頭 2 文字で分けたサブディレクトリに, キーをファイル名として保存します。
+#[derive(Debug, Clone)]
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub async fn new(root: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let root = root.into();
+        tokio::fs::create_dir_all(&root)
+            .await
+            .with_context(|| format!("Failed to create blob directory {}", root.display()))?;
+        Ok(Self { root })
+    }
+
+    /// キーに対応するファイルのパスを返します。
+    ///
+    /// ディレクトリの外を指さないよう, SHA-256 の 16 進数の小文字表記でないキーには
+    /// `None` を返します。
+    fn blob_path(&self, key: &domain::MediaHash) -> Option<PathBuf> {
+        const KEY_LEN: usize = 64;
+
+        let key = key.as_inner();
+        let valid = key.len() == KEY_LEN
+            && key
+                .chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, 'a'..='f'));
+        valid.then(|| self.root.join(&key[..2]).join(key))
+    }
+}
+
+impl<C, E> service::BlobStore<C, E> for LocalBlobStore
+where
+    C: Send + Sync,
+    E: Error,
+{
+    #[tracing::instrument(skip(self, _ctx, data), fields(len = data.len()))]
+    async fn put_blob(&self, _ctx: C, key: &domain::MediaHash, data: &[u8]) -> Result<(), E> {
+        let path = self
+            .blob_path(key)
+            .with_context(|| format!("Invalid blob key {key}"))?;
+        // 内容はキーで決まるので, すでにあれば書き直さない
+        if tokio::fs::try_exists(&path)
+            .await
+            .context("Failed to check blob existence")?
+        {
+            tracing::debug!("Blob already exists");
+            return Ok(());
+        }
+        let dir = path.parent().context("Blob path has no parent")?;
+        tokio::fs::create_dir_all(dir)
+            .await
+            .context("Failed to create blob directory")?;
+        // 書きかけのファイルを読ませないよう, 一時ファイルに書いてから移動する
+        let tmp = dir.join(format!(".{key}.{}.tmp", uuid::Uuid::now_v7()));
+        tokio::fs::write(&tmp, data)
+            .await
+            .context("Failed to write blob")?;
+        if let Err(e) = tokio::fs::rename(&tmp, &path).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(anyhow::Error::new(e).context("Failed to move blob").into());
+        }
+        tracing::debug!("Stored blob");
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, _ctx))]
+    async fn get_blob(&self, _ctx: C, key: &domain::MediaHash) -> Result<Vec<u8>, E> {
+        let Some(path) = self.blob_path(key) else {
+            return Err(E::not_found("Media not found"));
+        };
+        match tokio::fs::read(&path).await {
+            Ok(data) => Ok(data),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                Err(E::not_found("Media not found"))
+            }
+            Err(e) => Err(anyhow::Error::new(e).context("Failed to read blob").into()),
+        }
+    }
+
+    #[tracing::instrument(skip(self, _ctx))]
+    async fn delete_blob(&self, _ctx: C, key: &domain::MediaHash) -> Result<(), E> {
+        let path = self
+            .blob_path(key)
+            .with_context(|| format!("Invalid blob key {key}"))?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => {
+                tracing::debug!("Deleted blob");
+                Ok(())
+            }
+            // すでに消えていれば目的は果たせている
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(anyhow::Error::new(e)
+                .context("Failed to delete blob")
+                .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use service::BlobStore;
+
+    use super::LocalBlobStore;
+
+    #[derive(Debug)]
+    enum TestError {
+        NotFound(String),
+        Unexpected(anyhow::Error),
+    }
+
+    impl std::fmt::Display for TestError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                TestError::NotFound(msg) => write!(f, "Not Found: {msg}"),
+                TestError::Unexpected(err) => write!(f, "Unexpected error: {err}"),
+            }
+        }
+    }
+
+    impl std::error::Error for TestError {}
+
+    impl From<anyhow::Error> for TestError {
+        fn from(err: anyhow::Error) -> Self {
+            TestError::Unexpected(err)
+        }
+    }
+
+    impl super::Error for TestError {
+        fn not_found(message: &str) -> Self {
+            TestError::NotFound(message.to_string())
+        }
+    }
+
+    const KEY: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    async fn store() -> (tempfile::TempDir, LocalBlobStore) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalBlobStore::new(dir.path()).await.unwrap();
+        (dir, store)
+    }
+
+    #[tokio::test]
+    async fn blob_path_rejects_invalid_keys() {
+        let (_dir, store) = store().await;
+        let invalid = [
+            String::new(),
+            KEY.to_ascii_uppercase(),
+            format!("../{}", &KEY[3..]),
+            KEY[..63].to_string(),
+            format!("{KEY}0"),
+            "ab".to_string(),
+        ];
+        for key in invalid {
+            let key = domain::MediaHash::new(key);
+            assert_eq!(store.blob_path(&key), None, "{key}");
+        }
+    }
+
+    #[tokio::test]
+    async fn blob_path_shards_by_prefix() {
+        let (dir, store) = store().await;
+        let path = store.blob_path(&domain::MediaHash::new(KEY.to_string()));
+        assert_eq!(path, Some(dir.path().join(&KEY[..2]).join(KEY)));
+    }
+
+    #[tokio::test]
+    async fn put_get_delete_round_trip() {
+        let (_dir, store) = store().await;
+        let key = domain::MediaHash::new(KEY.to_string());
+
+        BlobStore::<(), TestError>::put_blob(&store, (), &key, b"data")
+            .await
+            .unwrap();
+        // 同じキーへの 2 回目の書き込みは何もしない
+        BlobStore::<(), TestError>::put_blob(&store, (), &key, b"data")
+            .await
+            .unwrap();
+        let data = BlobStore::<(), TestError>::get_blob(&store, (), &key)
+            .await
+            .unwrap();
+        assert_eq!(data, b"data");
+
+        BlobStore::<(), TestError>::delete_blob(&store, (), &key)
+            .await
+            .unwrap();
+        let missing = BlobStore::<(), TestError>::get_blob(&store, (), &key).await;
+        assert!(matches!(missing, Err(TestError::NotFound(_))));
+        // 消えている blob の削除は成功する
+        BlobStore::<(), TestError>::delete_blob(&store, (), &key)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn invalid_keys_are_not_found_or_rejected() {
+        let (_dir, store) = store().await;
+        let key = domain::MediaHash::new("../secret".to_string());
+
+        let read = BlobStore::<(), TestError>::get_blob(&store, (), &key).await;
+        assert!(matches!(read, Err(TestError::NotFound(_))));
+        let write = BlobStore::<(), TestError>::put_blob(&store, (), &key, b"data").await;
+        assert!(matches!(write, Err(TestError::Unexpected(_))));
+        let delete = BlobStore::<(), TestError>::delete_blob(&store, (), &key).await;
+        assert!(matches!(delete, Err(TestError::Unexpected(_))));
+    }
+}